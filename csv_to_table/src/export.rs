@@ -0,0 +1,130 @@
+//! The module contains [`Csv`] and [`Delimited`] which write a [`Table`] in a machine readable form.
+
+use std::io::{self, Write};
+
+use tabled::{tables, Table};
+
+/// Csv writes [`Table`] records as a RFC 4180 CSV.
+///
+/// Fields which contain a delimiter, a quote or a new line are quoted.
+/// ANSI sequences are removed from the content before it's written.
+///
+/// The header is a first row of a table, so it's written if the table has one.
+///
+/// It's a shortcut for [`tabled::tables::Delimited::csv`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::Table;
+/// use csv_to_table::export::Csv;
+///
+/// let data = [("Hello, World", "\"quoted\"")];
+/// let table = Table::new(data);
+///
+/// let csv = Csv::new().to_string(&table);
+///
+/// assert_eq!(csv, "&str,&str\n\"Hello, World\",\"\"\"quoted\"\"\"\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Csv {
+    delimiter: u8,
+}
+
+impl Csv {
+    /// Creates a [`Csv`] which uses `,` as a delimiter.
+    pub const fn new() -> Self {
+        Self { delimiter: b',' }
+    }
+
+    /// Sets a field delimiter.
+    pub const fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Writes a [`Table`] into a [`Write`]r.
+    pub fn write<W: Write>(&self, table: &Table, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_string(table).as_bytes())?;
+        writer.flush()
+    }
+
+    /// Writes a [`Table`] into a [`String`].
+    pub fn to_string(&self, table: &Table) -> String {
+        tables::Delimited::csv()
+            .delimiter(char::from(self.delimiter))
+            .export(table)
+    }
+}
+
+impl Default for Csv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Delimited writes [`Table`] records as plain lines of fields separated by a given delimiter,
+/// e.g. a TSV.
+///
+/// No quoting is done so it's easy to process the output by tools like `awk` or `cut`.
+/// Instead the delimiter, tabs and backslashes inside a content are escaped by a backslash.
+///
+/// Lines of a multiline cell are joined by a separator (a space by default).
+/// ANSI sequences are removed from the content before it's written.
+///
+/// It's a shortcut for [`tabled::tables::Delimited::tsv`] with a line separator set.
+///
+/// # Example
+///
+/// ```
+/// use tabled::Table;
+/// use csv_to_table::export::Delimited;
+///
+/// let data = [("Hello", "multiline\ntext")];
+/// let table = Table::new(data);
+///
+/// let tsv = Delimited::new('\t').to_string(&table);
+///
+/// assert_eq!(tsv, "&str\t&str\nHello\tmultiline text\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Delimited {
+    delimiter: char,
+    line_separator: String,
+}
+
+impl Delimited {
+    /// Creates a [`Delimited`] with a given delimiter.
+    pub fn new(delimiter: char) -> Self {
+        Self {
+            delimiter,
+            line_separator: String::from(" "),
+        }
+    }
+
+    /// Sets a separator which is used to join lines of a multiline cell.
+    pub fn line_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.line_separator = separator.into();
+        self
+    }
+
+    /// Writes a [`Table`] into a [`Write`]r.
+    pub fn write<W: Write>(&self, table: &Table, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_string(table).as_bytes())?;
+        writer.flush()
+    }
+
+    /// Writes a [`Table`] into a [`String`].
+    pub fn to_string(&self, table: &Table) -> String {
+        tables::Delimited::tsv()
+            .delimiter(self.delimiter)
+            .line_separator(self.line_separator.as_str())
+            .export(table)
+    }
+}
+
+impl Default for Delimited {
+    fn default() -> Self {
+        Self::new('\t')
+    }
+}
@@ -30,6 +30,8 @@
 //! assert_eq!(table, expected);
 //! ```
 //!
+//! A [`Table`] can be written back as a CSV using [`export::Csv`].
+//!
 //! You can also use [`iter`] to build an table from [`Iterator`].
 //! It is usefull when you have a huge csv and don't want to load it all along into memory.
 //! But it's interface might be a little bit less feature full cause of its limitations.
@@ -57,6 +59,8 @@
 
 mod records;
 
+pub mod export;
+
 use std::{io::Read, path::Path};
 
 use csv::{Reader, ReaderBuilder};
@@ -0,0 +1,90 @@
+use csv_to_table::export::{Csv, Delimited};
+use tabled::{builder::Builder, Table};
+
+#[test]
+fn export_test() {
+    let mut builder = Builder::default();
+    builder.set_header(["name", "comment"]);
+    builder.push_record(["Alice", "likes tea, coffee"]);
+    builder.push_record(["Bob", "says \"hi\""]);
+    builder.push_record(["Carol", "multi\nline"]);
+    builder.push_record([
+        "\u{1b}[31mDave\u{1b}[39m",
+        "\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\",
+    ]);
+    let table = builder.build();
+
+    let csv = Csv::new().to_string(&table);
+
+    assert_eq!(
+        csv,
+        "name,comment\n\
+         Alice,\"likes tea, coffee\"\n\
+         Bob,\"says \"\"hi\"\"\"\n\
+         Carol,\"multi\nline\"\n\
+         Dave,link\n"
+    );
+}
+
+#[test]
+fn export_delimiter_test() {
+    let table = Table::new([("a;b", "c")]);
+
+    let csv = Csv::new().delimiter(b';').to_string(&table);
+
+    assert_eq!(csv, "&str;&str\n\"a;b\";c\n");
+}
+
+#[test]
+fn export_round_trip_test() {
+    let csv = "name,designed_by,invented_year\n\
+               C,Dennis Ritchie,1972\n\
+               Rust,\"Graydon Hoare, et al.\",2010\n";
+
+    let table = csv_to_table::from_reader(csv.as_bytes()).unwrap();
+
+    assert_eq!(Csv::new().to_string(&table), csv);
+}
+
+#[test]
+fn export_empty_test() {
+    let table = Table::default();
+    assert_eq!(Csv::new().to_string(&table), "");
+}
+
+#[test]
+fn export_delimited_test() {
+    let mut builder = Builder::default();
+    builder.set_header(["name", "comment"]);
+    builder.push_record(["Alice", "likes tea, coffee"]);
+    builder.push_record(["Carol", "multi\nline\ntext"]);
+    builder.push_record(["\u{1b}[31mDave\u{1b}[39m", ""]);
+    let table = builder.build();
+
+    assert_eq!(
+        Delimited::new('\t').to_string(&table),
+        "name\tcomment\n\
+         Alice\tlikes tea, coffee\n\
+         Carol\tmulti line text\n\
+         Dave\t\n"
+    );
+
+    assert_eq!(
+        Delimited::new('|').line_separator("\\n").to_string(&table),
+        "name|comment\n\
+         Alice|likes tea, coffee\n\
+         Carol|multi\\nline\\ntext\n\
+         Dave|\n"
+    );
+}
+
+#[test]
+fn export_delimited_default_test() {
+    let table = Table::new([("a", "b\nc")]);
+
+    assert_eq!(
+        Delimited::default().to_string(&table),
+        "&str\t&str\na\tb c\n"
+    );
+    assert_eq!(Delimited::default().to_string(&Table::default()), "");
+}
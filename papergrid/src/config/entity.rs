@@ -36,6 +36,21 @@ pub enum Entity {
 
 impl Entity {
     /// Iterate over cells which are covered via the [`Entity`].
+    ///
+    /// Positions are produced row by row, from left to right.
+    ///
+    /// ```
+    /// use papergrid::config::Entity;
+    ///
+    /// let cells: Vec<_> = Entity::Row(1).iter(3, 2).collect();
+    /// assert_eq!(cells, [(1, 0), (1, 1)]);
+    ///
+    /// let cells: Vec<_> = Entity::Global.iter(2, 2).collect();
+    /// assert_eq!(cells, [(0, 0), (0, 1), (1, 0), (1, 1)]);
+    ///
+    /// // Nothing is produced for an empty grid.
+    /// assert_eq!(Entity::Cell(0, 0).iter(0, 0).next(), None);
+    /// ```
     pub fn iter(&self, count_rows: usize, count_cols: usize) -> EntityIterator {
         EntityIterator {
             entity: *self,
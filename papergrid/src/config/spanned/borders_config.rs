@@ -1,6 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::config::{Border, Borders, Position};
+use crate::config::{Border, Borders, Position, Sides};
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub(crate) struct BordersConfig<T> {
@@ -64,6 +64,12 @@ impl<T> BordersConfig<T> {
         }
     }
 
+    pub(crate) fn insert_intersection(&mut self, pos: Position, c: T) {
+        self.cells.intersection.insert(pos, c);
+        self.layout.horizontals.insert(pos.0);
+        self.layout.verticals.insert(pos.1);
+    }
+
     pub(crate) fn remove_border(&mut self, pos: Position, shape: (usize, usize)) {
         let (count_rows, count_cols) = shape;
 
@@ -95,6 +101,67 @@ impl<T> BordersConfig<T> {
         }
     }
 
+    pub(crate) fn remove_border_side(
+        &mut self,
+        pos: Position,
+        sides: Sides<bool>,
+        shape: (usize, usize),
+    ) {
+        let (count_rows, count_cols) = shape;
+
+        if sides.top {
+            self.cells.horizontal.remove(&pos);
+        }
+
+        if sides.bottom {
+            self.cells.horizontal.remove(&(pos.0 + 1, pos.1));
+        }
+
+        if sides.left {
+            self.cells.vertical.remove(&pos);
+        }
+
+        if sides.right {
+            self.cells.vertical.remove(&(pos.0, pos.1 + 1));
+        }
+
+        // a corner is removed only when both of its sides are gone.
+
+        if sides.top && sides.left {
+            self.cells.intersection.remove(&pos);
+        }
+
+        if sides.top && sides.right {
+            self.cells.intersection.remove(&(pos.0, pos.1 + 1));
+        }
+
+        if sides.bottom && sides.left {
+            self.cells.intersection.remove(&(pos.0 + 1, pos.1));
+        }
+
+        if sides.bottom && sides.right {
+            self.cells.intersection.remove(&(pos.0 + 1, pos.1 + 1));
+        }
+
+        // clean up the layout.
+
+        if sides.top && !self.check_is_horizontal_set(pos.0, count_rows) {
+            self.layout.horizontals.remove(&pos.0);
+        }
+
+        if sides.bottom && !self.check_is_horizontal_set(pos.0 + 1, count_rows) {
+            self.layout.horizontals.remove(&(pos.0 + 1));
+        }
+
+        if sides.left && !self.check_is_vertical_set(pos.1, count_cols) {
+            self.layout.verticals.remove(&pos.1);
+        }
+
+        if sides.right && !self.check_is_vertical_set(pos.1 + 1, count_cols) {
+            self.layout.verticals.remove(&(pos.1 + 1));
+        }
+    }
+
     pub(crate) fn get_border(&self, pos: Position, shape: (usize, usize)) -> Border<&T> {
         Border {
             top: self.get_horizontal(pos, shape.0),
@@ -315,6 +382,184 @@ impl<T> BordersConfig<T> {
             || self.is_vertical_set(col, count_cols)
     }
 
+    /// Makes a room for a new row at the given index.
+    ///
+    /// Borders of cells are moved together with the cells,
+    /// while a top frame line is kept in place.
+    pub(crate) fn insert_row(&mut self, row: usize) {
+        let shift_cell = |r: usize| if r >= row { r + 1 } else { r };
+        let shift_line = |l: usize| if l > 0 && l >= row { l + 1 } else { l };
+
+        self.cells.vertical = shift_keys(&mut self.cells.vertical, |(r, c)| (shift_cell(r), c));
+        self.cells.horizontal = shift_keys(&mut self.cells.horizontal, |(r, c)| (shift_line(r), c));
+        self.cells.intersection =
+            shift_keys(&mut self.cells.intersection, |(r, c)| (shift_line(r), c));
+        self.horizontals = shift_keys(&mut self.horizontals, shift_line);
+
+        let mut horizontals: HashSet<usize> =
+            self.layout.horizontals.drain().map(shift_line).collect();
+        horizontals.extend(self.horizontals.keys().copied());
+        self.layout.horizontals = horizontals;
+    }
+
+    /// Makes a room for a new column at the given index.
+    ///
+    /// Borders of cells are moved together with the cells,
+    /// while a left frame line is kept in place.
+    pub(crate) fn insert_column(&mut self, column: usize) {
+        let shift_cell = |col: usize| if col >= column { col + 1 } else { col };
+        let shift_line = |col: usize| {
+            if col > 0 && col >= column {
+                col + 1
+            } else {
+                col
+            }
+        };
+
+        self.cells.vertical = shift_keys(&mut self.cells.vertical, |(r, c)| (r, shift_cell(c)));
+        self.cells.horizontal = shift_keys(&mut self.cells.horizontal, |(r, c)| (r, shift_cell(c)));
+        self.cells.intersection =
+            shift_keys(&mut self.cells.intersection, |(r, c)| (r, shift_cell(c)));
+        self.verticals = shift_keys(&mut self.verticals, shift_line);
+
+        let mut verticals: HashSet<usize> = self.layout.verticals.drain().map(shift_cell).collect();
+        verticals.extend(self.verticals.keys().copied());
+        self.layout.verticals = verticals;
+    }
+
+    /// Mirrors borders horizontally,
+    /// so borders of the first column become borders of the last one and vice versa.
+    ///
+    /// Left and right sides are swapped, characters themselves are not changed.
+    pub(crate) fn mirror_columns(&mut self, count_columns: usize) {
+        let mirror_cell = |col: usize| {
+            if col < count_columns {
+                count_columns - col - 1
+            } else {
+                col
+            }
+        };
+        let mirror_line = |col: usize| {
+            if col <= count_columns {
+                count_columns - col
+            } else {
+                col
+            }
+        };
+
+        self.cells.vertical = shift_keys(&mut self.cells.vertical, |(r, c)| (r, mirror_line(c)));
+        self.cells.horizontal =
+            shift_keys(&mut self.cells.horizontal, |(r, c)| (r, mirror_cell(c)));
+        self.cells.intersection =
+            shift_keys(&mut self.cells.intersection, |(r, c)| (r, mirror_line(c)));
+        self.verticals = shift_keys(&mut self.verticals, mirror_line);
+
+        for line in self.horizontals.values_mut() {
+            core::mem::swap(&mut line.left, &mut line.right);
+        }
+
+        let borders = &mut self.borders;
+        core::mem::swap(&mut borders.left, &mut borders.right);
+        core::mem::swap(&mut borders.top_left, &mut borders.top_right);
+        core::mem::swap(&mut borders.bottom_left, &mut borders.bottom_right);
+        core::mem::swap(
+            &mut borders.left_intersection,
+            &mut borders.right_intersection,
+        );
+
+        core::mem::swap(&mut self.layout.left, &mut self.layout.right);
+        self.layout.verticals = self.layout.verticals.drain().map(mirror_line).collect();
+    }
+
+    /// Keeps only borders of the given columns,
+    /// moving them to the indexes the columns have in the list.
+    ///
+    /// A vertical line is kept if it's on the left side of a selected column,
+    /// or on the right side of the last one.
+    pub(crate) fn select_columns(&mut self, columns: &[usize]) {
+        let select_cell = |col: usize| columns.iter().position(|&c| c == col);
+        let select_line = |col: usize| {
+            select_cell(col).or_else(|| {
+                let is_last = columns.last().map(|&last| last + 1) == Some(col);
+                if is_last {
+                    Some(columns.len())
+                } else {
+                    None
+                }
+            })
+        };
+
+        self.cells.vertical = select_keys(&mut self.cells.vertical, |(r, c)| {
+            select_line(c).map(|c| (r, c))
+        });
+        self.cells.horizontal = select_keys(&mut self.cells.horizontal, |(r, c)| {
+            select_cell(c).map(|c| (r, c))
+        });
+        self.cells.intersection = select_keys(&mut self.cells.intersection, |(r, c)| {
+            select_line(c).map(|c| (r, c))
+        });
+        self.verticals = select_keys(&mut self.verticals, select_line);
+
+        let mut verticals: HashSet<usize> = self
+            .layout
+            .verticals
+            .drain()
+            .filter_map(select_line)
+            .collect();
+        verticals.extend(self.verticals.keys().copied());
+        self.layout.verticals = verticals;
+    }
+
+    /// Removes a row at the given index.
+    ///
+    /// Borders of cells are moved together with the cells.
+    /// A line above the row is removed together with it,
+    /// except for a top frame line, in which case the line below the row is removed.
+    pub(crate) fn remove_row(&mut self, row: usize) {
+        let removed_line = std::cmp::max(row, 1);
+        let shift_cell = |r: usize| if r > row { r - 1 } else { r };
+        let shift_line = |l: usize| if l > removed_line { l - 1 } else { l };
+
+        self.cells.vertical = remove_keys(
+            &mut self.cells.vertical,
+            |&(r, _)| r != row,
+            |(r, c)| (shift_cell(r), c),
+        );
+        self.cells.horizontal = remove_keys(
+            &mut self.cells.horizontal,
+            |&(r, _)| r != removed_line,
+            |(r, c)| (shift_line(r), c),
+        );
+        self.cells.intersection = remove_keys(
+            &mut self.cells.intersection,
+            |&(r, _)| r != removed_line,
+            |(r, c)| (shift_line(r), c),
+        );
+        self.horizontals = remove_keys(&mut self.horizontals, |&l| l != removed_line, shift_line);
+
+        let mut horizontals: HashSet<usize> = self
+            .layout
+            .horizontals
+            .drain()
+            .filter(|&l| l != removed_line)
+            .map(shift_line)
+            .collect();
+        horizontals.extend(self.horizontals.keys().copied());
+        self.layout.horizontals = horizontals;
+    }
+
+    /// Drops borders of all rows starting from the given one.
+    ///
+    /// Borders of cells on a line above the first dropped row are dropped too,
+    /// while a whole horizontal line there is kept.
+    pub(crate) fn truncate_rows(&mut self, count_rows: usize) {
+        self.cells.vertical.retain(|&(r, _), _| r < count_rows);
+        self.cells.horizontal.retain(|&(r, _), _| r < count_rows);
+        self.cells.intersection.retain(|&(r, _), _| r < count_rows);
+        self.horizontals.retain(|&l, _| l <= count_rows);
+        self.layout.horizontals.retain(|&l| l <= count_rows);
+    }
+
     fn is_horizontal_set(&self, row: usize, count_rows: usize) -> bool {
         (row == 0 && self.layout.top)
             || (row == count_rows && self.layout.bottom)
@@ -342,6 +587,36 @@ impl<T> BordersConfig<T> {
     }
 }
 
+fn shift_keys<K, V, F>(map: &mut HashMap<K, V>, f: F) -> HashMap<K, V>
+where
+    K: std::hash::Hash + Eq,
+    F: Fn(K) -> K,
+{
+    map.drain().map(|(key, value)| (f(key), value)).collect()
+}
+
+fn remove_keys<K, V, P, F>(map: &mut HashMap<K, V>, keep: P, f: F) -> HashMap<K, V>
+where
+    K: std::hash::Hash + Eq,
+    P: Fn(&K) -> bool,
+    F: Fn(K) -> K,
+{
+    map.drain()
+        .filter(|(key, _)| keep(key))
+        .map(|(key, value)| (f(key), value))
+        .collect()
+}
+
+fn select_keys<K, V, F>(map: &mut HashMap<K, V>, f: F) -> HashMap<K, V>
+where
+    K: std::hash::Hash + Eq,
+    F: Fn(K) -> Option<K>,
+{
+    map.drain()
+        .filter_map(|(key, value)| f(key).map(|key| (key, value)))
+        .collect()
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub(crate) struct BordersMap<T> {
     vertical: HashMap<Position, T>,
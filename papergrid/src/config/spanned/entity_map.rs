@@ -78,6 +78,116 @@ impl<T> EntityMap<T> {
     }
 }
 
+impl<T> EntityMap<T> {
+    /// Returns all values which are set, the global one included.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        core::iter::once(&mut self.global)
+            .chain(self.columns.values_mut())
+            .chain(self.rows.values_mut())
+            .chain(self.cells.values_mut())
+    }
+
+    /// Removes values of all rows starting from the given one,
+    /// cells of the rows included.
+    pub fn truncate_rows(&mut self, count_rows: usize) {
+        self.rows.retain(|&row, _| row < count_rows);
+        self.cells.retain(|&(row, _), _| row < count_rows);
+    }
+
+    /// Makes a room for a new row by shifting
+    /// all rows and cells starting from the given one down.
+    pub fn insert_row(&mut self, row: usize) {
+        let shift = |r: usize| if r >= row { r + 1 } else { r };
+
+        self.rows = self
+            .rows
+            .drain()
+            .map(|(r, value)| (shift(r), value))
+            .collect();
+        self.cells = self
+            .cells
+            .drain()
+            .map(|((r, col), value)| ((shift(r), col), value))
+            .collect();
+    }
+
+    /// Makes a room for a new column by shifting
+    /// all columns and cells starting from the given one to the right.
+    pub fn insert_column(&mut self, column: usize) {
+        let shift = |col: usize| if col >= column { col + 1 } else { col };
+
+        self.columns = self
+            .columns
+            .drain()
+            .map(|(col, value)| (shift(col), value))
+            .collect();
+        self.cells = self
+            .cells
+            .drain()
+            .map(|((row, col), value)| ((row, shift(col)), value))
+            .collect();
+    }
+
+    /// Moves values of all columns and cells to the mirrored columns,
+    /// so the first column becomes the last one and vice versa.
+    pub fn mirror_columns(&mut self, count_columns: usize) {
+        let mirror = |col: usize| {
+            if col < count_columns {
+                count_columns - col - 1
+            } else {
+                col
+            }
+        };
+
+        self.columns = self
+            .columns
+            .drain()
+            .map(|(col, value)| (mirror(col), value))
+            .collect();
+        self.cells = self
+            .cells
+            .drain()
+            .map(|((row, col), value)| ((row, mirror(col)), value))
+            .collect();
+    }
+
+    /// Keeps only values of the given columns and their cells,
+    /// moving them to the indexes the columns have in the list.
+    pub fn select_columns(&mut self, columns: &[usize]) {
+        let select = |col: usize| columns.iter().position(|&c| c == col);
+
+        self.columns = self
+            .columns
+            .drain()
+            .filter_map(|(col, value)| select(col).map(|col| (col, value)))
+            .collect();
+        self.cells = self
+            .cells
+            .drain()
+            .filter_map(|((row, col), value)| select(col).map(|col| ((row, col), value)))
+            .collect();
+    }
+
+    /// Removes all values set to the given row and its cells,
+    /// shifting the following rows and cells up.
+    pub fn remove_row(&mut self, row: usize) {
+        let shift = |r: usize| if r > row { r - 1 } else { r };
+
+        self.rows = self
+            .rows
+            .drain()
+            .filter(|&(r, _)| r != row)
+            .map(|(r, value)| (shift(r), value))
+            .collect();
+        self.cells = self
+            .cells
+            .drain()
+            .filter(|&((r, _), _)| r != row)
+            .map(|((r, col), value)| ((shift(r), col), value))
+            .collect();
+    }
+}
+
 impl<T: Clone> EntityMap<T> {
     /// Set a value for an [`Entity`].
     pub fn insert(&mut self, entity: Entity, value: T) {
@@ -14,6 +14,7 @@ use crate::config::compact::CompactConfig;
 use crate::config::{
     AlignmentHorizontal, AlignmentVertical, Border, Borders, Entity, Indent, Position, Sides,
 };
+use crate::util::string::string_width_ambiguous;
 use borders_config::BordersConfig;
 
 pub use self::{entity_map::EntityMap, formatting::Formatting, offset::Offset};
@@ -39,6 +40,9 @@ pub struct SpannedConfig {
     vertical_colors: HashMap<Position, HashMap<Offset, AnsiColor<'static>>>,
     justification: EntityMap<char>,
     justification_color: EntityMap<Option<AnsiColor<'static>>>,
+    trim_lines: bool,
+    vertical_suffix: String,
+    ambiguous_width_wide: bool,
 }
 
 impl Default for SpannedConfig {
@@ -60,6 +64,9 @@ impl Default for SpannedConfig {
             vertical_colors: HashMap::default(),
             justification: EntityMap::new(' '),
             justification_color: EntityMap::default(),
+            trim_lines: false,
+            vertical_suffix: String::new(),
+            ambiguous_width_wide: false,
         }
     }
 }
@@ -89,6 +96,17 @@ impl SpannedConfig {
         self.margin.bottom.offset = margin.bottom;
     }
 
+    /// Set characters of margin layers.
+    ///
+    /// Layers are listed starting from the one closest to a grid.
+    /// Layers which has no character set are filled by a margin fill character.
+    pub fn set_margin_layers(&mut self, layers: Sides<Vec<char>>) {
+        self.margin.left.layers = layers.left;
+        self.margin.right.layers = layers.right;
+        self.margin.top.layers = layers.top;
+        self.margin.bottom.layers = layers.bottom;
+    }
+
     /// Returns a margin value currently set.
     pub fn get_margin(&self) -> Sides<Indent> {
         Sides::new(
@@ -109,6 +127,16 @@ impl SpannedConfig {
         )
     }
 
+    /// Returns characters of margin layers currently set.
+    pub fn get_margin_layers(&self) -> Sides<&[char]> {
+        Sides::new(
+            &self.margin.left.layers,
+            &self.margin.right.layers,
+            &self.margin.top.layers,
+            &self.margin.bottom.layers,
+        )
+    }
+
     /// Returns a margin offset value currently set.
     pub fn get_margin_offset(&self) -> Sides<Offset> {
         Sides::new(
@@ -127,6 +155,28 @@ impl SpannedConfig {
         self.vertical_chars.clear();
         self.horizontal_colors.clear();
         self.vertical_colors.clear();
+        self.vertical_suffix.clear();
+    }
+
+    /// Removes all colors which are set,
+    /// including the ones of borders, padding, margin and justification.
+    pub fn clear_colors(&mut self) {
+        self.margin.left.color = None;
+        self.margin.right.color = None;
+        self.margin.top.color = None;
+        self.margin.bottom.color = None;
+
+        for pad in self.padding.values_mut() {
+            pad.left.color = None;
+            pad.right.color = None;
+            pad.top.color = None;
+            pad.bottom.color = None;
+        }
+
+        self.borders_colors = BordersConfig::default();
+        self.horizontal_colors.clear();
+        self.vertical_colors.clear();
+        self.justification_color = EntityMap::default();
     }
 
     /// Set the [`Borders`] value as correct one.
@@ -468,6 +518,14 @@ impl SpannedConfig {
         self.borders.insert_border(pos, border);
     }
 
+    /// Sets an intersection character at a given crossing of a horizontal and a vertical line.
+    ///
+    /// The position is a pair of a horizontal line index and a vertical line index,
+    /// so `(0, 0)` is a top left corner of the grid.
+    pub fn set_intersection(&mut self, pos: Position, c: char) {
+        self.borders.insert_intersection(pos, c);
+    }
+
     /// Returns a border of a cell.
     pub fn get_border(&self, pos: Position, shape: (usize, usize)) -> Border<char> {
         self.borders.get_border(pos, shape).copied()
@@ -494,6 +552,294 @@ impl SpannedConfig {
         self.borders_missing_char
     }
 
+    /// Set whether trailing whitespace is removed from each rendered line.
+    pub fn set_trim_lines(&mut self, on: bool) {
+        self.trim_lines = on;
+    }
+
+    /// Get whether trailing whitespace is removed from each rendered line.
+    pub fn get_trim_lines(&self) -> bool {
+        self.trim_lines
+    }
+
+    /// Set a string which is printed right after each inner vertical character,
+    /// so a separator of columns can consist of multiple characters.
+    ///
+    /// Horizontal lines are extended by their horizontal character at the intersections.
+    pub fn set_vertical_suffix(&mut self, suffix: String) {
+        self.vertical_suffix = suffix;
+    }
+
+    /// Get a string which is printed right after each inner vertical character.
+    pub fn get_vertical_suffix(&self) -> &str {
+        &self.vertical_suffix
+    }
+
+    /// Set whether characters of an ambiguous East Asian width
+    /// are measured as wide (2 columns) or as narrow (1 column).
+    ///
+    /// By default such characters are considered to be narrow.
+    pub fn set_ambiguous_width_wide(&mut self, wide: bool) {
+        self.ambiguous_width_wide = wide;
+    }
+
+    /// Get whether characters of an ambiguous East Asian width are measured as wide.
+    pub fn is_ambiguous_width_wide(&self) -> bool {
+        self.ambiguous_width_wide
+    }
+
+    /// Makes a room for a new row at the given index,
+    /// by shifting all settings of the following rows down.
+    ///
+    /// It's meant to be called together with an insertion of a row into records.
+    pub fn insert_row(&mut self, row: usize) {
+        let shift_cell = |r: usize| if r >= row { r + 1 } else { r };
+        let shift_line = |l: usize| if l > 0 && l >= row { l + 1 } else { l };
+
+        self.padding.insert_row(row);
+        self.alignment_h.insert_row(row);
+        self.alignment_v.insert_row(row);
+        self.formatting.insert_row(row);
+        self.justification.insert_row(row);
+        self.justification_color.insert_row(row);
+
+        self.span_columns = shift_row_positions(&mut self.span_columns, shift_cell);
+        self.span_rows = shift_row_positions(&mut self.span_rows, shift_cell);
+
+        self.borders.insert_row(row);
+        self.borders_colors.insert_row(row);
+
+        self.horizontal_chars = shift_row_positions(&mut self.horizontal_chars, shift_line);
+        self.horizontal_colors = shift_row_positions(&mut self.horizontal_colors, shift_line);
+        self.vertical_chars = shift_row_positions(&mut self.vertical_chars, shift_cell);
+        self.vertical_colors = shift_row_positions(&mut self.vertical_colors, shift_cell);
+    }
+
+    /// Makes a room for a new column at the given index,
+    /// by shifting all settings of the following columns to the right.
+    ///
+    /// It's meant to be called together with an insertion of a column into records.
+    pub fn insert_column(&mut self, column: usize) {
+        let shift_cell = |col: usize| if col >= column { col + 1 } else { col };
+        let shift_line = |col: usize| {
+            if col > 0 && col >= column {
+                col + 1
+            } else {
+                col
+            }
+        };
+
+        self.padding.insert_column(column);
+        self.alignment_h.insert_column(column);
+        self.alignment_v.insert_column(column);
+        self.formatting.insert_column(column);
+        self.justification.insert_column(column);
+        self.justification_color.insert_column(column);
+
+        self.span_columns = shift_positions(&mut self.span_columns, shift_cell);
+        self.span_rows = shift_positions(&mut self.span_rows, shift_cell);
+
+        self.borders.insert_column(column);
+        self.borders_colors.insert_column(column);
+
+        self.horizontal_chars = shift_positions(&mut self.horizontal_chars, shift_cell);
+        self.horizontal_colors = shift_positions(&mut self.horizontal_colors, shift_cell);
+        self.vertical_chars = shift_positions(&mut self.vertical_chars, shift_line);
+        self.vertical_colors = shift_positions(&mut self.vertical_colors, shift_line);
+    }
+
+    /// Mirrors all settings horizontally,
+    /// so settings of the first column become settings of the last one and vice versa.
+    ///
+    /// Left and right sides of paddings, margin and borders are swapped,
+    /// and a left alignment becomes a right one.
+    ///
+    /// It's meant to be called together with a reversal of columns in records.
+    pub fn mirror_columns(&mut self, count_columns: usize) {
+        let mirror_cell = |col: usize| {
+            if col < count_columns {
+                count_columns - col - 1
+            } else {
+                col
+            }
+        };
+        let mirror_line = |col: usize| {
+            if col <= count_columns {
+                count_columns - col
+            } else {
+                col
+            }
+        };
+
+        core::mem::swap(&mut self.margin.left, &mut self.margin.right);
+
+        self.padding.mirror_columns(count_columns);
+        for padding in self.padding.values_mut() {
+            core::mem::swap(&mut padding.left, &mut padding.right);
+        }
+
+        self.alignment_h.mirror_columns(count_columns);
+        for alignment in self.alignment_h.values_mut() {
+            *alignment = match *alignment {
+                AlignmentHorizontal::Left => AlignmentHorizontal::Right,
+                AlignmentHorizontal::Right => AlignmentHorizontal::Left,
+                AlignmentHorizontal::Center => AlignmentHorizontal::Center,
+            };
+        }
+
+        self.alignment_v.mirror_columns(count_columns);
+        self.formatting.mirror_columns(count_columns);
+        self.justification.mirror_columns(count_columns);
+        self.justification_color.mirror_columns(count_columns);
+
+        self.span_columns = self
+            .span_columns
+            .drain()
+            .map(|((row, col), span)| {
+                let col = if col + span <= count_columns {
+                    count_columns - col - span
+                } else {
+                    col
+                };
+
+                ((row, col), span)
+            })
+            .collect();
+        self.span_rows = shift_positions(&mut self.span_rows, mirror_cell);
+
+        self.borders.mirror_columns(count_columns);
+        self.borders_colors.mirror_columns(count_columns);
+
+        self.horizontal_chars = mirror_offsets(&mut self.horizontal_chars, mirror_cell);
+        self.horizontal_colors = mirror_offsets(&mut self.horizontal_colors, mirror_cell);
+        self.vertical_chars = shift_positions(&mut self.vertical_chars, mirror_line);
+        self.vertical_colors = shift_positions(&mut self.vertical_colors, mirror_line);
+    }
+
+    /// Keeps only settings of the given columns,
+    /// moving them to the indexes the columns have in the list.
+    ///
+    /// A column span is cut to the columns which follow it in the list,
+    /// and it's dropped if there's none.
+    ///
+    /// It's meant to be called together with a selection of columns in records.
+    pub fn select_columns(&mut self, columns: &[usize]) {
+        let select_cell = |col: usize| columns.iter().position(|&c| c == col);
+        let select_line = |col: usize| {
+            select_cell(col).or_else(|| {
+                let is_last = columns.last().map(|&last| last + 1) == Some(col);
+                if is_last {
+                    Some(columns.len())
+                } else {
+                    None
+                }
+            })
+        };
+
+        self.padding.select_columns(columns);
+        self.alignment_h.select_columns(columns);
+        self.alignment_v.select_columns(columns);
+        self.formatting.select_columns(columns);
+        self.justification.select_columns(columns);
+        self.justification_color.select_columns(columns);
+
+        self.span_columns = self
+            .span_columns
+            .drain()
+            .filter_map(|((row, col), span)| {
+                let i = select_cell(col)?;
+                let span = columns[i..]
+                    .iter()
+                    .zip(col..col + span)
+                    .take_while(|(a, b)| *a == b)
+                    .count();
+
+                Some(((row, i), span))
+            })
+            .filter(|&(_, span)| span > 1)
+            .collect();
+        self.span_rows = select_positions(&mut self.span_rows, select_cell);
+
+        self.borders.select_columns(columns);
+        self.borders_colors.select_columns(columns);
+
+        self.horizontal_chars = select_positions(&mut self.horizontal_chars, select_cell);
+        self.horizontal_colors = select_positions(&mut self.horizontal_colors, select_cell);
+        self.vertical_chars = select_positions(&mut self.vertical_chars, select_line);
+        self.vertical_colors = select_positions(&mut self.vertical_colors, select_line);
+    }
+
+    /// Drops all settings of rows starting from the given one,
+    /// while row spans which cross the row are cut.
+    ///
+    /// It's meant to be called together with a truncation of records.
+    pub fn truncate_rows(&mut self, count_rows: usize) {
+        self.padding.truncate_rows(count_rows);
+        self.alignment_h.truncate_rows(count_rows);
+        self.alignment_v.truncate_rows(count_rows);
+        self.formatting.truncate_rows(count_rows);
+        self.justification.truncate_rows(count_rows);
+        self.justification_color.truncate_rows(count_rows);
+
+        self.span_columns.retain(|&(r, _), _| r < count_rows);
+        self.span_rows.retain(|&(r, _), _| r < count_rows);
+        for (&(r, _), span) in self.span_rows.iter_mut() {
+            *span = std::cmp::min(*span, count_rows - r);
+        }
+        self.span_rows.retain(|_, &mut span| span > 1);
+
+        self.borders.truncate_rows(count_rows);
+        self.borders_colors.truncate_rows(count_rows);
+
+        self.horizontal_chars.retain(|&(l, _), _| l < count_rows);
+        self.horizontal_colors.retain(|&(l, _), _| l < count_rows);
+        self.vertical_chars.retain(|&(r, _), _| r < count_rows);
+        self.vertical_colors.retain(|&(r, _), _| r < count_rows);
+    }
+
+    /// Removes all settings of a row at the given index,
+    /// by shifting all settings of the following rows up.
+    ///
+    /// A row span which covers the row is shortened,
+    /// while a span which starts at the row is removed.
+    ///
+    /// It's meant to be called together with a removal of a row from records.
+    pub fn remove_row(&mut self, row: usize) {
+        let removed_line = std::cmp::max(row, 1);
+        let shift_cell = |r: usize| if r > row { r - 1 } else { r };
+        let shift_line = |l: usize| if l > removed_line { l - 1 } else { l };
+
+        self.padding.remove_row(row);
+        self.alignment_h.remove_row(row);
+        self.alignment_v.remove_row(row);
+        self.formatting.remove_row(row);
+        self.justification.remove_row(row);
+        self.justification_color.remove_row(row);
+
+        self.span_columns = remove_row_positions(&mut self.span_columns, row, shift_cell);
+        self.span_rows = self
+            .span_rows
+            .drain()
+            .filter(|&((r, _), _)| r != row)
+            .map(|((r, c), span)| {
+                let is_covered = r < row && r + span > row;
+                let span = if is_covered { span - 1 } else { span };
+                ((shift_cell(r), c), span)
+            })
+            .filter(|&(_, span)| span > 1)
+            .collect();
+
+        self.borders.remove_row(row);
+        self.borders_colors.remove_row(row);
+
+        self.horizontal_chars =
+            remove_row_positions(&mut self.horizontal_chars, removed_line, shift_line);
+        self.horizontal_colors =
+            remove_row_positions(&mut self.horizontal_colors, removed_line, shift_line);
+        self.vertical_chars = remove_row_positions(&mut self.vertical_chars, row, shift_cell);
+        self.vertical_colors = remove_row_positions(&mut self.vertical_colors, row, shift_cell);
+    }
+
     /// Gets a color of all borders on the grid.
     pub fn get_border_color_global(&self) -> Option<&AnsiColor<'static>> {
         self.borders_colors.get_global()
@@ -529,6 +875,14 @@ impl SpannedConfig {
         self.borders.remove_border(pos, shape);
     }
 
+    /// Sets off the chosen sides of a border on the [`Entity`].
+    ///
+    /// A corner is removed only if both sides it joins are removed.
+    /// Like [`SpannedConfig::remove_border`] it doesn't change globally set borders.
+    pub fn remove_border_side(&mut self, pos: Position, sides: Sides<bool>, shape: (usize, usize)) {
+        self.borders.remove_border_side(pos, sides, shape);
+    }
+
     /// Gets a color of border of a cell on the grid.
     //
     // todo: would be great to remove a shape
@@ -722,8 +1076,26 @@ impl SpannedConfig {
     /// grid: crate::Grid
     pub fn count_vertical(&self, count_columns: usize) -> usize {
         (0..=count_columns)
-            .filter(|&col| self.has_vertical(col, count_columns))
-            .count()
+            .map(|col| self.get_vertical_width(col, count_columns))
+            .sum()
+    }
+
+    /// Calculates a width of a vertical line would present on the grid.
+    ///
+    /// It's 0 if there's no line, and it includes a vertical suffix for inner lines.
+    ///
+    /// grid: crate::Grid
+    pub fn get_vertical_width(&self, col: usize, count_columns: usize) -> usize {
+        if !self.has_vertical(col, count_columns) {
+            return 0;
+        }
+
+        let is_inner = col > 0 && col < count_columns;
+        if is_inner && !self.vertical_suffix.is_empty() {
+            return 1 + string_width_ambiguous(&self.vertical_suffix, self.ambiguous_width_wide);
+        }
+
+        1
     }
 
     /// The function returns whether the cells will be rendered or it will be hidden because of a span.
@@ -810,6 +1182,69 @@ fn borders_static_color_to_ansi_color(b: Borders<StaticColor>) -> Borders<AnsiCo
     }
 }
 
+fn shift_positions<V, F>(map: &mut HashMap<Position, V>, shift_column: F) -> HashMap<Position, V>
+where
+    F: Fn(usize) -> usize,
+{
+    map.drain()
+        .map(|((row, col), value)| ((row, shift_column(col)), value))
+        .collect()
+}
+
+fn shift_row_positions<V, F>(map: &mut HashMap<Position, V>, shift_row: F) -> HashMap<Position, V>
+where
+    F: Fn(usize) -> usize,
+{
+    map.drain()
+        .map(|((row, col), value)| ((shift_row(row), col), value))
+        .collect()
+}
+
+fn mirror_offsets<V, F>(
+    map: &mut HashMap<Position, HashMap<Offset, V>>,
+    mirror_column: F,
+) -> HashMap<Position, HashMap<Offset, V>>
+where
+    F: Fn(usize) -> usize,
+{
+    map.drain()
+        .map(|((row, col), mut chars)| {
+            let chars = chars
+                .drain()
+                .map(|(offset, value)| match offset {
+                    Offset::Begin(offset) => (Offset::End(offset), value),
+                    Offset::End(offset) => (Offset::Begin(offset), value),
+                })
+                .collect();
+
+            ((row, mirror_column(col)), chars)
+        })
+        .collect()
+}
+
+fn select_positions<V, F>(map: &mut HashMap<Position, V>, select_column: F) -> HashMap<Position, V>
+where
+    F: Fn(usize) -> Option<usize>,
+{
+    map.drain()
+        .filter_map(|((row, col), value)| select_column(col).map(|col| ((row, col), value)))
+        .collect()
+}
+
+fn remove_row_positions<V, F>(
+    map: &mut HashMap<Position, V>,
+    row: usize,
+    shift_row: F,
+) -> HashMap<Position, V>
+where
+    F: Fn(usize) -> usize,
+{
+    map.drain()
+        .filter(|&((r, _), _)| r != row)
+        .map(|((r, col), value)| ((shift_row(r), col), value))
+        .collect()
+}
+
 fn set_cell_row_span(cfg: &mut SpannedConfig, pos: Position, span: usize) {
     // such spans aren't supported
     if span == 0 {
@@ -884,6 +1319,8 @@ struct ColoredMarginIndent {
     offset: Offset,
     /// An color value.
     color: Option<AnsiColor<'static>>,
+    /// A list of characters per layer.
+    layers: Vec<char>,
 }
 
 impl Default for ColoredMarginIndent {
@@ -892,6 +1329,7 @@ impl Default for ColoredMarginIndent {
             indent: Indent::default(),
             offset: Offset::Begin(0),
             color: None,
+            layers: Vec::new(),
         }
     }
 }
@@ -11,7 +11,7 @@ use crate::{
     config::Position,
     dimension::{Dimension, Estimate},
     records::Records,
-    util::string::{count_lines, string_dimension, string_width_multiline},
+    util::string::{count_lines, string_dimension_ambiguous, string_width_multiline_ambiguous},
 };
 
 use crate::config::spanned::SpannedConfig;
@@ -81,7 +81,7 @@ fn build_dimensions<R: Records>(records: R, cfg: &SpannedConfig) -> (Vec<usize>,
             }
 
             let text = cell.as_ref();
-            let (height, width) = string_dimension(text);
+            let (height, width) = string_dimension_ambiguous(text, cfg.is_ambiguous_width_wide());
             let pad = cfg.get_padding(pos.into());
             let width = width + pad.left.size + pad.right.size;
             let height = height + pad.top.size + pad.bottom.size;
@@ -243,7 +243,7 @@ fn adjust_column_range(
 
 fn get_cell_width(text: &str, cfg: &SpannedConfig, pos: Position) -> usize {
     let padding = get_cell_padding(cfg, pos);
-    let width = string_width_multiline(text);
+    let width = string_width_multiline_ambiguous(text, cfg.is_ambiguous_width_wide());
     width + padding
 }
 
@@ -267,8 +267,8 @@ fn range_width(
 fn count_vertical_borders(cfg: &SpannedConfig, len: usize, start: usize, end: usize) -> usize {
     (start..end)
         .skip(1)
-        .filter(|&i| cfg.has_vertical(i, len))
-        .count()
+        .map(|i| cfg.get_vertical_width(i, len))
+        .sum()
 }
 
 fn build_height<R: Records>(records: R, cfg: &SpannedConfig) -> Vec<usize> {
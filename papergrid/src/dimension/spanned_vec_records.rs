@@ -14,6 +14,7 @@ use crate::{
         vec_records::{Cell, VecRecords},
         Records,
     },
+    util::string::string_width_multiline_ambiguous,
 };
 
 use crate::config::spanned::SpannedConfig;
@@ -90,7 +91,7 @@ fn build_dimensions<T: Cell + AsRef<str>>(
                 continue;
             }
 
-            let width = cell.width();
+            let width = get_cell_text_width(cell, cfg);
             let height = cell.count_lines();
             let pad = cfg.get_padding(pos.into());
             let width = width + pad.left.size + pad.right.size;
@@ -245,6 +246,15 @@ fn adjust_column_range(
     inc_range(widths, max_span_width - range_width, start, end);
 }
 
+fn get_cell_text_width<T: Cell>(cell: &T, cfg: &SpannedConfig) -> usize {
+    // a cached width is measured with ambiguous characters being narrow.
+    if cfg.is_ambiguous_width_wide() {
+        string_width_multiline_ambiguous(cell.text(), true)
+    } else {
+        cell.width()
+    }
+}
+
 fn get_cell_padding_horizontal(cfg: &SpannedConfig, pos: Position) -> usize {
     let padding = cfg.get_padding(pos.into());
     padding.left.size + padding.right.size
@@ -270,8 +280,8 @@ fn range_width(
 fn count_vertical_borders(cfg: &SpannedConfig, len: usize, start: usize, end: usize) -> usize {
     (start..end)
         .skip(1)
-        .filter(|&i| cfg.has_vertical(i, len))
-        .count()
+        .map(|i| cfg.get_vertical_width(i, len))
+        .sum()
 }
 
 fn build_height<T: Cell + AsRef<str>>(records: &VecRecords<T>, cfg: &SpannedConfig) -> Vec<usize> {
@@ -316,7 +326,8 @@ fn build_width<T: Cell + AsRef<str>>(records: &VecRecords<T>, cfg: &SpannedConfi
                 continue;
             }
 
-            let width = cell.width() + get_cell_padding_horizontal(cfg, (row, col));
+            let width =
+                get_cell_text_width(cell, cfg) + get_cell_padding_horizontal(cfg, (row, col));
             match cfg.get_column_span(pos) {
                 Some(n) if n > 1 => {
                     vspans.insert(pos, (n, width));
@@ -13,7 +13,12 @@ use crate::{
     config::{AlignmentHorizontal, AlignmentVertical, Indent, Position, Sides},
     dimension::Dimension,
     records::Records,
-    util::string::{count_lines, get_lines, string_width, string_width_multiline, Lines},
+    util::{
+        string::{
+            count_lines, get_lines, string_width_ambiguous, string_width_multiline_ambiguous, Lines,
+        },
+        trim_writer::TrimWriter,
+    },
 };
 
 use crate::config::spanned::{Formatting, Offset, SpannedConfig};
@@ -54,6 +59,11 @@ impl<R, D, G, C> Grid<R, D, G, C> {
         }
 
         let config = self.config.borrow();
+        if config.get_trim_lines() {
+            let mut f = TrimWriter::new(f);
+            return print_grid(&mut f, self.records, config, &self.dimension, &self.colors);
+        }
+
         print_grid(&mut f, self.records, config, &self.dimension, &self.colors)
     }
 
@@ -283,15 +293,16 @@ fn print_single_line_column<F: Write, C: Color>(
     let fmt = cfg.get_formatting(pos);
     let space = cfg.get_justification(pos);
     let space_color = cfg.get_justification_color(pos);
+    let ambiguous_wide = cfg.is_ambiguous_width_wide();
 
     let (text, text_width) = if fmt.horizontal_trim && !text.is_empty() {
         let text = string_trim(text);
-        let width = string_width(&text);
+        let width = string_width_ambiguous(&text, ambiguous_wide);
 
         (text, width)
     } else {
         let text = Cow::Borrowed(text);
-        let width = string_width_multiline(&text);
+        let width = string_width_multiline_ambiguous(&text, ambiguous_wide);
 
         (text, width)
     };
@@ -504,6 +515,8 @@ fn print_split_line_spanned<S, F: Write, D: Dimension, C: Color>(
     for col in 0..shape.1 {
         let pos = (row, col);
         if cfg.is_cell_covered_by_both_spans(pos) {
+            // the right split char (including a frame one) is printed
+            // by the row spanned cell itself, as it skips its column span.
             continue;
         }
 
@@ -559,10 +572,20 @@ fn print_vertical_intersection<'a, F: fmt::Write>(
         Some(c) => {
             let clr = cfg.get_intersection_color(pos, shape);
             prepare_coloring(f, clr, used_color)?;
-            f.write_char(c)
+            f.write_char(c)?;
         }
-        None => Ok(()),
+        None => return Ok(()),
     }
+
+    let width = cfg.get_vertical_width(pos.1, shape.1).saturating_sub(1);
+    if width == 0 {
+        return Ok(());
+    }
+
+    let c = cfg.get_horizontal(pos, shape.0).unwrap_or(' ');
+    let clr = cfg.get_horizontal_color(pos, shape.0);
+    prepare_coloring(f, clr, used_color)?;
+    repeat_char(f, c, width)
 }
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
@@ -746,6 +769,7 @@ struct Cell<T, C> {
     pad_color: Sides<Option<AnsiColor<'static>>>,
     color: Option<C>,
     justification: (char, Option<AnsiColor<'static>>),
+    ambiguous_wide: bool,
 }
 
 impl<T, C> Cell<T, C>
@@ -779,9 +803,11 @@ where
 
         let indent_top = top_indent(&pad, alignv, count_lines, height);
 
+        let ambiguous_wide = cfg.is_ambiguous_width_wide();
+
         let mut indent_left = None;
         if !fmt.allow_lines_alignment {
-            let text_width = get_text_width(text.as_ref(), fmt.horizontal_trim);
+            let text_width = get_text_width(text.as_ref(), fmt.horizontal_trim, ambiguous_wide);
             let available = width - pad.left.size - pad.right.size;
             indent_left = Some(calculate_indent(alignh, text_width, available).0);
         }
@@ -802,6 +828,7 @@ where
             pad_color,
             color,
             justification,
+            ambiguous_wide,
         }
     }
 }
@@ -832,7 +859,7 @@ where
             line
         };
 
-        let line_width = string_width(&line);
+        let line_width = string_width_ambiguous(&line, self.ambiguous_wide);
         let available_width = self.width - self.pad.left.size - self.pad.right.size;
 
         let (left, right) = if self.fmt.allow_lines_alignment {
@@ -998,13 +1025,24 @@ fn print_vertical_char<F: Write>(
         .flatten()
         .unwrap_or(symbol);
 
+    let is_inner = pos.1 > 0 && pos.1 < count_columns;
+    let suffix = if is_inner {
+        cfg.get_vertical_suffix()
+    } else {
+        ""
+    };
+
     match cfg.get_vertical_color(pos, count_columns) {
         Some(clr) => {
             clr.fmt_prefix(f)?;
             f.write_char(symbol)?;
+            f.write_str(suffix)?;
             clr.fmt_suffix(f)?;
         }
-        None => f.write_char(symbol)?,
+        None => {
+            f.write_char(symbol)?;
+            f.write_str(suffix)?;
+        }
     }
 
     Ok(())
@@ -1015,7 +1053,8 @@ fn print_margin_top<F: Write>(f: &mut F, cfg: &SpannedConfig, width: usize) -> f
     let offset = cfg.get_margin_offset().top;
     let color = cfg.get_margin_color();
     let color = color.top.as_ref();
-    print_indent_lines(f, &indent, &offset, color, width)
+    let layers = cfg.get_margin_layers().top;
+    print_indent_lines(f, &indent, &offset, color, layers, true, width)
 }
 
 fn print_margin_bottom<F: Write>(f: &mut F, cfg: &SpannedConfig, width: usize) -> fmt::Result {
@@ -1023,7 +1062,8 @@ fn print_margin_bottom<F: Write>(f: &mut F, cfg: &SpannedConfig, width: usize) -
     let offset = cfg.get_margin_offset().bottom;
     let color = cfg.get_margin_color();
     let color = color.bottom.as_ref();
-    print_indent_lines(f, &indent, &offset, color, width)
+    let layers = cfg.get_margin_layers().bottom;
+    print_indent_lines(f, &indent, &offset, color, layers, false, width)
 }
 
 fn print_margin_left<F: Write>(
@@ -1036,7 +1076,8 @@ fn print_margin_left<F: Write>(
     let offset = cfg.get_margin_offset().left;
     let color = cfg.get_margin_color();
     let color = color.left.as_ref();
-    print_margin_vertical(f, indent, offset, color, line, height)
+    let layers = cfg.get_margin_layers().left;
+    print_margin_vertical(f, indent, offset, color, layers, true, line, height)
 }
 
 fn print_margin_right<F: Write>(
@@ -1049,14 +1090,18 @@ fn print_margin_right<F: Write>(
     let offset = cfg.get_margin_offset().right;
     let color = cfg.get_margin_color();
     let color = color.right.as_ref();
-    print_margin_vertical(f, indent, offset, color, line, height)
+    let layers = cfg.get_margin_layers().right;
+    print_margin_vertical(f, indent, offset, color, layers, false, line, height)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn print_margin_vertical<F: Write>(
     f: &mut F,
     indent: Indent,
     offset: Offset,
     color: Option<&AnsiColor<'_>>,
+    layers: &[char],
+    reverse: bool,
     line: usize,
     height: Option<usize>,
 ) -> fmt::Result {
@@ -1071,7 +1116,7 @@ fn print_margin_vertical<F: Write>(
             }
 
             if line >= offset {
-                print_indent(f, indent.fill, indent.size, color)?;
+                print_margin_indent(f, &indent, color, layers, reverse)?;
             } else {
                 repeat_char(f, ' ', indent.size)?;
             }
@@ -1084,10 +1129,10 @@ fn print_margin_vertical<F: Write>(
                 if line >= pos {
                     repeat_char(f, ' ', indent.size)?;
                 } else {
-                    print_indent(f, indent.fill, indent.size, color)?;
+                    print_margin_indent(f, &indent, color, layers, reverse)?;
                 }
             } else {
-                print_indent(f, indent.fill, indent.size, color)?;
+                print_margin_indent(f, &indent, color, layers, reverse)?;
             }
         }
     }
@@ -1100,6 +1145,8 @@ fn print_indent_lines<F: Write>(
     indent: &Indent,
     offset: &Offset,
     color: Option<&AnsiColor<'_>>,
+    layers: &[char],
+    reverse: bool,
     width: usize,
 ) -> fmt::Result {
     if indent.size == 0 {
@@ -1121,7 +1168,8 @@ fn print_indent_lines<F: Write>(
         }
 
         if indent_size > 0 {
-            print_indent(f, indent.fill, indent_size, color)?;
+            let c = get_margin_layer_char(indent, layers, i, reverse);
+            print_indent(f, c, indent_size, color)?;
         }
 
         if end_offset > 0 {
@@ -1149,6 +1197,37 @@ fn print_padding_n<F: Write>(
     print_indent(f, pad.fill, n, color)
 }
 
+fn print_margin_indent<F: Write>(
+    f: &mut F,
+    indent: &Indent,
+    color: Option<&AnsiColor<'_>>,
+    layers: &[char],
+    reverse: bool,
+) -> fmt::Result {
+    if layers.is_empty() {
+        return print_indent(f, indent.fill, indent.size, color);
+    }
+
+    if let Some(color) = color {
+        color.fmt_prefix(f)?;
+    }
+
+    for i in 0..indent.size {
+        f.write_char(get_margin_layer_char(indent, layers, i, reverse))?;
+    }
+
+    if let Some(color) = color {
+        color.fmt_suffix(f)?;
+    }
+
+    Ok(())
+}
+
+fn get_margin_layer_char(indent: &Indent, layers: &[char], i: usize, reverse: bool) -> char {
+    let layer = if reverse { indent.size - 1 - i } else { i };
+    layers.get(layer).copied().unwrap_or(indent.fill)
+}
+
 fn print_indent<F: Write>(
     f: &mut F,
     c: char,
@@ -1204,7 +1283,7 @@ fn count_horizontals_in_range(cfg: &SpannedConfig, from: usize, end: usize, max:
 fn count_verticals_in_range(cfg: &SpannedConfig, start: usize, end: usize, max: usize) -> usize {
     (start..end)
         .skip(1)
-        .map(|i| cfg.has_vertical(i, max) as usize)
+        .map(|i| cfg.get_vertical_width(i, max))
         .sum()
 }
 
@@ -1287,14 +1366,14 @@ fn count_empty_lines(cell: &str) -> (usize, usize, usize) {
     (len, top, bottom)
 }
 
-fn get_text_width(text: &str, trim: bool) -> usize {
+fn get_text_width(text: &str, trim: bool, ambiguous_wide: bool) -> usize {
     if trim {
         get_lines(text)
-            .map(|line| string_width(line.trim()))
+            .map(|line| string_width_ambiguous(line.trim(), ambiguous_wide))
             .max()
             .unwrap_or(0)
     } else {
-        string_width_multiline(text)
+        string_width_multiline_ambiguous(text, ambiguous_wide)
     }
 }
 
@@ -14,7 +14,10 @@ use crate::{
     config::{AlignmentHorizontal, AlignmentVertical, Indent, Position, Sides},
     dimension::Dimension,
     records::{ExactRecords, PeekableRecords, Records},
-    util::string::string_width,
+    util::{
+        string::{string_width_ambiguous, string_width_multiline_ambiguous},
+        trim_writer::TrimWriter,
+    },
 };
 
 /// Grid provides a set of methods for building a text-based table.
@@ -53,6 +56,11 @@ impl<R, G, D, C> PeekableGrid<R, G, D, C> {
         }
 
         let config = self.config.borrow();
+        if config.get_trim_lines() {
+            let mut f = TrimWriter::new(f);
+            return print_grid(&mut f, self.records, config, &self.dimension, &self.colors);
+        }
+
         print_grid(&mut f, self.records, config, &self.dimension, &self.colors)
     }
 
@@ -236,7 +244,27 @@ fn print_vertical_intersection<'a, F: fmt::Write>(
 
     let color = cfg.get_intersection_color(pos, shape);
     prepare_coloring(f, color, used_color)?;
-    f.write_char(intersection)
+    f.write_char(intersection)?;
+
+    print_vertical_intersection_suffix(f, cfg, pos, shape, used_color)
+}
+
+fn print_vertical_intersection_suffix<'a, F: fmt::Write>(
+    f: &mut F,
+    cfg: &'a SpannedConfig,
+    pos: Position,
+    shape: (usize, usize),
+    used_color: &mut Option<&'a AnsiColor<'static>>,
+) -> fmt::Result {
+    let width = cfg.get_vertical_width(pos.1, shape.1).saturating_sub(1);
+    if width == 0 {
+        return Ok(());
+    }
+
+    let c = cfg.get_horizontal(pos, shape.0).unwrap_or(' ');
+    let color = cfg.get_horizontal_color(pos, shape.0);
+    prepare_coloring(f, color, used_color)?;
+    repeat_char(f, c, width)
 }
 
 fn prepare_coloring<'a, 'b, F: Write>(
@@ -287,13 +315,24 @@ fn print_vertical_char<F: Write>(
         .flatten()
         .unwrap_or(symbol);
 
+    let is_inner = pos.1 > 0 && pos.1 < count_columns;
+    let suffix = if is_inner {
+        cfg.get_vertical_suffix()
+    } else {
+        ""
+    };
+
     match cfg.get_vertical_color(pos, count_columns) {
         Some(clr) => {
             clr.fmt_prefix(f)?;
             f.write_char(symbol)?;
+            f.write_str(suffix)?;
             clr.fmt_suffix(f)?;
         }
-        None => f.write_char(symbol)?,
+        None => {
+            f.write_char(symbol)?;
+            f.write_str(suffix)?;
+        }
     }
 
     Ok(())
@@ -356,6 +395,11 @@ fn build_grid_spanned<
 
             for col in 0..records.count_columns() {
                 if cfg.is_cell_covered_by_both_spans((row, col)) {
+                    let is_last_column = col + 1 == records.count_columns();
+                    if is_last_column {
+                        print_vertical_char(f, cfg, (row, col + 1), i, count_lines, shape.1)?;
+                    }
+
                     continue;
                 }
 
@@ -617,6 +661,7 @@ fn print_cell_line<F: Write, R: Records + PeekableRecords + ExactRecords, C: Col
         color,
         justification,
         width,
+        cfg.is_ambiguous_width_wide(),
     )?;
 
     print_indent(f, pad.right.fill, pad.right.size, pad_color.right.as_ref())?;
@@ -635,12 +680,17 @@ fn print_line<F: Write, R: Records + PeekableRecords, C: Color>(
     color: Option<C>,
     justification: (char, Option<&AnsiColor<'_>>),
     available: usize,
+    ambiguous_wide: bool,
 ) -> fmt::Result {
     let line = records.get_line(pos, index);
     let (line, line_width) = if formatting.horizontal_trim {
         let line = string_trim(line);
-        let width = string_width(&line);
+        let width = string_width_ambiguous(&line, ambiguous_wide);
         (line, width)
+    } else if ambiguous_wide {
+        // a cached width is measured with ambiguous characters being narrow.
+        let width = string_width_ambiguous(line, ambiguous_wide);
+        (Cow::Borrowed(line), width)
     } else {
         let width = records.get_line_width(pos, index);
         (Cow::Borrowed(line), width)
@@ -654,9 +704,11 @@ fn print_line<F: Write, R: Records + PeekableRecords, C: Color>(
     let cell_width = if formatting.horizontal_trim {
         (0..records.count_lines(pos))
             .map(|i| records.get_line(pos, i))
-            .map(|line| string_width(line.trim()))
+            .map(|line| string_width_ambiguous(line.trim(), ambiguous_wide))
             .max()
             .unwrap_or_default()
+    } else if ambiguous_wide {
+        string_width_multiline_ambiguous(records.get_text(pos), ambiguous_wide)
     } else {
         records.get_width(pos)
     };
@@ -781,7 +833,8 @@ fn print_margin_top<F: Write>(f: &mut F, cfg: &SpannedConfig, width: usize) -> f
     let offset = cfg.get_margin_offset().top;
     let color = cfg.get_margin_color();
     let color = color.top.as_ref();
-    print_indent_lines(f, &indent, &offset, color, width)
+    let layers = cfg.get_margin_layers().top;
+    print_indent_lines(f, &indent, &offset, color, layers, true, width)
 }
 
 fn print_margin_bottom<F: Write>(f: &mut F, cfg: &SpannedConfig, width: usize) -> fmt::Result {
@@ -789,7 +842,8 @@ fn print_margin_bottom<F: Write>(f: &mut F, cfg: &SpannedConfig, width: usize) -
     let offset = cfg.get_margin_offset().bottom;
     let color = cfg.get_margin_color();
     let color = color.bottom.as_ref();
-    print_indent_lines(f, &indent, &offset, color, width)
+    let layers = cfg.get_margin_layers().bottom;
+    print_indent_lines(f, &indent, &offset, color, layers, false, width)
 }
 
 fn print_margin_left<F: Write>(
@@ -802,7 +856,8 @@ fn print_margin_left<F: Write>(
     let offset = cfg.get_margin_offset().left;
     let color = cfg.get_margin_color();
     let color = color.left.as_ref();
-    print_margin_vertical(f, indent, offset, color, line, height)
+    let layers = cfg.get_margin_layers().left;
+    print_margin_vertical(f, indent, offset, color, layers, true, line, height)
 }
 
 fn print_margin_right<F: Write>(
@@ -815,14 +870,18 @@ fn print_margin_right<F: Write>(
     let offset = cfg.get_margin_offset().right;
     let color = cfg.get_margin_color();
     let color = color.right.as_ref();
-    print_margin_vertical(f, indent, offset, color, line, height)
+    let layers = cfg.get_margin_layers().right;
+    print_margin_vertical(f, indent, offset, color, layers, false, line, height)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn print_margin_vertical<F: Write>(
     f: &mut F,
     indent: Indent,
     offset: Offset,
     color: Option<&AnsiColor<'_>>,
+    layers: &[char],
+    reverse: bool,
     line: usize,
     height: usize,
 ) -> fmt::Result {
@@ -834,7 +893,7 @@ fn print_margin_vertical<F: Write>(
         Offset::Begin(offset) => {
             let offset = cmp::min(offset, height);
             if line >= offset {
-                print_indent(f, indent.fill, indent.size, color)?;
+                print_margin_indent(f, &indent, color, layers, reverse)?;
             } else {
                 repeat_char(f, ' ', indent.size)?;
             }
@@ -846,7 +905,7 @@ fn print_margin_vertical<F: Write>(
             if line >= pos {
                 repeat_char(f, ' ', indent.size)?;
             } else {
-                print_indent(f, indent.fill, indent.size, color)?;
+                print_margin_indent(f, &indent, color, layers, reverse)?;
             }
         }
     }
@@ -859,6 +918,8 @@ fn print_indent_lines<F: Write>(
     indent: &Indent,
     offset: &Offset,
     color: Option<&AnsiColor<'_>>,
+    layers: &[char],
+    reverse: bool,
     width: usize,
 ) -> fmt::Result {
     if indent.size == 0 {
@@ -880,7 +941,8 @@ fn print_indent_lines<F: Write>(
         }
 
         if indent_size > 0 {
-            print_indent(f, indent.fill, indent_size, color)?;
+            let c = get_margin_layer_char(indent, layers, i, reverse);
+            print_indent(f, c, indent_size, color)?;
         }
 
         if end_offset > 0 {
@@ -895,6 +957,37 @@ fn print_indent_lines<F: Write>(
     Ok(())
 }
 
+fn print_margin_indent<F: Write>(
+    f: &mut F,
+    indent: &Indent,
+    color: Option<&AnsiColor<'_>>,
+    layers: &[char],
+    reverse: bool,
+) -> fmt::Result {
+    if layers.is_empty() {
+        return print_indent(f, indent.fill, indent.size, color);
+    }
+
+    if let Some(color) = color {
+        color.fmt_prefix(f)?;
+    }
+
+    for i in 0..indent.size {
+        f.write_char(get_margin_layer_char(indent, layers, i, reverse))?;
+    }
+
+    if let Some(color) = color {
+        color.fmt_suffix(f)?;
+    }
+
+    Ok(())
+}
+
+fn get_margin_layer_char(indent: &Indent, layers: &[char], i: usize, reverse: bool) -> char {
+    let layer = if reverse { indent.size - 1 - i } else { i };
+    layers.get(layer).copied().unwrap_or(indent.fill)
+}
+
 fn print_indent<F: Write, C: Color>(f: &mut F, c: char, n: usize, color: Option<C>) -> fmt::Result {
     if n == 0 {
         return Ok(());
@@ -927,7 +1020,7 @@ fn range_width<D: Dimension>(dims: &D, start: usize, end: usize) -> usize {
 
 fn count_verticals_range(cfg: &SpannedConfig, start: usize, end: usize, max: usize) -> usize {
     (start + 1..end)
-        .map(|i| cfg.has_vertical(i, max) as usize)
+        .map(|i| cfg.get_vertical_width(i, max))
         .sum()
 }
 
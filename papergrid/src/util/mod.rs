@@ -1,3 +1,6 @@
 //! A module contains utility functions which grid relay on.
 
 pub mod string;
+
+#[cfg(feature = "std")]
+pub(crate) mod trim_writer;
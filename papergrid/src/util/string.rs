@@ -4,17 +4,68 @@
 //!
 //! [`Grid`]: crate::grid::iterable::Grid
 
+/// Returns a width of a character.
+///
+/// Control characters are considered to have 0 width.
+/// Characters of an ambiguous East Asian width are considered to be narrow,
+/// see [`get_char_width_ambiguous`].
+pub fn get_char_width(c: char) -> usize {
+    get_char_width_ambiguous(c, false)
+}
+
+/// Returns a width of a character,
+/// measuring characters of an ambiguous East Asian width as wide (2 columns) if `as_wide` is set.
+///
+/// Control characters are considered to have 0 width.
+pub fn get_char_width_ambiguous(c: char, as_wide: bool) -> usize {
+    let width = if as_wide {
+        unicode_width::UnicodeWidthChar::width_cjk(c)
+    } else {
+        unicode_width::UnicodeWidthChar::width(c)
+    };
+
+    width.unwrap_or(0)
+}
+
+/// Returns a width of a text as it is,
+/// in contrast to [`string_width`] it does not consider ANSI sequences.
+pub fn get_text_width(text: &str) -> usize {
+    get_text_width_ambiguous(text, false)
+}
+
+/// Returns a width of a text as it is, like [`get_text_width`] does,
+/// measuring characters of an ambiguous East Asian width as wide if `as_wide` is set.
+pub fn get_text_width_ambiguous(text: &str, as_wide: bool) -> usize {
+    if as_wide {
+        unicode_width::UnicodeWidthStr::width_cjk(text)
+    } else {
+        unicode_width::UnicodeWidthStr::width(text)
+    }
+}
+
 /// Returns string width and count lines of a string. It's a combination of [`string_width_multiline`] and [`count_lines`].
 #[cfg(feature = "std")]
 pub fn string_dimension(text: &str) -> (usize, usize) {
+    string_dimension_ambiguous(text, false)
+}
+
+/// Returns string width and count lines of a string, like [`string_dimension`] does,
+/// measuring characters of an ambiguous East Asian width as wide if `as_wide` is set.
+#[cfg(feature = "std")]
+pub fn string_dimension_ambiguous(text: &str, as_wide: bool) -> (usize, usize) {
     #[cfg(not(feature = "color"))]
     {
+        if text.contains("\u{1b}]") || text.contains('\r') {
+            return split_lines(text)
+                .map(|line| string_width_ambiguous(line, as_wide))
+                .fold((0, 0), |(i, acc), width| (i + 1, acc.max(width)));
+        }
+
         let (lines, acc, max) = text.chars().fold((1, 0, 0), |(lines, acc, max), c| {
             if c == '\n' {
                 (lines + 1, 0, acc.max(max))
             } else {
-                let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                (lines, acc + w, max)
+                (lines, acc + get_char_width_ambiguous(c, as_wide), max)
             }
         });
 
@@ -24,16 +75,32 @@ pub fn string_dimension(text: &str) -> (usize, usize) {
     #[cfg(feature = "color")]
     {
         get_lines(text)
-            .map(|line| string_width(&line))
+            .map(|line| string_width_ambiguous(&line, as_wide))
             .fold((0, 0), |(i, acc), width| (i + 1, acc.max(width)))
     }
 }
 
 /// Returns a string width.
 pub fn string_width(text: &str) -> usize {
+    string_width_ambiguous(text, false)
+}
+
+/// Returns a string width, like [`string_width`] does,
+/// measuring characters of an ambiguous East Asian width as wide if `as_wide` is set.
+pub fn string_width_ambiguous(text: &str, as_wide: bool) -> usize {
     #[cfg(not(feature = "color"))]
     {
-        unicode_width::UnicodeWidthStr::width(text)
+        // terminal links are not visible so we skip them,
+        // other escape sequences are handled only with `color` feature.
+
+        let mut width = 0;
+        let mut text = text;
+        while let Some(start) = text.find("\u{1b}]") {
+            width += get_text_width_ambiguous(&text[..start], as_wide);
+            text = skip_osc(&text[start + 2..]);
+        }
+
+        width + get_text_width_ambiguous(text, as_wide)
     }
 
     #[cfg(feature = "color")]
@@ -44,34 +111,85 @@ pub fn string_width(text: &str) -> usize {
         ansitok::parse_ansi(text)
             .filter(|e| e.kind() == ansitok::ElementKind::Text)
             .map(|e| &text[e.start()..e.end()])
-            .map(unicode_width::UnicodeWidthStr::width)
+            .map(|text| get_text_width_ambiguous(text, as_wide))
             .sum()
     }
 }
 
 /// Returns a max string width of a line.
 pub fn string_width_multiline(text: &str) -> usize {
-    #[cfg(not(feature = "color"))]
-    {
-        text.lines()
-            .map(unicode_width::UnicodeWidthStr::width)
-            .max()
-            .unwrap_or(0)
-    }
+    string_width_multiline_ambiguous(text, false)
+}
 
-    #[cfg(feature = "color")]
-    {
-        text.lines().map(string_width).max().unwrap_or(0)
-    }
+/// Returns a max string width of a line, like [`string_width_multiline`] does,
+/// measuring characters of an ambiguous East Asian width as wide if `as_wide` is set.
+pub fn string_width_multiline_ambiguous(text: &str, as_wide: bool) -> usize {
+    split_lines(text)
+        .map(|line| string_width_ambiguous(line, as_wide))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns a text after an OSC sequence, which is terminated by BEL or ST (`ESC \`).
+#[cfg(not(feature = "color"))]
+fn skip_osc(text: &str) -> &str {
+    let bel = text.find('\u{7}').map(|i| i + 1);
+    let st = text.find("\u{1b}\\").map(|i| i + 2);
+
+    let end = match (bel, st) {
+        (Some(bel), Some(st)) => bel.min(st),
+        (Some(end), None) | (None, Some(end)) => end,
+        (None, None) => text.len(),
+    };
+
+    &text[end..]
 }
 
 /// Calculates a number of lines.
+///
+/// `\r\n` and a lone `\r` are counted as a line break same as `\n`.
 pub fn count_lines(s: &str) -> usize {
     if s.is_empty() {
         return 1;
     }
 
-    bytecount::count(s.as_bytes(), b'\n') + 1
+    if !s.contains('\r') {
+        return bytecount::count(s.as_bytes(), b'\n') + 1;
+    }
+
+    split_lines(s).count()
+}
+
+/// Splits a string by `\n`, `\r\n` and a lone `\r`.
+///
+/// In comparison to `str::lines`, it treats trailing line break as a new line.
+pub fn split_lines(text: &str) -> SplitLines<'_> {
+    SplitLines { text: Some(text) }
+}
+
+/// Iterator over lines of a string, returned by [`split_lines`].
+#[derive(Debug, Clone)]
+pub struct SplitLines<'a> {
+    text: Option<&'a str>,
+}
+
+impl<'a> Iterator for SplitLines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.text?;
+        match text.find(&['\n', '\r'][..]) {
+            Some(i) => {
+                let size = if text[i..].starts_with("\r\n") { 2 } else { 1 };
+                self.text = Some(&text[i + size..]);
+                Some(&text[..i])
+            }
+            None => {
+                self.text = None;
+                Some(text)
+            }
+        }
+    }
 }
 
 /// Returns a list of tabs (`\t`) in a string..
@@ -80,21 +198,34 @@ pub fn count_tabs(s: &str) -> usize {
 }
 
 /// Splits the string by lines.
+///
+/// `\r\n` and a lone `\r` are treated as a line break same as `\n`.
 #[cfg(feature = "std")]
 pub fn get_lines(text: &str) -> Lines<'_> {
     #[cfg(not(feature = "color"))]
     {
-        // we call `split()` but not `lines()` in order to match colored implementation
+        // we don't call `lines()` in order to match colored implementation
         // specifically how we treat a trailing '\n' character.
         Lines {
-            inner: text.split('\n'),
+            inner: split_lines(text),
         }
     }
 
     #[cfg(feature = "color")]
     {
+        if text.contains('\r') {
+            let text = text.replace("\r\n", "\n").replace('\r', "\n");
+            let lines = ansi_str::AnsiStr::ansi_split(&text, "\n")
+                .map(|line| std::borrow::Cow::Owned(line.into_owned()))
+                .collect::<Vec<_>>();
+
+            return Lines {
+                inner: LinesInner::Normalized(lines.into_iter()),
+            };
+        }
+
         Lines {
-            inner: ansi_str::AnsiStr::ansi_split(text, "\n"),
+            inner: LinesInner::Split(ansi_str::AnsiStr::ansi_split(text, "\n")),
         }
     }
 }
@@ -106,9 +237,15 @@ pub fn get_lines(text: &str) -> Lines<'_> {
 #[cfg(feature = "std")]
 pub struct Lines<'a> {
     #[cfg(not(feature = "color"))]
-    inner: std::str::Split<'a, char>,
+    inner: SplitLines<'a>,
     #[cfg(feature = "color")]
-    inner: ansi_str::AnsiSplit<'a>,
+    inner: LinesInner<'a>,
+}
+
+#[cfg(feature = "color")]
+enum LinesInner<'a> {
+    Split(ansi_str::AnsiSplit<'a>),
+    Normalized(std::vec::IntoIter<std::borrow::Cow<'a, str>>),
 }
 #[cfg(feature = "std")]
 impl<'a> Iterator for Lines<'a> {
@@ -122,7 +259,10 @@ impl<'a> Iterator for Lines<'a> {
 
         #[cfg(feature = "color")]
         {
-            self.inner.next()
+            match &mut self.inner {
+                LinesInner::Split(split) => split.next(),
+                LinesInner::Normalized(lines) => lines.next(),
+            }
         }
     }
 }
@@ -208,7 +348,6 @@ mod tests {
         assert_eq!(count_lines("now is the time for all good men\n"), 2);
     }
 
-    #[cfg(feature = "color")]
     #[test]
     fn string_width_multinline_for_link() {
         assert_eq!(
@@ -219,7 +358,6 @@ mod tests {
         );
     }
 
-    #[cfg(feature = "color")]
     #[test]
     fn string_width_for_link() {
         assert_eq!(
@@ -249,6 +387,10 @@ mod tests {
             (2, 32)
         );
         assert_eq!(string_dimension("asd"), (1, 3));
+        assert_eq!(
+            string_dimension("\u{1b}]8;;https://example.com\u{7}link\u{1b}]8;;\u{7}\nasd"),
+            (2, 4)
+        );
         assert_eq!(string_dimension(""), (1, 0));
     }
 
@@ -0,0 +1,71 @@
+//! A module contains a [`TrimWriter`] which removes trailing whitespace of lines.
+
+use std::fmt::{self, Write};
+
+/// A writer which removes whitespace at the end of each line.
+///
+/// Whitespace is held back until something else is written on the same line,
+/// so whitespace at the end of the output is dropped as well.
+#[derive(Debug)]
+pub(crate) struct TrimWriter<F> {
+    f: F,
+    buf: String,
+}
+
+impl<F> TrimWriter<F> {
+    /// Creates a new [`TrimWriter`].
+    pub(crate) fn new(f: F) -> Self {
+        Self {
+            f,
+            buf: String::new(),
+        }
+    }
+}
+
+impl<F: Write> Write for TrimWriter<F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (i, part) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.buf.clear();
+                self.f.write_char('\n')?;
+            }
+
+            let text = part.trim_end();
+            if text.is_empty() {
+                self.buf.push_str(part);
+                continue;
+            }
+
+            self.f.write_str(&self.buf)?;
+            self.f.write_str(text)?;
+
+            self.buf.clear();
+            self.buf.push_str(&part[text.len()..]);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trim(parts: &[&str]) -> String {
+        let mut buf = String::new();
+        let mut f = TrimWriter::new(&mut buf);
+        for part in parts {
+            f.write_str(part).unwrap();
+        }
+
+        buf
+    }
+
+    #[test]
+    fn trim_writer_test() {
+        assert_eq!(trim(&["a  ", " b ", " \n", "c", "  "]), "a   b\nc");
+        assert_eq!(trim(&["  a  \n  \n"]), "  a\n\n");
+        assert_eq!(trim(&["|", " ", "|"]), "| |");
+        assert_eq!(trim(&[" ", "\n", " "]), "\n");
+    }
+}
@@ -218,20 +218,24 @@ test_table!(
 );
 
 test_table!(
-    doesnt_render_return_carige_0,
+    return_carige_is_line_break_0,
     grid(2, 2).change_cell((0, 1), "123\r\r\r567").build(),
-    "+---+------+"
-    "|0-0|123\r\r\r567|"
-    "+---+------+"
-    "|1-0|1-1   |"
-    "+---+------+"
+    "+---+---+"
+    "|0-0|123|"
+    "|   |   |"
+    "|   |   |"
+    "|   |567|"
+    "+---+---+"
+    "|1-0|1-1|"
+    "+---+---+"
 );
 
 test_table!(
-    doesnt_render_return_carige_1,
-    grid(2, 2).change_cell((1, 1), "12345678").change_cell((0, 1), "123\r\r\r567").build(),
+    return_carige_is_line_break_1,
+    grid(2, 2).change_cell((1, 1), "12345678").change_cell((0, 1), "123\r\n567").build(),
     "+---+--------+"
-    "|0-0|123\r\r\r567  |"
+    "|0-0|123     |"
+    "|   |567     |"
     "+---+--------+"
     "|1-0|12345678|"
     "+---+--------+"
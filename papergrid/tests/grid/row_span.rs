@@ -600,6 +600,44 @@ test_table!(
     "+---+---+---+---+"
 );
 
+test_table!(
+    _4x4_with_row_span_and_col_span_at_last_column,
+    grid(4, 4)
+        .config(|cfg|{
+            cfg.set_row_span((1, 2), 2);
+            cfg.set_column_span((1, 2), 2);
+        })
+        .build(),
+    "+---+---+---+---+"
+    "|0-0|0-1|0-2|0-3|"
+    "+---+---+---+---+"
+    "|1-0|1-1|1-2    |"
+    "+---+---+       +"
+    "|2-0|2-1|       |"
+    "+---+---+---+---+"
+    "|3-0|3-1|3-2|3-3|"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    _4x4_with_row_span_and_col_span_at_last_cell,
+    grid(4, 4)
+        .config(|cfg|{
+            cfg.set_row_span((2, 2), 2);
+            cfg.set_column_span((2, 2), 2);
+        })
+        .build(),
+    "+---+---+---+---+"
+    "|0-0|0-1|0-2|0-3|"
+    "+---+---+---+---+"
+    "|1-0|1-1|1-2|1-3|"
+    "+---+---+---+---+"
+    "|2-0|2-1|2-2    |"
+    "+---+---+       +"
+    "|3-0|3-1|       |"
+    "+---+---+---+---+"
+);
+
 test_table!(
     _4x4_with_row_span_and_col_span_1,
     grid(4, 4)
@@ -332,3 +332,35 @@ fn grid_2x2_ansi_border_none_if_string_is_not_1_char_test() {
     assert!(AnsiColor::try_from("1".on_red().blue().to_string()).is_ok());
     assert!(AnsiColor::try_from("1".truecolor(0, 1, 3).on_truecolor(1, 2, 3).to_string()).is_ok());
 }
+
+test_table!(
+    grid_3x3_vertical_suffix_test,
+    grid(3, 3)
+        .config(|cfg| cfg.set_vertical_suffix(String::from("|")))
+        .build(),
+    "+---+----+----+"
+    "|0-0||0-1||0-2|"
+    "+---+----+----+"
+    "|1-0||1-1||1-2|"
+    "+---+----+----+"
+    "|2-0||2-1||2-2|"
+    "+---+----+----+"
+);
+
+test_table!(
+    grid_3x3_vertical_suffix_column_span_test,
+    grid(3, 3)
+        .config(|cfg| {
+            cfg.set_vertical_suffix(String::from("::"));
+            cfg.set_column_span((1, 0), 2);
+        })
+        .change_cell((1, 0), "a long spanned cell")
+        .build(),
+    "+--------+----------+-----+"
+    "|0-0     |::0-1     |::0-2|"
+    "+--------+----------+-----+"
+    "|a long spanned cell|::1-2|"
+    "+--------+----------+-----+"
+    "|2-0     |::2-1     |::2-2|"
+    "+--------+----------+-----+"
+);
@@ -0,0 +1,150 @@
+//! The benchmark counts heap allocations instead of time,
+//! to compare a `Table` which owns its records with the same one borrowing them.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{
+    black_box, criterion_group, criterion_main,
+    measurement::{Measurement, ValueFormatter},
+    BenchmarkId, Criterion, Throughput,
+};
+
+use tabled::Table;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// A measurement of a number of heap allocations.
+///
+/// Plots are disabled as the values don't vary between runs.
+pub struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::SeqCst)
+    }
+
+    fn end(&self, i: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::SeqCst) - i
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+pub struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _: f64, _: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        let count = match *throughput {
+            Throughput::Bytes(n) | Throughput::BytesDecimal(n) | Throughput::Elements(n) => n,
+        };
+
+        for value in values {
+            *value /= count as f64;
+        }
+
+        "allocs/cell"
+    }
+
+    fn scale_for_machines(&self, _: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+}
+
+pub fn owned_vs_borrowed_records(c: &mut Criterion<Allocations>) {
+    let mut group = c.benchmark_group("owned_vs_borrowed_records");
+    for size in [1, 64, 1024, 8192] {
+        let data = build_data(size);
+        group.throughput(Throughput::Elements((size * COUNT_COLUMNS) as u64));
+
+        group.bench_with_input(BenchmarkId::new("owned", size), &data, |b, data| {
+            b.iter(|| {
+                let table = Table::new(data);
+                black_box(table.to_string())
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("borrowed", size), &data, |b, data| {
+            b.iter(|| {
+                let table = Table::new_borrowed(data);
+                black_box(table.to_string())
+            });
+        });
+    }
+    group.finish();
+}
+
+const COUNT_COLUMNS: usize = 6;
+
+type Row = (String, String, String, String, String, String);
+
+fn build_data(size: usize) -> Vec<Row> {
+    (0..size)
+        .map(|i| {
+            (
+                format!("cell {i} 0"),
+                format!("cell {i} 1"),
+                format!("cell {i} 2"),
+                format!("cell {i} 3"),
+                format!("cell {i} 4"),
+                format!("cell {i} 5"),
+            )
+        })
+        .collect()
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().with_measurement(Allocations).without_plots();
+    targets = owned_vs_borrowed_records
+}
+criterion_main!(benches);
@@ -48,6 +48,8 @@ pub struct Builder {
     is_consistent: bool,
     /// A content of cells which are created in case rows has different length.
     empty_cell_text: Option<String>,
+    /// A number of rows filled in each column.
+    column_lengths: Vec<usize>,
 }
 
 impl Builder {
@@ -78,8 +80,93 @@ impl Builder {
         b
     }
 
+    /// Creates a [`Builder`] from a list of maps, where each map is a row.
+    ///
+    /// The header is a union of all keys in the order they were first seen.
+    /// Missing keys are filled with empty cells.
+    ///
+    /// Notice that the order is only as stable as an iteration order of the maps,
+    /// for maps like [`HashMap`] use [`Builder::from_sorted_maps`] to get the keys sorted.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tabled::builder::Builder;
+    ///
+    /// let maps = [
+    ///     HashMap::from([("name", "Alice")]),
+    ///     HashMap::from([("age", "42")]),
+    /// ];
+    ///
+    /// let table = Builder::from_maps(maps).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-----+\n\
+    ///      | name  | age |\n\
+    ///      +-------+-----+\n\
+    ///      | Alice |     |\n\
+    ///      +-------+-----+\n\
+    ///      |       | 42  |\n\
+    ///      +-------+-----+"
+    /// );
+    /// ```
+    ///
+    /// [`HashMap`]: std::collections::HashMap
+    pub fn from_maps<I, M, K, V>(maps: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let (keys, rows) = collect_maps(maps);
+        build_from_maps(keys, rows)
+    }
+
+    /// Creates a [`Builder`] from a list of maps, where each map is a row.
+    ///
+    /// The header is a sorted union of all keys.
+    /// Missing keys are filled with empty cells.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tabled::builder::Builder;
+    ///
+    /// let maps = [
+    ///     BTreeMap::from([("name", "Alice")]),
+    ///     BTreeMap::from([("age", "42")]),
+    /// ];
+    ///
+    /// let table = Builder::from_sorted_maps(maps).build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----+-------+\n\
+    ///      | age | name  |\n\
+    ///      +-----+-------+\n\
+    ///      |     | Alice |\n\
+    ///      +-----+-------+\n\
+    ///      | 42  |       |\n\
+    ///      +-----+-------+"
+    /// );
+    /// ```
+    pub fn from_sorted_maps<I, M, K, V>(maps: I) -> Self
+    where
+        I: IntoIterator<Item = M>,
+        M: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let (mut keys, rows) = collect_maps(maps);
+        keys.sort();
+        build_from_maps(keys, rows)
+    }
+
     /// Sets a [`Table`] header.
     ///
+    /// The header is not checked against the records,
+    /// use [`Builder::try_set_header`] for that.
+    ///
     /// ```
     /// # use tabled::builder::Builder;
     /// let mut builder = Builder::default();
@@ -98,6 +185,46 @@ impl Builder {
         self
     }
 
+    /// Sets a [`Table`] header checking that it has the same number of columns as the records.
+    ///
+    /// If there are records and the number of columns doesn't match
+    /// the header is returned back as an error.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["1", "2", "3"]);
+    ///
+    /// assert!(builder.try_set_header(["a", "b"]).is_err());
+    /// assert!(builder.try_set_header(["a", "b", "c"]).is_ok());
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+---+---+---+\n\
+    ///      | a | b | c |\n\
+    ///      +---+---+---+\n\
+    ///      | 1 | 2 | 3 |\n\
+    ///      +---+---+---+"
+    /// );
+    /// ```
+    pub fn try_set_header<H, T>(&mut self, columns: H) -> Result<&mut Self, Vec<String>>
+    where
+        H: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let columns = columns.into_iter().map(Into::into).collect::<Vec<String>>();
+
+        let count_columns = self.data.iter().map(Vec::len).max();
+        if let Some(count_columns) = count_columns {
+            if count_columns != columns.len() {
+                return Err(columns);
+            }
+        }
+
+        Ok(self.set_header(columns))
+    }
+
     /// Sets off a [`Table`] header.
     ///
     /// If not set its a nop.
@@ -218,10 +345,66 @@ impl Builder {
         T: Into<String>,
     {
         let list = create_row(row, self.count_columns);
+        let size = list.len();
 
-        self.update_size(list.len());
+        self.update_size(size);
         self.data.push(list);
 
+        for col in 0..size {
+            self.set_column_length(col, self.data.len());
+        }
+
+        self
+    }
+
+    /// Adds a nested [`Table`] as a cell to the last row.
+    ///
+    /// The table is rendered and its lines become a multiline cell,
+    /// so a column is sized to at least [`Table::total_width`] of the nested table.
+    ///
+    /// If there's no rows a new one is created.
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, settings::Style, Table};
+    ///
+    /// let mut nested = Table::new([["1", "2"]]);
+    /// nested.with(Style::modern());
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.push_record(["nested"]);
+    /// builder.push_cell_table(&nested);
+    ///
+    /// let table = builder.build().to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+--------+-----------+\n\
+    ///      | nested | ┌───┬───┐ |\n\
+    ///      |        | │ 0 │ 1 │ |\n\
+    ///      |        | ├───┼───┤ |\n\
+    ///      |        | │ 1 │ 2 │ |\n\
+    ///      |        | └───┴───┘ |\n\
+    ///      +--------+-----------+"
+    /// )
+    /// ```
+    pub fn push_cell_table(&mut self, table: &Table) -> &mut Self {
+        if self.data.is_empty() {
+            self.data.push(Vec::new());
+        }
+
+        let text = table.to_string();
+
+        let size = match self.data.last_mut() {
+            Some(row) => {
+                row.push(CellInfo::new(text));
+                row.len()
+            }
+            None => return self,
+        };
+
+        self.update_size(size);
+        self.set_column_length(size - 1, self.data.len());
+
         self
     }
 
@@ -236,15 +419,97 @@ impl Builder {
         R::Item: Into<String>,
     {
         let list = create_row(record, self.count_columns);
+        let size = list.len();
 
-        self.update_size(list.len());
+        self.update_size(size);
         self.data.insert(index, list);
 
+        for length in &mut self.column_lengths {
+            if *length > index {
+                *length += 1;
+            }
+        }
+
+        for col in 0..size {
+            let length = std::cmp::max(self.column_length(col), index + 1);
+            self.set_column_length(col, length);
+        }
+
         true
     }
 
+    /// Appends values to an existing column, a value per row.
+    ///
+    /// Values are put right after the last filled cell of the column,
+    /// so columns can be filled independently.
+    /// A cell is considered filled once it's set, even if its text is empty.
+    /// New rows are created if needed, their other cells are left empty.
+    ///
+    /// If the column doesn't exist the values are returned back as an error.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_header(["name", "age"]);
+    ///
+    /// builder.extend_column(0, ["Alice", "Bob"]).unwrap();
+    /// builder.extend_column(1, ["31"]).unwrap();
+    /// builder.extend_column(1, ["27"]).unwrap();
+    ///
+    /// assert!(builder.extend_column(2, ["?"]).is_err());
+    ///
+    /// assert_eq!(
+    ///     builder.build().to_string(),
+    ///     "+-------+-----+\n\
+    ///      | name  | age |\n\
+    ///      +-------+-----+\n\
+    ///      | Alice | 31  |\n\
+    ///      +-------+-----+\n\
+    ///      | Bob   | 27  |\n\
+    ///      +-------+-----+"
+    /// );
+    /// ```
+    pub fn extend_column<I, T>(&mut self, index: usize, values: I) -> Result<&mut Self, Vec<String>>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let values = values.into_iter().map(Into::into).collect::<Vec<String>>();
+        if index >= self.count_columns {
+            return Err(values);
+        }
+
+        let empty = CellInfo::new(self.empty_cell_text.to_owned().unwrap_or_default());
+
+        let start = self.column_length(index);
+        let length = start + values.len();
+
+        for (row, text) in (start..).zip(values) {
+            if row == self.data.len() {
+                self.data.push(Vec::new());
+            }
+
+            let cells = &mut self.data[row];
+            if cells.len() <= index {
+                append_vec(cells, empty.clone(), index + 1 - cells.len());
+            }
+
+            cells[index] = CellInfo::new(text);
+
+            let size = cells.len();
+            self.update_size(size);
+        }
+
+        self.set_column_length(index, length);
+
+        Ok(self)
+    }
+
     /// Clean removes empty columns and rows.
     ///
+    /// It's the same as calling [`Builder::clean_columns`] and [`Builder::clean_rows`].
+    ///
     /// # Example
     ///
     /// ```
@@ -267,11 +532,40 @@ impl Builder {
     /// )
     /// ```
     pub fn clean(&mut self) -> &mut Self {
-        self.clean_columns();
-        self.clean_rows();
+        self.clean_columns().clean_rows()
+    }
+
+    /// Removes all records and a header,
+    /// while keeping the allocated capacity so the builder can be reused.
+    ///
+    /// A default text set by [`Builder::set_default_text`] is kept as well.
+    ///
+    /// ```
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::with_capacity(16);
+    /// builder.push_record(["Hello", "World"]);
+    ///
+    /// builder.clear();
+    ///
+    /// assert_eq!(builder.count_rows(), 0);
+    /// assert_eq!(builder.count_columns(), 0);
+    /// assert!(builder.capacity() >= 16);
+    /// ```
+    pub fn clear(&mut self) -> &mut Self {
+        self.data.clear();
+        self.columns = None;
+        self.column_lengths.clear();
+        self.count_columns = 0;
+        self.is_consistent = false;
         self
     }
 
+    /// Returns an amount of rows the builder can hold without reallocation.
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
     /// Set a column size.
     ///
     /// If it make it lower then it was originally it is considered NOP.
@@ -296,23 +590,23 @@ impl Builder {
         self.columns.is_some()
     }
 
-    fn clean_columns(&mut self) {
+    /// Removes columns which have only empty cells.
+    ///
+    /// A header is not taken into account,
+    /// so a column which has only a name is removed as well.
+    ///
+    /// See [`Builder::clean`].
+    pub fn clean_columns(&mut self) -> &mut Self {
         let mut i = 0;
         for col in 0..self.count_columns {
             let col = col - i;
 
-            let mut is_empty = true;
-            for row in 0..self.data.len() {
-                let cell = &self.data[row][col];
-                if !cell.as_ref().is_empty() {
-                    is_empty = false;
-                    break;
-                }
-            }
-
+            let is_empty = self.data.iter().all(|row| is_empty_cell(row, col));
             if is_empty {
-                for row in 0..self.data.len() {
-                    let _ = self.data[row].remove(col);
+                for row in &mut self.data {
+                    if row.len() > col {
+                        let _ = row.remove(col);
+                    }
                 }
 
                 if let Some(columns) = self.columns.as_mut() {
@@ -321,32 +615,50 @@ impl Builder {
                     }
                 }
 
+                if self.column_lengths.len() > col {
+                    let _ = self.column_lengths.remove(col);
+                }
+
                 i += 1;
             }
         }
 
         self.count_columns -= i;
+        self
     }
 
-    fn clean_rows(&mut self) {
+    /// Removes rows which have only empty cells.
+    ///
+    /// See [`Builder::clean`].
+    pub fn clean_rows(&mut self) -> &mut Self {
+        let count_columns = self.count_columns;
+        let is_empty_row =
+            |row: &[CellInfo<String>]| (0..count_columns).all(|col| is_empty_cell(row, col));
+
         for row in (0..self.data.len()).rev() {
-            let mut is_empty = true;
-            for col in 0..self.count_columns {
-                let cell = &self.data[row][col];
-                if !cell.as_ref().is_empty() {
-                    is_empty = false;
-                    break;
+            if is_empty_row(&self.data[row]) {
+                for length in &mut self.column_lengths {
+                    if *length > row {
+                        *length -= 1;
+                    }
                 }
             }
+        }
 
-            if is_empty {
-                let _ = self.data.remove(row);
-            }
+        self.data.retain(|row| !is_empty_row(row));
+        self
+    }
 
-            if row == 0 {
-                break;
-            }
+    fn column_length(&self, col: usize) -> usize {
+        self.column_lengths.get(col).copied().unwrap_or(0)
+    }
+
+    fn set_column_length(&mut self, col: usize, length: usize) {
+        if self.column_lengths.len() <= col {
+            self.column_lengths.resize(col + 1, 0);
         }
+
+        self.column_lengths[col] = length;
     }
 
     fn update_size(&mut self, size: usize) {
@@ -457,10 +769,11 @@ impl From<Vec<Vec<String>>> for Builder {
     fn from(data: Vec<Vec<String>>) -> Self {
         let count_columns = data.get(0).map_or(0, |row| row.len());
 
-        let data = data
+        let data: Vec<Vec<_>> = data
             .into_iter()
             .map(|row| row.into_iter().map(CellInfo::new).collect())
             .collect();
+        let column_lengths = count_column_lengths(&data);
 
         Self {
             data,
@@ -468,6 +781,7 @@ impl From<Vec<Vec<String>>> for Builder {
             columns: None,
             is_consistent: false,
             empty_cell_text: None,
+            column_lengths,
         }
     }
 }
@@ -475,6 +789,7 @@ impl From<Vec<Vec<String>>> for Builder {
 impl From<Vec<Vec<CellInfo<String>>>> for Builder {
     fn from(data: Vec<Vec<CellInfo<String>>>) -> Self {
         let count_columns = data.get(0).map_or(0, |row| row.len());
+        let column_lengths = count_column_lengths(&data);
 
         Self {
             data,
@@ -482,6 +797,7 @@ impl From<Vec<Vec<CellInfo<String>>>> for Builder {
             columns: None,
             is_consistent: false,
             empty_cell_text: None,
+            column_lengths,
         }
     }
 }
@@ -504,3 +820,64 @@ where
 fn append_vec<T: Clone>(v: &mut Vec<T>, value: T, n: usize) {
     v.extend((0..n).map(|_| value.clone()));
 }
+
+fn count_column_lengths<T>(data: &[Vec<T>]) -> Vec<usize> {
+    let count_columns = data.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..count_columns)
+        .map(|col| {
+            data.iter()
+                .rposition(|row| row.len() > col)
+                .map_or(0, |row| row + 1)
+        })
+        .collect()
+}
+
+fn is_empty_cell(row: &[CellInfo<String>], col: usize) -> bool {
+    row.get(col).map_or(true, |cell| cell.as_ref().is_empty())
+}
+
+fn collect_maps<I, M, K, V>(maps: I) -> (Vec<String>, Vec<Vec<(String, String)>>)
+where
+    I: IntoIterator<Item = M>,
+    M: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<String>,
+{
+    let mut keys: Vec<String> = Vec::new();
+    let mut rows = Vec::new();
+    for map in maps {
+        let mut row = Vec::new();
+        for (key, value) in map {
+            let key = key.into();
+            if !keys.contains(&key) {
+                keys.push(key.clone());
+            }
+
+            row.push((key, value.into()));
+        }
+
+        rows.push(row);
+    }
+
+    (keys, rows)
+}
+
+fn build_from_maps(keys: Vec<String>, rows: Vec<Vec<(String, String)>>) -> Builder {
+    let mut builder = Builder::with_capacity(rows.len());
+    for mut row in rows {
+        let record = keys
+            .iter()
+            .map(|key| match row.iter().position(|(k, _)| k == key) {
+                Some(i) => row.swap_remove(i).1,
+                None => String::new(),
+            })
+            .collect::<Vec<_>>();
+
+        let _ = builder.push_record(record);
+    }
+
+    let _ = builder.set_header(keys);
+
+    builder
+}
@@ -1,8 +1,11 @@
 use std::ops::{Deref, DerefMut};
 
-use crate::grid::{
-    color::AnsiColor,
-    config::{Entity, EntityMap, SpannedConfig},
+use crate::{
+    grid::{
+        color::AnsiColor,
+        config::{Entity, EntityMap, SpannedConfig},
+    },
+    settings::Caption,
 };
 
 /// A spanned configuration plus colors for cells.
@@ -10,6 +13,9 @@ use crate::grid::{
 pub struct ColoredConfig {
     config: SpannedConfig,
     colors: ColorMap,
+    pinned_columns: Vec<usize>,
+    caption_top: Option<Caption>,
+    caption_bottom: Option<Caption>,
 }
 
 impl ColoredConfig {
@@ -18,6 +24,9 @@ impl ColoredConfig {
         Self {
             config,
             colors: ColorMap::default(),
+            pinned_columns: Vec::new(),
+            caption_top: None,
+            caption_bottom: None,
         }
     }
 
@@ -55,6 +64,132 @@ impl ColoredConfig {
         self
     }
 
+    /// Makes a room for a new row at the given index,
+    /// by shifting all settings of the following rows down, colors included.
+    ///
+    /// It's meant to be called together with an insertion of a row into records.
+    pub fn insert_row(&mut self, row: usize) {
+        self.config.insert_row(row);
+
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.insert_row(row);
+        }
+    }
+
+    /// Makes a room for a new column at the given index,
+    /// by shifting all settings of the following columns to the right, colors included.
+    ///
+    /// It's meant to be called together with an insertion of a column into records.
+    pub fn insert_column(&mut self, column: usize) {
+        self.config.insert_column(column);
+
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.insert_column(column);
+        }
+
+        for pinned in &mut self.pinned_columns {
+            if *pinned >= column {
+                *pinned += 1;
+            }
+        }
+    }
+
+    /// Mirrors all settings horizontally, colors and pinned columns included.
+    ///
+    /// It's meant to be called together with a reversal of columns in records.
+    pub fn mirror_columns(&mut self, count_columns: usize) {
+        self.config.mirror_columns(count_columns);
+
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.mirror_columns(count_columns);
+        }
+
+        for pinned in &mut self.pinned_columns {
+            if *pinned < count_columns {
+                *pinned = count_columns - *pinned - 1;
+            }
+        }
+        self.pinned_columns.sort_unstable();
+    }
+
+    /// Keeps only settings of the given columns, colors and pinned columns included,
+    /// moving them to the indexes the columns have in the list.
+    ///
+    /// It's meant to be called together with a selection of columns in records.
+    pub fn select_columns(&mut self, columns: &[usize]) {
+        self.config.select_columns(columns);
+
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.select_columns(columns);
+        }
+
+        self.pinned_columns = self
+            .pinned_columns
+            .iter()
+            .filter_map(|&col| columns.iter().position(|&c| c == col))
+            .collect();
+        self.pinned_columns.sort_unstable();
+    }
+
+    /// Removes all settings of a row at the given index,
+    /// by shifting all settings of the following rows up, colors included.
+    ///
+    /// It's meant to be called together with a removal of a row from records.
+    pub fn remove_row(&mut self, row: usize) {
+        self.config.remove_row(row);
+
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.remove_row(row);
+        }
+    }
+
+    /// Drops colors and settings of all rows starting from the given one,
+    /// while spans which cross the row are cut.
+    ///
+    /// It's meant to be called together with a truncation of records.
+    pub fn truncate_rows(&mut self, count_rows: usize) {
+        if let Some(colors) = self.colors.0.as_mut() {
+            colors.truncate_rows(count_rows);
+        }
+
+        self.config.truncate_rows(count_rows);
+    }
+
+    /// Marks a column as pinned,
+    /// so it's repeated on each table produced by [`Table::split_columns`].
+    ///
+    /// [`Table::split_columns`]: crate::Table::split_columns
+    pub fn pin_column(&mut self, column: usize) {
+        if let Err(i) = self.pinned_columns.binary_search(&column) {
+            self.pinned_columns.insert(i, column);
+        }
+    }
+
+    /// Returns a sorted list of pinned columns.
+    pub fn get_pinned_columns(&self) -> &[usize] {
+        &self.pinned_columns
+    }
+
+    /// Sets a caption which is rendered outside of a table frame,
+    /// replacing a caption set on the same side.
+    pub fn set_caption(&mut self, caption: Caption) {
+        if caption.is_bottom() {
+            self.caption_bottom = Some(caption);
+        } else {
+            self.caption_top = Some(caption);
+        }
+    }
+
+    /// Returns a caption which is rendered above a table frame.
+    pub fn get_caption_top(&self) -> Option<&Caption> {
+        self.caption_top.as_ref()
+    }
+
+    /// Returns a caption which is rendered below a table frame.
+    pub fn get_caption_bottom(&self) -> Option<&Caption> {
+        self.caption_bottom.as_ref()
+    }
+
     /// Returns a list of colors.
     pub fn get_colors(&self) -> &ColorMap {
         &self.colors
@@ -5,7 +5,7 @@ use papergrid::{
 };
 
 use crate::grid::{
-    config::{ColoredConfig, SpannedConfig},
+    config::{ColoredConfig, Entity, SpannedConfig},
     dimension::{Dimension, Estimate},
     records::vec_records::Cell,
 };
@@ -152,3 +152,64 @@ impl<T: AsRef<str> + Cell> Estimate<&VecRecords<T>, ColoredConfig>
         self.estimate(records, cfg.as_ref())
     }
 }
+
+pub(crate) fn dimension_reastimate(
+    dims: &mut CompleteDimensionVecRecords<'_>,
+    widths: Option<Vec<usize>>,
+    heights: Option<Vec<usize>>,
+    hint: Option<Entity>,
+) {
+    let hint = match hint {
+        Some(hint) => hint,
+        None => return,
+    };
+
+    match hint {
+        Entity::Global | Entity::Cell(_, _) => {
+            dims_set_widths(dims, widths);
+            dims_set_heights(dims, heights);
+        }
+        Entity::Column(_) => {
+            dims_set_widths(dims, widths);
+        }
+        Entity::Row(_) => {
+            dims_set_heights(dims, heights);
+        }
+    }
+}
+
+fn dims_set_widths(dims: &mut CompleteDimensionVecRecords<'_>, list: Option<Vec<usize>>) {
+    match list {
+        Some(list) => match dims.get_widths() {
+            Some(widths) => {
+                if widths == list {
+                    dims.clear_width();
+                } else {
+                    dims.set_widths(list);
+                }
+            }
+            None => dims.set_widths(list),
+        },
+        None => {
+            dims.clear_width();
+        }
+    }
+}
+
+fn dims_set_heights(dims: &mut CompleteDimensionVecRecords<'_>, list: Option<Vec<usize>>) {
+    match list {
+        Some(list) => match dims.get_heights() {
+            Some(heights) => {
+                if heights == list {
+                    dims.clear_height();
+                } else {
+                    dims.set_heights(list);
+                }
+            }
+            None => dims.set_heights(list),
+        },
+        None => {
+            dims.clear_height();
+        }
+    }
+}
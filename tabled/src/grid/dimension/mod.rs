@@ -20,6 +20,8 @@ pub use self::{
     peekable_dimension::PeekableDimension,
     static_dimension::{DimensionValue, StaticDimension},
 };
+#[cfg(feature = "std")]
+pub(crate) use complete_dimension_vec_records::dimension_reastimate;
 pub use const_dimension::{ConstDimension, ConstSize};
 pub use papergrid::dimension::{Dimension, Estimate};
 pub use pool_table_dimension::{DimensionPriority, PoolTableDimension};
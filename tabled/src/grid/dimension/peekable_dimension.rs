@@ -66,6 +66,7 @@ mod estimation {
     use papergrid::{
         config::Position,
         records::vec_records::{Cell, CellInfo, VecRecords},
+        util::string::string_width_multiline_ambiguous,
     };
 
     use super::*;
@@ -91,7 +92,7 @@ mod estimation {
                 }
 
                 let height = cell.count_lines();
-                let width = cell.width();
+                let width = get_cell_width(cell, cfg);
 
                 let pad = cfg.get_padding(pos.into());
                 let width = width + pad.left.size + pad.right.size;
@@ -266,8 +267,8 @@ mod estimation {
     fn count_vertical_borders(cfg: &SpannedConfig, len: usize, start: usize, end: usize) -> usize {
         (start..end)
             .skip(1)
-            .filter(|&i| cfg.has_vertical(i, len))
-            .count()
+            .map(|i| cfg.get_vertical_width(i, len))
+            .sum()
     }
 
     pub(super) fn build_height<T: AsRef<str>>(
@@ -302,6 +303,15 @@ mod estimation {
         heights
     }
 
+    fn get_cell_width<T: AsRef<str>>(cell: &CellInfo<T>, cfg: &SpannedConfig) -> usize {
+        // a cached width is measured with ambiguous characters being narrow.
+        if cfg.is_ambiguous_width_wide() {
+            string_width_multiline_ambiguous(cell.text(), true)
+        } else {
+            cell.width()
+        }
+    }
+
     pub(super) fn build_width<T: AsRef<str>>(
         records: &VecRecords<CellInfo<T>>,
         cfg: &SpannedConfig,
@@ -318,7 +328,7 @@ mod estimation {
                     continue;
                 }
 
-                let width = cell.width();
+                let width = get_cell_width(cell, cfg);
                 match cfg.get_column_span(pos) {
                     Some(n) if n > 1 => {
                         let _ = vspans.insert(pos, (n, width));
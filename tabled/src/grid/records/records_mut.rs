@@ -1,3 +1,6 @@
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
 use crate::grid::config::Position;
 #[cfg(feature = "std")]
 use crate::grid::records::vec_records::{CellInfo, VecRecords};
@@ -32,3 +35,60 @@ impl RecordsMut<&str> for VecRecords<CellInfo<String>> {
         self[row][col] = CellInfo::new(text.to_string());
     }
 }
+
+/// Records which borrow their content.
+///
+/// A cell is copied only when it's being changed,
+/// while a borrowed text can be set without allocation.
+///
+/// The records are used by a [`Table`] built by [`Table::new_borrowed`],
+/// while they can be rendered by a grid directly as well.
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use tabled::grid::{
+///     config::SpannedConfig,
+///     colors::NoColors,
+///     dimension::{CompleteDimension, Estimate},
+///     records::{vec_records::{CellInfo, VecRecords}, RecordsMut},
+///     PeekableGrid,
+/// };
+///
+/// let data = [["Hello", "World"], ["Borrowed", "records"]];
+/// let data = data
+///     .iter()
+///     .map(|row| row.iter().map(|s| CellInfo::new(Cow::Borrowed(*s))).collect())
+///     .collect();
+///
+/// let mut records: VecRecords<CellInfo<Cow<'_, str>>> = VecRecords::new(data);
+/// records.set((1, 1), String::from("cells"));
+///
+/// let cfg = SpannedConfig::default();
+/// let mut dims = CompleteDimension::default();
+/// dims.estimate(&records, &cfg);
+///
+/// let grid = PeekableGrid::new(&records, &cfg, &dims, NoColors).to_string();
+///
+/// assert_eq!(
+///     grid,
+///     "Hello   World\n\
+///      Borrowedcells",
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::new_borrowed`]: crate::Table::new_borrowed
+#[cfg(feature = "std")]
+impl RecordsMut<String> for VecRecords<CellInfo<Cow<'_, str>>> {
+    fn set(&mut self, (row, col): Position, text: String) {
+        self[row][col] = CellInfo::new(Cow::Owned(text));
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> RecordsMut<&'a str> for VecRecords<CellInfo<Cow<'a, str>>> {
+    fn set(&mut self, (row, col): Position, text: &'a str) {
+        self[row][col] = CellInfo::new(Cow::Borrowed(text));
+    }
+}
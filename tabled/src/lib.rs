@@ -296,7 +296,10 @@ pub mod grid;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub use crate::{tabled::Tabled, tables::Table};
+pub use crate::{
+    tabled::{IntoTable, Tabled},
+    tables::Table,
+};
 
 /// A derive to implement a [`Tabled`] trait.
 ///
@@ -419,6 +422,22 @@ pub use crate::{tabled::Tabled, tables::Table};
 /// }
 /// ```
 ///
+/// A map field can be displayed using `#[tabled(map)]`.
+/// Its entries are put into a cell line by line as `key: value`, sorted by key,
+/// so the output doesn't depend on an iteration order of a map.
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+/// use tabled::Tabled;
+///
+/// #[derive(Tabled)]
+/// pub struct Service {
+///     pub name: &'static str,
+///     #[tabled(map)]
+///     pub labels: HashMap<String, String>,
+/// }
+/// ```
+///
 /// ### Format headers
 ///
 /// Beside `#[tabled(rename = "")]` you can change a format of a column name using
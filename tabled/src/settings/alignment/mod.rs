@@ -111,6 +111,29 @@ impl Alignment {
 
     /// Center constructs a horizontal alignment to [`AlignmentHorizontal::Center`]
     ///
+    /// An indent is calculated from a display width of a text, so wide characters are centered correctly.
+    /// If the space can't be split evenly the extra space is put on the right.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Alignment, Padding}};
+    ///
+    /// let mut table = Table::new([["你好"], ["a你"], ["123456"]]);
+    /// table.with(Alignment::center()).with(Padding::zero());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+\n\
+    ///      |  0   |\n\
+    ///      +------+\n\
+    ///      | 你好 |\n\
+    ///      +------+\n\
+    ///      | a你  |\n\
+    ///      +------+\n\
+    ///      |123456|\n\
+    ///      +------+"
+    /// );
+    /// ```
+    ///
     /// ## Notice
     ///
     /// When you use [`MinWidth`] the alignment might not work as you expected.
@@ -137,6 +160,24 @@ impl Alignment {
         Self::vertical(AlignmentVertical::Center)
     }
 
+    /// Decimal constructs a [`DecimalAlignment`] which aligns numbers by a decimal point.
+    ///
+    /// [`DecimalAlignment`]: crate::settings::formatting::DecimalAlignment
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const fn decimal() -> crate::settings::formatting::DecimalAlignment {
+        crate::settings::formatting::DecimalAlignment::new()
+    }
+
+    /// Auto constructs an [`AutoAlignment`] which aligns numbers to the right and text to the left.
+    ///
+    /// [`AutoAlignment`]: crate::settings::formatting::AutoAlignment
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const fn auto() -> crate::settings::formatting::AutoAlignment {
+        crate::settings::formatting::AutoAlignment::new()
+    }
+
     /// Returns an alignment with the given horizontal alignment.
     const fn horizontal(alignment: AlignmentHorizontal) -> Self {
         Self::new(Horizontal(alignment))
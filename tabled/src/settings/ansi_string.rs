@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use crate::{
+    grid::util::string::string_width_multiline,
+    settings::width::util::{cut_str, cut_str_rest},
+};
+
+/// [`AnsiStr`] is a wrapper around a string which works with its visible width.
+///
+/// A length of a string in bytes tells little about how it's displayed,
+/// because of ANSI escape sequences (with a `color` feature) and wide unicode characters.
+/// [`AnsiStr`] measures and cuts a string the same way as a [`Table`] does.
+///
+/// A character which is split in 2 is replaced by a `\u{FFFD}`.
+///
+/// # Example
+///
+/// ```
+/// use tabled::settings::AnsiStr;
+///
+/// let text = AnsiStr::new("Hello World");
+///
+/// assert_eq!(text.width(), 11);
+/// assert_eq!(text.truncate_to(5), "Hello");
+/// assert_eq!(text.split_at_width(6), ("Hello ".into(), "World".into()));
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AnsiStr<'a>(&'a str);
+
+impl<'a> AnsiStr<'a> {
+    /// Creates a new [`AnsiStr`] from a string.
+    pub const fn new(text: &'a str) -> Self {
+        Self(text)
+    }
+
+    /// Returns an original string.
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Returns a visible width of a string.
+    ///
+    /// For a multiline string the width of its widest line is returned.
+    pub fn width(&self) -> usize {
+        string_width_multiline(self.0)
+    }
+
+    /// Cuts a string to a given visible width, keeping its ANSI sequences.
+    ///
+    /// It's meant to be used for a single line string.
+    pub fn truncate_to(&self, width: usize) -> Cow<'a, str> {
+        cut_str(self.0, width, false)
+    }
+
+    /// Splits a string at a given visible width, keeping ANSI sequences on both sides.
+    ///
+    /// It's meant to be used for a single line string.
+    pub fn split_at_width(&self, width: usize) -> (Cow<'a, str>, Cow<'a, str>) {
+        (
+            cut_str(self.0, width, false),
+            cut_str_rest(self.0, width, false),
+        )
+    }
+}
+
+impl<'a> From<&'a str> for AnsiStr<'a> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl AsRef<str> for AnsiStr<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
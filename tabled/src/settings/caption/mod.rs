@@ -0,0 +1,213 @@
+//! This module contains a [`Caption`] setting which puts a text outside of a [`Table`] frame.
+//!
+//! [`Table`]: crate::Table
+
+use core::fmt;
+
+use crate::{
+    grid::{
+        config::{AlignmentHorizontal, ColoredConfig, Entity},
+        dimension::{CompleteDimensionVecRecords, Dimension, Estimate},
+        records::{
+            vec_records::{CellInfo, VecRecords},
+            ExactRecords, Records,
+        },
+        util::string::{get_lines, string_width_ambiguous, string_width_multiline_ambiguous},
+    },
+    settings::{TableOption, Width},
+};
+
+/// Caption renders a text above or below a table frame.
+///
+/// In comparison to [`Panel`] the caption has no borders
+/// and it's aligned to the width of the whole table.
+/// Multiline captions are supported.
+///
+/// A caption doesn't change widths of columns.
+/// If it's wider than the table it overflows the table,
+/// unless [`Caption::widen`] is set in which case the table is stretched.
+///
+/// The caption is kept apart from cells, so settings applied afterwards don't affect it.
+/// A table has one caption above and one below it,
+/// so a new caption replaces a previous one on the same side.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Caption, Style}};
+///
+/// let data = [("Apple", 3), ("Orange", 12)];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(Caption::new("Fruits").center())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "    Fruits    \n",
+///         " &str   | i32 \n",
+///         "--------+-----\n",
+///         " Apple  | 3   \n",
+///         " Orange | 12  ",
+///     )
+/// );
+/// ```
+///
+/// [`Panel`]: crate::settings::Panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Caption {
+    text: String,
+    bottom: bool,
+    alignment: AlignmentHorizontal,
+    widen: bool,
+}
+
+impl Caption {
+    /// Creates a new [`Caption`] which is rendered above a table and aligned to the left.
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            bottom: false,
+            alignment: AlignmentHorizontal::Left,
+            widen: false,
+        }
+    }
+
+    /// Renders the caption above a table.
+    pub fn top(mut self) -> Self {
+        self.bottom = false;
+        self
+    }
+
+    /// Renders the caption below a table.
+    pub fn bottom(mut self) -> Self {
+        self.bottom = true;
+        self
+    }
+
+    /// Aligns the caption to the left.
+    pub fn left(mut self) -> Self {
+        self.alignment = AlignmentHorizontal::Left;
+        self
+    }
+
+    /// Aligns the caption to the center.
+    pub fn center(mut self) -> Self {
+        self.alignment = AlignmentHorizontal::Center;
+        self
+    }
+
+    /// Aligns the caption to the right.
+    pub fn right(mut self) -> Self {
+        self.alignment = AlignmentHorizontal::Right;
+        self
+    }
+
+    /// Sets whether a table is stretched to the caption width in case the caption is wider.
+    ///
+    /// By default the caption overflows the table.
+    pub fn widen(mut self, on: bool) -> Self {
+        self.widen = on;
+        self
+    }
+}
+
+impl Caption {
+    pub(crate) fn is_bottom(&self) -> bool {
+        self.bottom
+    }
+
+    pub(crate) fn width(&self, ambiguous_wide: bool) -> usize {
+        string_width_multiline_ambiguous(&self.text, ambiguous_wide)
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        get_lines(&self.text).count()
+    }
+
+    /// Prints the caption aligned to a given width,
+    /// filling the rest of each line with spaces.
+    pub(crate) fn print<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        width: usize,
+        ambiguous_wide: bool,
+    ) -> fmt::Result {
+        for (i, line) in get_lines(&self.text).enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+
+            let line_width = string_width_ambiguous(&line, ambiguous_wide);
+            let rest = width.saturating_sub(line_width);
+            let indent = match self.alignment {
+                AlignmentHorizontal::Left => 0,
+                AlignmentHorizontal::Center => rest / 2,
+                AlignmentHorizontal::Right => rest,
+            };
+
+            repeat_char(f, ' ', indent)?;
+            f.write_str(&line)?;
+            repeat_char(f, ' ', rest - indent)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TableOption<VecRecords<CellInfo<String>>, CompleteDimensionVecRecords<'a>, ColoredConfig>
+    for Caption
+{
+    fn change(
+        self,
+        records: &mut VecRecords<CellInfo<String>>,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'a>,
+    ) {
+        if self.widen && records.count_rows() > 0 && records.count_columns() > 0 {
+            let caption_width = self.width(cfg.is_ambiguous_width_wide());
+            if caption_width > total_width(records, cfg, dims) {
+                Width::increase(caption_width).change(records, cfg, dims);
+            }
+        }
+
+        cfg.set_caption(self);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        if self.widen {
+            Some(Entity::Global)
+        } else {
+            None
+        }
+    }
+}
+
+fn total_width(
+    records: &VecRecords<CellInfo<String>>,
+    cfg: &ColoredConfig,
+    dims: &CompleteDimensionVecRecords<'_>,
+) -> usize {
+    let mut dims = dims.clone();
+    dims.estimate(records, cfg.as_ref());
+
+    let count_columns = records.count_columns();
+    let margin = cfg.get_margin();
+
+    (0..count_columns)
+        .map(|col| dims.get_width(col))
+        .sum::<usize>()
+        + cfg.count_vertical(count_columns)
+        + margin.left.size
+        + margin.right.size
+}
+
+fn repeat_char<F: fmt::Write>(f: &mut F, c: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
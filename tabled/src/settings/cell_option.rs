@@ -5,11 +5,66 @@ use crate::grid::{
 
 /// A trait for configuring a single cell.
 ///
-/// ~~~~ Where cell represented by 'row' and 'column' indexes. ~~~~
+/// Where cell represented by 'row' and 'column' indexes.
+/// A set of cells is given as an [`Entity`],
+/// use [`Entity::iter`] to go through every cell it covers.
 ///
-/// A cell can be targeted by [`Cell`].
+/// It's applied through [`Modify`] or [`Table::modify`],
+/// where cells are located by an [`Object`], like [`Cell`] or [`Rows`].
 ///
-/// [`Cell`]: crate::object::Cell
+/// # Example
+///
+/// A custom option which puts a content into brackets.
+///
+/// ```
+/// use tabled::{
+///     grid::config::Entity,
+///     grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+///     settings::{object::Rows, CellOption, Style},
+///     Table,
+/// };
+///
+/// #[derive(Clone)]
+/// struct Brackets;
+///
+/// impl<R, C> CellOption<R, C> for Brackets
+/// where
+///     R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+/// {
+///     fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+///         let count_rows = records.count_rows();
+///         let count_cols = records.count_columns();
+///
+///         for pos in entity.iter(count_rows, count_cols) {
+///             let text = format!("[{}]", records.get_text(pos));
+///             records.set(pos, text);
+///         }
+///     }
+/// }
+///
+/// let data = [[1, 2], [3, 4]];
+///
+/// let table = Table::new(data)
+///     .with(Style::markdown())
+///     .modify(Rows::new(1..), Brackets)
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "| 0   | 1   |\n",
+///         "|-----|-----|\n",
+///         "| [1] | [2] |\n",
+///         "| [3] | [4] |",
+///     ),
+/// );
+/// ```
+///
+/// [`Modify`]: crate::settings::Modify
+/// [`Table::modify`]: crate::Table::modify
+/// [`Object`]: crate::settings::object::Object
+/// [`Cell`]: crate::settings::object::Cell
+/// [`Rows`]: crate::settings::object::Rows
 pub trait CellOption<R, C> {
     /// Modification function of a certail part of a grid targeted by [`Entity`].
     fn change(self, records: &mut R, cfg: &mut C, entity: Entity);
@@ -33,6 +88,21 @@ pub trait CellOption<R, C> {
     fn hint_change(&self) -> Option<Entity> {
         Some(Entity::Global)
     }
+
+    /// Modification function of a list of entities which are treated as a single region.
+    ///
+    /// It gives a chance to handle a target as a whole,
+    /// like setting a border only around a perimeter of a region.
+    ///
+    /// By default [`CellOption::change`] is called for each entity separately.
+    fn change_region(self, records: &mut R, cfg: &mut C, entities: &[Entity])
+    where
+        Self: Sized + Clone,
+    {
+        for &entity in entities {
+            self.clone().change(records, cfg, entity);
+        }
+    }
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,152 @@
+//! This module contains a [`Direction`] setting which sets a layout direction of a [`Table`].
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use tabled::{Table, settings::Direction};
+//!
+//! let data = [["Hello", "1"], ["World!", "2"]];
+//!
+//! let table = Table::new(data).with(Direction::Rtl).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         "+---+--------+\n",
+//!         "| 1 |      0 |\n",
+//!         "+---+--------+\n",
+//!         "| 1 |  Hello |\n",
+//!         "+---+--------+\n",
+//!         "| 2 | World! |\n",
+//!         "+---+--------+",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::config::{Borders, ColoredConfig, HorizontalLine},
+    grid::records::{ExactRecords, Records, Resizable},
+    settings::TableOption,
+};
+
+/// Direction sets a layout direction of a [`Table`].
+///
+/// [`Direction::Rtl`] mirrors a table, which is handy for right-to-left languages.
+/// The columns are rendered in a reversed order,
+/// a default alignment is flipped and borders are mirrored.
+///
+/// It changes only the layout, bidi text shaping is not done.
+///
+/// Settings applied to particular columns or cells are moved together with them,
+/// while column indexes of consequent settings refer to the reversed order.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    /// Left-to-right layout, which is a default one.
+    ///
+    /// It doesn't change anything.
+    Ltr,
+    /// Right-to-left layout.
+    ///
+    /// Applying it twice restores the original layout.
+    Rtl,
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Direction
+where
+    R: Records + ExactRecords + Resizable,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self == Self::Ltr {
+            return;
+        }
+
+        let count_columns = records.count_columns();
+        for col in 0..count_columns / 2 {
+            records.swap_column(col, count_columns - col - 1);
+        }
+
+        cfg.mirror_columns(count_columns);
+
+        let borders = mirror_chars(*cfg.get_borders());
+        cfg.set_borders(borders);
+
+        for (row, line) in cfg.get_horizontal_lines() {
+            let line = HorizontalLine {
+                main: line.main.map(mirror_char),
+                intersection: line.intersection.map(mirror_char),
+                left: line.left.map(mirror_char),
+                right: line.right.map(mirror_char),
+            };
+
+            cfg.insert_horizontal_line(row, line);
+        }
+    }
+}
+
+fn mirror_chars(borders: Borders<char>) -> Borders<char> {
+    let mirror = |c: Option<char>| c.map(mirror_char);
+
+    Borders {
+        top: mirror(borders.top),
+        top_left: mirror(borders.top_left),
+        top_right: mirror(borders.top_right),
+        top_intersection: mirror(borders.top_intersection),
+        bottom: mirror(borders.bottom),
+        bottom_left: mirror(borders.bottom_left),
+        bottom_right: mirror(borders.bottom_right),
+        bottom_intersection: mirror(borders.bottom_intersection),
+        horizontal: mirror(borders.horizontal),
+        vertical: mirror(borders.vertical),
+        intersection: mirror(borders.intersection),
+        left: mirror(borders.left),
+        left_intersection: mirror(borders.left_intersection),
+        right: mirror(borders.right),
+        right_intersection: mirror(borders.right_intersection),
+    }
+}
+
+fn mirror_char(c: char) -> char {
+    const PAIRS: [(char, char); 24] = [
+        ('┌', '┐'),
+        ('└', '┘'),
+        ('├', '┤'),
+        ('┏', '┓'),
+        ('┗', '┛'),
+        ('┣', '┫'),
+        ('╔', '╗'),
+        ('╚', '╝'),
+        ('╠', '╣'),
+        ('╒', '╕'),
+        ('╘', '╛'),
+        ('╞', '╡'),
+        ('╓', '╖'),
+        ('╙', '╜'),
+        ('╟', '╢'),
+        ('╭', '╮'),
+        ('╰', '╯'),
+        ('▌', '▐'),
+        ('[', ']'),
+        ('(', ')'),
+        ('{', '}'),
+        ('<', '>'),
+        ('/', '\\'),
+        ('▏', '▕'),
+    ];
+
+    for (l, r) in PAIRS {
+        if c == l {
+            return r;
+        }
+
+        if c == r {
+            return l;
+        }
+    }
+
+    c
+}
@@ -0,0 +1,156 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// [`Hyperlink`] makes a cell content a terminal hyperlink,
+/// by wrapping it into OSC 8 escape sequences.
+///
+/// The sequences are not visible, so they don't affect a cell width.
+/// Each line of a multiline cell is wrapped separately.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{format::Hyperlink, Modify}};
+///
+/// let data = [["tabled"]];
+///
+/// let mut table = Table::new(data);
+/// table.with(Modify::new((1, 0)).with(Hyperlink::new("https://github.com/zhiburt/tabled")));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+\n\
+///      | 0      |\n\
+///      +--------+\n\
+///      | \u{1b}]8;;https://github.com/zhiburt/tabled\u{1b}\\tabled\u{1b}]8;;\u{1b}\\ |\n\
+///      +--------+"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hyperlink<S> {
+    url: S,
+}
+
+impl<S> Hyperlink<S> {
+    /// Creates a new [`Hyperlink`] to a given url.
+    pub fn new(url: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self { url }
+    }
+}
+
+impl Hyperlink<()> {
+    /// Creates a new [`HyperlinkFn`] which builds a url from a cell content.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{format::Hyperlink, object::Rows, Modify}};
+    ///
+    /// let data = [["README.md"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Modify::new(Rows::new(1..)).with(Hyperlink::wrap(|text| format!("file:///{text}"))));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-----------+\n\
+    ///      | 0         |\n\
+    ///      +-----------+\n\
+    ///      | \u{1b}]8;;file:///README.md\u{1b}\\README.md\u{1b}]8;;\u{1b}\\ |\n\
+    ///      +-----------+"
+    /// );
+    /// ```
+    pub fn wrap<F>(f: F) -> HyperlinkFn<F>
+    where
+        F: FnMut(&str) -> String,
+    {
+        HyperlinkFn { f }
+    }
+}
+
+impl<S, R, D, C> TableOption<R, D, C> for Hyperlink<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<S, R, C> CellOption<R, C> for Hyperlink<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let url = self.url.as_ref();
+
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let text = make_link(records.get_text(pos), url);
+            records.set(pos, text);
+        }
+    }
+}
+
+/// [`HyperlinkFn`] makes a cell content a terminal hyperlink,
+/// with a url built from the content by a function.
+///
+/// It's created by [`Hyperlink::wrap`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HyperlinkFn<F> {
+    f: F,
+}
+
+impl<F, R, D, C> TableOption<R, D, C> for HyperlinkFn<F>
+where
+    F: FnMut(&str) -> String,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<F, R, C> CellOption<R, C> for HyperlinkFn<F>
+where
+    F: FnMut(&str) -> String,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(mut self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let text = records.get_text(pos);
+            let url = (self.f)(text);
+            let text = make_link(text, &url);
+
+            records.set(pos, text);
+        }
+    }
+}
+
+fn make_link(text: &str, url: &str) -> String {
+    text.split('\n')
+        .map(|line| format!("\u{1b}]8;;{url}\u{1b}\\{line}\u{1b}]8;;\u{1b}\\"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
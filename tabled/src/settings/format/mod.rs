@@ -5,10 +5,16 @@
 mod format_config;
 mod format_content;
 mod format_positioned;
+mod hyperlink;
+mod number_format;
+mod zero_pad;
 
 pub use format_config::FormatConfig;
 pub use format_content::FormatContent;
 pub use format_positioned::FormatContentPositioned;
+pub use hyperlink::{Hyperlink, HyperlinkFn};
+pub use number_format::NumberFormat;
+pub use zero_pad::ZeroPad;
 
 /// A formatting function of particular cells on a [`Table`].
 ///
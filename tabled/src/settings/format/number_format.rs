@@ -0,0 +1,293 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// [`NumberFormat`] reformats numeric cells,
+/// grouping digits of an integer part and setting a precision of a fractional part.
+///
+/// Cells which are not numbers are left untouched.
+/// Numbers in a scientific notation, like `1.5e3`, are written out in full.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{format::NumberFormat, object::Columns, Modify, Style}};
+///
+/// let data = vec![("Q1", "1234567.891"), ("Q2", "-9876.5"), ("Q3", "n/a")];
+///
+/// let table = Table::new(&data)
+///     .with(Style::markdown())
+///     .with(Modify::new(Columns::single(1)).with(NumberFormat::new().precision(2)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| &str | &str         |\n\
+///      |------|--------------|\n\
+///      | Q1   | 1,234,567.89 |\n\
+///      | Q2   | -9,876.50    |\n\
+///      | Q3   | n/a          |"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumberFormat {
+    grouping: Option<char>,
+    decimal: char,
+    precision: Option<usize>,
+}
+
+impl NumberFormat {
+    /// Creates a [`NumberFormat`] which uses `,` as a grouping character
+    /// and `.` as a decimal point, keeping a fractional part as it is.
+    pub const fn new() -> Self {
+        Self {
+            grouping: Some(','),
+            decimal: '.',
+            precision: None,
+        }
+    }
+
+    /// Sets a character which separates each 3 digits of an integer part.
+    pub const fn grouping(mut self, c: char) -> Self {
+        self.grouping = Some(c);
+        self
+    }
+
+    /// Sets a thousands separator.
+    ///
+    /// It's the same as [`NumberFormat::grouping`].
+    ///
+    /// ```
+    /// use tabled::settings::format::NumberFormat;
+    ///
+    /// let format = NumberFormat::new().thousands(',').decimals(2);
+    ///
+    /// assert_eq!(format.format("1234567.5").as_deref(), Some("1,234,567.50"));
+    /// assert_eq!(format.format("-1.5e3").as_deref(), Some("-1,500.00"));
+    /// assert_eq!(format.format("text"), None);
+    /// ```
+    pub const fn thousands(self, c: char) -> Self {
+        self.grouping(c)
+    }
+
+    /// Turns off digit grouping.
+    pub const fn no_grouping(mut self) -> Self {
+        self.grouping = None;
+        self
+    }
+
+    /// Sets a character which is used as a decimal point.
+    pub const fn decimal(mut self, c: char) -> Self {
+        self.decimal = c;
+        self
+    }
+
+    /// Sets a fixed number of digits after a decimal point.
+    ///
+    /// The value is rounded half up if it has more digits,
+    /// and padded with zeros if it has less.
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets a fixed number of digits after a decimal point.
+    ///
+    /// It's the same as [`NumberFormat::precision`].
+    pub const fn decimals(self, count: usize) -> Self {
+        self.precision(count)
+    }
+
+    /// Formats a given text if it's a number.
+    ///
+    /// Returns [`None`] if the text is not a number.
+    pub fn format(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        let number = Number::parse(text).or_else(|| Number::parse_scientific(text))?;
+        let number = match self.precision {
+            Some(precision) => number.round(precision),
+            None => number,
+        };
+
+        let mut buf = String::new();
+        if number.negative && !number.is_zero() {
+            buf.push('-');
+        }
+
+        let count_digits = number.integer.len();
+        for (i, c) in number.integer.chars().enumerate() {
+            let left = count_digits - i;
+            if i > 0 && left % 3 == 0 {
+                if let Some(grouping) = self.grouping {
+                    buf.push(grouping);
+                }
+            }
+
+            buf.push(c);
+        }
+
+        if !number.fraction.is_empty() {
+            buf.push(self.decimal);
+            buf.push_str(&number.fraction);
+        }
+
+        Some(buf)
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, D, C> TableOption<R, D, C> for NumberFormat
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<R, C> CellOption<R, C> for NumberFormat
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let content = records.get_text(pos);
+            if let Some(content) = self.format(content) {
+                records.set(pos, content);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct Number {
+    negative: bool,
+    integer: String,
+    fraction: String,
+}
+
+impl Number {
+    pub(super) fn parse(text: &str) -> Option<Self> {
+        let (negative, text) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+
+        let (integer, fraction) = match text.split_once('.') {
+            Some((integer, fraction)) => (integer, fraction),
+            None => (text, ""),
+        };
+
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+            return None;
+        }
+
+        Some(Self {
+            negative,
+            integer: integer.to_owned(),
+            fraction: fraction.to_owned(),
+        })
+    }
+
+    /// Parses a number like `-1.5e3`, shifting its decimal point by the exponent.
+    fn parse_scientific(text: &str) -> Option<Self> {
+        const MAX_EXPONENT: i64 = 308;
+
+        let (mantissa, exponent) = text.split_once(['e', 'E'])?;
+        let mut number = Self::parse(mantissa)?;
+
+        let exponent = exponent.strip_prefix('+').unwrap_or(exponent);
+        let exponent = exponent.parse::<i64>().ok()?;
+        if exponent.abs() > MAX_EXPONENT {
+            return None;
+        }
+
+        let point = number.integer.len() as i64 + exponent;
+
+        let mut digits = std::mem::take(&mut number.integer);
+        digits.push_str(&number.fraction);
+
+        if point <= 0 {
+            let zeros = point.unsigned_abs() as usize;
+            number.integer = String::from("0");
+            number.fraction = "0".repeat(zeros) + &digits;
+        } else if point as usize >= digits.len() {
+            let zeros = point as usize - digits.len();
+            number.integer = digits + &"0".repeat(zeros);
+            number.fraction = String::new();
+        } else {
+            number.fraction = digits.split_off(point as usize);
+            number.integer = digits;
+        }
+
+        let leading_zeros = number.integer.len() - number.integer.trim_start_matches('0').len();
+        let leading_zeros = leading_zeros.min(number.integer.len() - 1);
+        let _ = number.integer.drain(..leading_zeros);
+
+        let fraction_len = number.fraction.trim_end_matches('0').len();
+        number.fraction.truncate(fraction_len);
+
+        Some(number)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.integer
+            .bytes()
+            .chain(self.fraction.bytes())
+            .all(|b| b == b'0')
+    }
+
+    fn round(mut self, precision: usize) -> Self {
+        if self.fraction.len() <= precision {
+            let zeros = precision - self.fraction.len();
+            self.fraction.extend(std::iter::repeat('0').take(zeros));
+            return self;
+        }
+
+        let round_up = self.fraction.as_bytes()[precision] >= b'5';
+        self.fraction.truncate(precision);
+
+        if round_up {
+            let mut digits = self.integer.into_bytes();
+            digits.extend(self.fraction.bytes());
+
+            let mut carry = true;
+            for d in digits.iter_mut().rev() {
+                if *d == b'9' {
+                    *d = b'0';
+                } else {
+                    *d += 1;
+                    carry = false;
+                    break;
+                }
+            }
+
+            if carry {
+                digits.insert(0, b'1');
+            }
+
+            let fraction = digits.split_off(digits.len() - precision);
+            self.integer = String::from_utf8(digits).expect("ascii digits");
+            self.fraction = String::from_utf8(fraction).expect("ascii digits");
+        }
+
+        self
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+use super::number_format::Number;
+
+/// [`ZeroPad`] left pads numeric cells with `0` up to a given width.
+///
+/// A sign is kept in front of zeros and counted in the width.
+/// Cells which are not numbers or which are already wide enough are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{format::ZeroPad, object::Columns, Modify, Style}};
+///
+/// let data = vec![(7, "Apple"), (42, "Orange"), (1234, "Banana")];
+///
+/// let table = Table::new(&data)
+///     .with(Style::markdown())
+///     .with(Modify::new(Columns::single(0)).with(ZeroPad::to(4)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| i32  | &str   |\n\
+///      |------|--------|\n\
+///      | 0007 | Apple  |\n\
+///      | 0042 | Orange |\n\
+///      | 1234 | Banana |"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZeroPad {
+    width: usize,
+}
+
+impl ZeroPad {
+    /// Creates a [`ZeroPad`] which pads numbers to a given width.
+    pub const fn to(width: usize) -> Self {
+        Self { width }
+    }
+
+    /// Pads a given text if it's a number.
+    ///
+    /// Returns [`None`] if the text is not a number.
+    pub fn format(&self, text: &str) -> Option<String> {
+        let text = text.trim();
+        let _ = Number::parse(text)?;
+
+        let has_sign = text.starts_with('-') || text.starts_with('+');
+        let (sign, digits) = text.split_at(has_sign as usize);
+
+        let zeros = self.width.saturating_sub(text.len());
+
+        let mut buf = String::with_capacity(text.len() + zeros);
+        buf.push_str(sign);
+        buf.extend(std::iter::repeat('0').take(zeros));
+        buf.push_str(digits);
+
+        Some(buf)
+    }
+}
+
+impl<R, D, C> TableOption<R, D, C> for ZeroPad
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<R, C> CellOption<R, C> for ZeroPad
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let content = records.get_text(pos);
+            if let Some(content) = self.format(content) {
+                records.set(pos, content);
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    grid::config::{AlignmentHorizontal, ColoredConfig, Entity},
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::{CellOption, TableOption},
+};
+
+/// AutoAlignment aligns numbers to the right and everything else to the left.
+///
+/// A cell content is inspected when the option is applied.
+/// Negative numbers, thousands separators and percentages are considered to be numbers.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{Modify, object::{Columns, Rows, Object}, Alignment},
+/// };
+///
+/// let data = [("Coffee", "1,250"), ("Tea", "n/a"), ("Milk", "-7%")];
+///
+/// let mut table = Table::new(data);
+/// table.with(Modify::new(Columns::single(1).not(Rows::first())).with(Alignment::auto()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+-------+\n\
+///      | &str   | &str  |\n\
+///      +--------+-------+\n\
+///      | Coffee | 1,250 |\n\
+///      +--------+-------+\n\
+///      | Tea    | n/a   |\n\
+///      +--------+-------+\n\
+///      | Milk   |   -7% |\n\
+///      +--------+-------+"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AutoAlignment;
+
+impl AutoAlignment {
+    /// Creates a new [`AutoAlignment`] object.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for AutoAlignment
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global)
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for AutoAlignment
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let alignment = if is_number(records.get_text(pos)) {
+                AlignmentHorizontal::Right
+            } else {
+                AlignmentHorizontal::Left
+            };
+
+            cfg.set_alignment_horizontal(pos.into(), alignment);
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+/// Checks whether a text looks like a number, like `-1,024.5` or `12%`.
+fn is_number(text: &str) -> bool {
+    let text = text.trim();
+    let text = text.strip_suffix('%').unwrap_or(text);
+    let text = text.strip_prefix(|c| c == '-' || c == '+').unwrap_or(text);
+
+    let (int, fraction) = match text.find('.') {
+        Some(i) => (&text[..i], &text[i + 1..]),
+        None => (text, ""),
+    };
+
+    if int.is_empty() && fraction.is_empty() {
+        return false;
+    }
+
+    is_digits(fraction) && is_integer(int)
+}
+
+fn is_integer(text: &str) -> bool {
+    if !text.contains(',') {
+        return is_digits(text);
+    }
+
+    let mut groups = text.split(',');
+    let first = groups.next().unwrap_or_default();
+    let is_first_valid = !first.is_empty() && first.len() <= 3 && is_digits(first);
+
+    is_first_valid && groups.all(|group| group.len() == 3 && is_digits(group))
+}
+
+fn is_digits(text: &str) -> bool {
+    text.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_number;
+
+    #[test]
+    fn is_number_test() {
+        for text in [
+            "12",
+            "-3.5",
+            "+7",
+            "1,024",
+            "12,345,678.90",
+            "15%",
+            "-0.5%",
+            ".5",
+            " 42 ",
+        ] {
+            assert!(is_number(text), "{:?}", text);
+        }
+
+        for text in [
+            "abc", "", "-", "%", ".", "1,2", "12,34", "1.2.3", "1e5", "inf", "12 %",
+        ] {
+            assert!(!is_number(text), "{:?}", text);
+        }
+    }
+}
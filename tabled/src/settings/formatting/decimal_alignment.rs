@@ -0,0 +1,151 @@
+use crate::{
+    grid::config::{AlignmentHorizontal, ColoredConfig, Entity, Position},
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// DecimalAlignment aligns numbers of a column by a decimal point.
+///
+/// Numbers are padded so their decimal points share a column,
+/// integers are padded with trailing spaces.
+/// Cells which are not numbers are aligned to the right.
+///
+/// Each column is handled on its own.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{Modify, object::{Columns, Rows, Object}, Alignment},
+/// };
+///
+/// let data = [("Coffee", "1.5"), ("Laptop", "1299.99"), ("Book", "12"), ("Gift", "-")];
+///
+/// let mut table = Table::new(data);
+/// table.with(Modify::new(Columns::single(1).not(Rows::first())).with(Alignment::decimal()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+--------+---------+\n\
+///      | &str   | &str    |\n\
+///      +--------+---------+\n\
+///      | Coffee |    1.5  |\n\
+///      +--------+---------+\n\
+///      | Laptop | 1299.99 |\n\
+///      +--------+---------+\n\
+///      | Book   |   12    |\n\
+///      +--------+---------+\n\
+///      | Gift   |       - |\n\
+///      +--------+---------+"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalAlignment;
+
+impl DecimalAlignment {
+    /// Creates a new [`DecimalAlignment`] object.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for DecimalAlignment
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global)
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for DecimalAlignment
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        align_decimal(records, cfg, &[entity]);
+    }
+
+    fn change_region(self, records: &mut R, cfg: &mut ColoredConfig, entities: &[Entity]) {
+        align_decimal(records, cfg, entities);
+    }
+}
+
+fn align_decimal<R>(records: &mut R, cfg: &mut ColoredConfig, entities: &[Entity])
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    let count_rows = records.count_rows();
+    let count_cols = records.count_columns();
+
+    let mut positions = entities
+        .iter()
+        .flat_map(|entity| entity.iter(count_rows, count_cols))
+        .filter(|&(row, col)| row < count_rows && col < count_cols)
+        .collect::<Vec<_>>();
+    positions.sort_by_key(|&(row, col)| (col, row));
+    positions.dedup();
+
+    for column in split_by_column(&positions) {
+        align_column(records, column);
+    }
+
+    for pos in positions {
+        cfg.set_alignment_horizontal(pos.into(), AlignmentHorizontal::Right);
+    }
+}
+
+fn align_column<R>(records: &mut R, positions: &[Position])
+where
+    R: PeekableRecords + RecordsMut<String>,
+{
+    let numbers = positions
+        .iter()
+        .filter_map(|&pos| split_number(records.get_text(pos)).map(|(i, f)| (pos, i, f)))
+        .map(|(pos, int, fraction)| (pos, int.to_owned(), fraction.to_owned()))
+        .collect::<Vec<_>>();
+
+    let int_width = numbers.iter().map(|(_, i, _)| i.len()).max().unwrap_or(0);
+    let fraction_width = numbers.iter().map(|(_, _, f)| f.len()).max().unwrap_or(0);
+
+    for (pos, int, fraction) in numbers {
+        let text = format!(
+            "{:>int_width$}{:<fraction_width$}",
+            int,
+            fraction,
+            int_width = int_width,
+            fraction_width = fraction_width
+        );
+
+        records.set(pos, text);
+    }
+}
+
+/// Splits a number into an integer part and a fraction part including a decimal point.
+fn split_number(text: &str) -> Option<(&str, &str)> {
+    let text = text.trim();
+    match text.parse::<f64>() {
+        Ok(value) if value.is_finite() => {}
+        _ => return None,
+    }
+
+    match text.find('.') {
+        Some(i) => Some((&text[..i], &text[i..])),
+        None => Some((text, "")),
+    }
+}
+
+/// Splits a list of positions sorted by columns into a list of columns.
+fn split_by_column(positions: &[Position]) -> Vec<&[Position]> {
+    let mut columns = Vec::new();
+    let mut start = 0;
+    for i in 1..=positions.len() {
+        if i == positions.len() || positions[i].1 != positions[start].1 {
+            columns.push(&positions[start..i]);
+            start = i;
+        }
+    }
+
+    columns
+}
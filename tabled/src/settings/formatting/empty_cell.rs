@@ -0,0 +1,75 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    settings::{CellOption, TableOption},
+};
+
+/// EmptyCell sets a placeholder text to cells which are empty or contain only whitespaces.
+///
+/// Cells with any other content are not changed.
+/// The placeholder is set as a content of a cell,
+/// so it's measured and aligned like any other text.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{formatting::EmptyCell, Style}};
+///
+/// let data = [["1", ""], [" ", "4"]];
+///
+/// let table = Table::new(data)
+///     .with(Style::markdown())
+///     .with(EmptyCell::new("N/A"))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| 0   | 1   |\n\
+///      |-----|-----|\n\
+///      | 1   | N/A |\n\
+///      | N/A | 4   |"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct EmptyCell<S> {
+    text: S,
+}
+
+impl<S> EmptyCell<S> {
+    /// Creates a new [`EmptyCell`] with a given placeholder.
+    pub const fn new(text: S) -> Self {
+        Self { text }
+    }
+}
+
+impl<S, R, D> TableOption<R, D, ColoredConfig> for EmptyCell<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global)
+    }
+}
+
+impl<S, R> CellOption<R, ColoredConfig> for EmptyCell<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos || !cfg.is_cell_visible(pos) {
+                continue;
+            }
+
+            if records.get_text(pos).trim().is_empty() {
+                records.set(pos, self.text.as_ref().to_owned());
+            }
+        }
+    }
+}
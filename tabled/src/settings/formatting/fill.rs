@@ -0,0 +1,89 @@
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, Records},
+    },
+    settings::{CellOption, TableOption},
+};
+
+/// Set a character which fills a cell wherever an empty space is inserted.
+///
+/// It's used for padding and for the space added by an alignment,
+/// so it works like a [`Padding::fill`] and a [`Justification`] together.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{Modify, object::Cell, formatting::Fill, Alignment},
+/// };
+///
+/// let mut table = Table::new(&[("Hello", "World"), ("Hi", "")]);
+/// table.with(Modify::new(Cell::new(2, 0)).with(Fill::char('.')).with(Alignment::center()));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "+-------+-------+\n\
+///      | &str  | &str  |\n\
+///      +-------+-------+\n\
+///      | Hello | World |\n\
+///      +-------+-------+\n\
+///      |..Hi...|       |\n\
+///      +-------+-------+"
+/// );
+/// ```
+///
+/// [`Padding::fill`]: crate::settings::Padding::fill
+/// [`Justification`]: crate::settings::formatting::Justification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fill {
+    c: char,
+}
+
+impl Fill {
+    /// Creates a new [`Fill`] object.
+    pub const fn char(c: char) -> Self {
+        Self { c }
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Fill
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global)
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for Fill
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        // padding sizes may differ from cell to cell so we keep them as they are.
+        for pos in entity.iter(count_rows, count_cols) {
+            let mut pad = cfg.get_padding(pos.into());
+            pad.left.fill = self.c;
+            pad.right.fill = self.c;
+            pad.top.fill = self.c;
+            pad.bottom.fill = self.c;
+
+            cfg.set_padding(pos.into(), pad);
+        }
+
+        cfg.set_justification(entity, self.c);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
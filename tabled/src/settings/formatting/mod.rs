@@ -2,19 +2,36 @@
 //!
 //! - [`TrimStrategy`] and [`AlignmentStrategy`] allows to set [`Alignment`] settings.
 //! - [`TabSize`] sets a default tab size.
+//! - [`TabExpand`] expands tabs to tab stops.
 //! - [`Charset`] responsible for special char treatment.
 //! - [`Justification`] responsible for justification space of content.
+//! - [`DecimalAlignment`] aligns numbers by a decimal point.
+//! - [`AutoAlignment`] aligns numbers to the right and text to the left.
+//! - [`TrimLines`] removes trailing whitespace of rendered lines.
+//! - [`EmptyCell`] sets a placeholder to empty cells.
 //!
 //! [`Alignment`]: crate::settings::Alignment
 
 mod alignment_strategy;
+mod auto_alignment;
 mod charset;
+mod decimal_alignment;
+mod empty_cell;
+mod fill;
 mod justification;
+mod tab_expand;
 mod tab_size;
+mod trim_lines;
 mod trim_strategy;
 
 pub use alignment_strategy::AlignmentStrategy;
+pub use auto_alignment::AutoAlignment;
 pub use charset::{Charset, CleanCharset};
+pub use decimal_alignment::DecimalAlignment;
+pub use empty_cell::EmptyCell;
+pub use fill::Fill;
 pub use justification::Justification;
+pub use tab_expand::TabExpand;
 pub use tab_size::TabSize;
+pub use trim_lines::TrimLines;
 pub use trim_strategy::TrimStrategy;
@@ -0,0 +1,101 @@
+use crate::{
+    grid::records::{Records, RecordsMut},
+    grid::util::string::get_char_width,
+    settings::TableOption,
+};
+
+/// Expands tabs to the next tab stop.
+///
+/// In comparison to [`TabSize`], which replaces each tab by the same number of spaces,
+/// a tab is replaced by as many spaces as needed to reach a next column which is a multiple of the size.
+/// So the text is laid out the same way as in a terminal.
+///
+/// Tab stops are counted from the beginning of each line of a cell.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::formatting::TabExpand};
+///
+/// let data = ["a\tb", "abc\td"];
+///
+/// let mut table = Table::new(data);
+/// table.with(TabExpand::size(4));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-------+\n",
+///         "| &str  |\n",
+///         "+-------+\n",
+///         "| a   b |\n",
+///         "+-------+\n",
+///         "| abc d |\n",
+///         "+-------+",
+///     )
+/// )
+/// ```
+///
+/// [`TabSize`]: crate::settings::formatting::TabSize
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TabExpand(usize);
+
+impl TabExpand {
+    /// Creates a [`TabExpand`] with a given distance between tab stops.
+    ///
+    /// A size of 0 removes tabs.
+    pub fn size(size: usize) -> Self {
+        Self(size)
+    }
+}
+
+impl<R, D, C> TableOption<R, D, C> for TabExpand
+where
+    for<'a> &'a R: Records,
+    R: RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, _: &mut D) {
+        let mut list = vec![];
+        for (row, cells) in records.iter_rows().into_iter().enumerate() {
+            for (col, text) in cells.into_iter().enumerate() {
+                let text = text.as_ref();
+                if text.contains('\t') {
+                    list.push(((row, col), expand_tabs(text, self.0)));
+                }
+            }
+        }
+
+        for (pos, text) in list {
+            records.set(pos, text);
+        }
+    }
+}
+
+fn expand_tabs(text: &str, size: usize) -> String {
+    let mut buf = String::with_capacity(text.len());
+    let mut width = 0;
+
+    for c in text.chars() {
+        match c {
+            '\t' => {
+                if size == 0 {
+                    continue;
+                }
+
+                let count_spaces = size - width % size;
+                buf.extend(std::iter::repeat(' ').take(count_spaces));
+                width += count_spaces;
+            }
+            '\n' => {
+                buf.push(c);
+                width = 0;
+            }
+            c => {
+                buf.push(c);
+                width += get_char_width(c);
+            }
+        }
+    }
+
+    buf
+}
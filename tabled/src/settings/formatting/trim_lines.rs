@@ -0,0 +1,58 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    settings::TableOption,
+};
+
+/// Removes trailing whitespace from each line of a rendered table.
+///
+/// It's handy for borderless styles like [`Style::blank`],
+/// where cells are padded to the column width with spaces.
+/// For styles with a right border it has no effect.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{formatting::TrimLines, Style}};
+///
+/// let data = [("Apple", 3), ("Orange", 12)];
+///
+/// let table = Table::new(data)
+///     .with(Style::blank())
+///     .with(TrimLines::new())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str     i32\n",
+///         " Apple    3\n",
+///         " Orange   12",
+///     )
+/// );
+/// ```
+///
+/// [`Style::blank`]: crate::settings::Style::blank
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TrimLines(bool);
+
+impl TrimLines {
+    /// Creates a [`TrimLines`] which removes trailing whitespace.
+    pub fn new() -> Self {
+        Self(true)
+    }
+
+    /// Creates a [`TrimLines`] which keeps trailing whitespace (the default behaviour).
+    pub fn off() -> Self {
+        Self(false)
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for TrimLines {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.set_trim_lines(self.0);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
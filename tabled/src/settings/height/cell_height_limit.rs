@@ -19,6 +19,7 @@ use super::table_height_limit::TableHeightLimit;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CellHeightLimit<W = usize> {
     height: W,
+    indicator: Option<char>,
 }
 
 impl<W> CellHeightLimit<W> {
@@ -27,7 +28,39 @@ impl<W> CellHeightLimit<W> {
     where
         W: Measurement<Height>,
     {
-        Self { height }
+        Self {
+            height,
+            indicator: None,
+        }
+    }
+
+    /// Sets a character which is appended to the last line of a cell
+    /// in case its content was clipped.
+    ///
+    /// It's used only when the limit is applied to cells.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Rows, Height, Modify}};
+    ///
+    /// let data = [("1\n2\n3", "1")];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Modify::new(Rows::new(1..)).with(Height::limit(2).indicator('▾')))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+------+------+\n\
+    ///      | &str | &str |\n\
+    ///      +------+------+\n\
+    ///      | 1    | 1    |\n\
+    ///      | 2▾   |      |\n\
+    ///      +------+------+",
+    /// );
+    /// ```
+    pub fn indicator(mut self, c: char) -> Self {
+        self.indicator = Some(c);
+        self
     }
 
     /// Set's a priority by which the limit logic will be applied.
@@ -65,7 +98,13 @@ where
                 continue;
             }
 
-            let content = limit_lines(text, height);
+            let mut content = limit_lines(text, height);
+            if let Some(c) = self.indicator {
+                if height > 0 {
+                    content.push(c);
+                }
+            }
+
             records.set(pos, content);
         }
     }
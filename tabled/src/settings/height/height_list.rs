@@ -1,13 +1,17 @@
 use std::iter::FromIterator;
 
 use crate::{
-    grid::dimension::CompleteDimensionVecRecords,
+    grid::config::ColoredConfig,
+    grid::dimension::{CompleteDimensionVecRecords, SpannedGridDimension},
     grid::records::{ExactRecords, Records},
     settings::TableOption,
 };
 
 /// A structure used to set [`Table`] height via a list of rows heights.
 ///
+/// If the list is shorter than a number of rows the rest of rows keep their natural height.
+/// If it's longer the extra values are ignored.
+///
 /// [`Table`]: crate::Table
 #[derive(Debug)]
 pub struct HeightList {
@@ -33,15 +37,26 @@ impl FromIterator<usize> for HeightList {
     }
 }
 
-impl<R, C> TableOption<R, CompleteDimensionVecRecords<'_>, C> for HeightList
+impl<R> TableOption<R, CompleteDimensionVecRecords<'_>, ColoredConfig> for HeightList
 where
     R: ExactRecords + Records,
+    for<'a> &'a R: Records,
 {
-    fn change(self, records: &mut R, _: &mut C, dims: &mut CompleteDimensionVecRecords<'_>) {
-        if self.list.len() < records.count_rows() {
-            return;
+    fn change(
+        mut self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let count_rows = records.count_rows();
+        if self.list.len() < count_rows {
+            let heights = SpannedGridDimension::height(&*records, cfg);
+            let rest = heights.into_iter().skip(self.list.len());
+            self.list.extend(rest);
         }
 
+        self.list.truncate(count_rows);
+
         dims.set_heights(self.list);
     }
 }
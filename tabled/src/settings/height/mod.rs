@@ -195,7 +195,8 @@ impl Height {
 
     /// Create [`HeightList`] to set a table height to a constant list of row heights.
     ///
-    /// Notice if you provide a list with `.len()` less than `Table::count_rows` then it will have no affect.
+    /// If the list is shorter than `Table::count_rows` the rest of rows keep their natural height,
+    /// and if it's longer the extra values are ignored.
     ///
     /// # Example
     ///
@@ -0,0 +1,216 @@
+use crate::{
+    grid::{
+        color::AnsiColor,
+        config::Entity,
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    },
+    settings::{CellOption, Color, TableOption},
+};
+
+/// [`HighlightText`] colorizes each occurrence of a text in a cell,
+/// leaving the rest of the cell content as it is.
+///
+/// A text is matched against a visible content, so ANSI sequences already present in a cell
+/// neither break a match nor get corrupted.
+/// A regex can be used instead of a plain text with the `regex` feature on.
+///
+/// The colors are injected into the content as ANSI sequences,
+/// so the `color` feature must be turned on to have a width calculated correctly.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{highlight::HighlightText, Color}};
+///
+/// let data = [["INFO: started"], ["ERROR: failed to connect"]];
+///
+/// let mut table = Table::new(data);
+/// table.with(HighlightText::new("ERROR", Color::FG_RED));
+///
+/// let cells: Vec<_> = table.cells().map(|(_, text)| text).collect();
+///
+/// assert_eq!(
+///     cells,
+///     ["0", "INFO: started", "\u{1b}[31mERROR\u{1b}[39m: failed to connect"],
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct HighlightText<S> {
+    pattern: Pattern<S>,
+    color: AnsiColor<'static>,
+}
+
+#[derive(Debug, Clone)]
+enum Pattern<S> {
+    Text(S),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+}
+
+impl<S> HighlightText<S> {
+    /// Creates a new [`HighlightText`] for a given substring.
+    pub fn new(pattern: S, color: Color) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self {
+            pattern: Pattern::Text(pattern),
+            color: color.into(),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+impl HighlightText<String> {
+    /// Creates a new [`HighlightText`] which colorizes all matches of a given regex.
+    ///
+    /// ```
+    /// use regex::Regex;
+    /// use tabled::{Table, settings::{highlight::HighlightText, Color}};
+    ///
+    /// let data = [["GET /index 200"], ["GET /login 404"]];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(HighlightText::regex(Regex::new("[45]0[0-9]").unwrap(), Color::FG_RED));
+    ///
+    /// let cells: Vec<_> = table.cells().map(|(_, text)| text).collect();
+    ///
+    /// assert_eq!(
+    ///     cells,
+    ///     ["0", "GET /index 200", "GET /login \u{1b}[31m404\u{1b}[39m"],
+    /// );
+    /// ```
+    pub fn regex(regex: regex::Regex, color: Color) -> Self {
+        Self {
+            pattern: Pattern::Regex(regex),
+            color: color.into(),
+        }
+    }
+}
+
+impl<S> Pattern<S>
+where
+    S: AsRef<str>,
+{
+    fn find(&self, text: &str) -> Vec<(usize, usize)> {
+        match self {
+            Pattern::Text(pattern) => {
+                let pattern = pattern.as_ref();
+                if pattern.is_empty() {
+                    return Vec::new();
+                }
+
+                text.match_indices(pattern)
+                    .map(|(i, m)| (i, i + m.len()))
+                    .collect()
+            }
+            #[cfg(feature = "regex")]
+            Pattern::Regex(regex) => regex
+                .find_iter(text)
+                .filter(|m| !m.as_str().is_empty())
+                .map(|m| (m.start(), m.end()))
+                .collect(),
+        }
+    }
+}
+
+impl<S, R, D, C> TableOption<R, D, C> for HighlightText<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
+        CellOption::change(self, records, cfg, Entity::Global);
+    }
+}
+
+impl<S, R, C> CellOption<R, C> for HighlightText<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut C, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let text = records.get_text(pos);
+            let (visible, offsets) = strip_ansi(text);
+
+            let matches = self.pattern.find(&visible);
+            if matches.is_empty() {
+                continue;
+            }
+
+            let prefix = self.color.get_prefix();
+            let suffix = self.color.get_suffix();
+
+            let mut buf = String::with_capacity(text.len());
+            let mut last = 0;
+            for (start, end) in matches {
+                let start = offsets[start].0;
+                let end = offsets[end - 1].1;
+
+                buf.push_str(&text[last..start]);
+                buf.push_str(prefix);
+                buf.push_str(&text[start..end]);
+                buf.push_str(suffix);
+
+                last = end;
+            }
+            buf.push_str(&text[last..]);
+
+            records.set(pos, buf);
+        }
+    }
+}
+
+/// Returns a text without ANSI sequences,
+/// together with a range in the original text for each byte of it.
+fn strip_ansi(text: &str) -> (String, Vec<(usize, usize)>) {
+    let mut buf = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            let range = (i, i + c.len_utf8());
+            offsets.extend(std::iter::repeat(range).take(c.len_utf8()));
+            continue;
+        }
+
+        match chars.next() {
+            // CSI sequence ends with a byte in a range 0x40..=0x7E
+            Some((_, '[')) => {
+                for (_, c) in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC sequence ends with BEL or ST (ESC \)
+            Some((_, ']')) => {
+                while let Some((_, c)) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && matches!(chars.peek(), Some((_, '\\'))) {
+                        let _ = chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (buf, offsets)
+}
@@ -3,6 +3,8 @@
 //!
 //! [`Table`]: crate::Table
 
+mod highlight_text;
+
 use std::collections::HashSet;
 
 use papergrid::color::AnsiColor;
@@ -15,6 +17,8 @@ use crate::{
     settings::{object::Object, style::BorderColor, Border, Color, TableOption},
 };
 
+pub use highlight_text::HighlightText;
+
 /// Highlight modifies a table style by changing a border of a target [`Table`] segment.
 ///
 /// # Example
@@ -124,7 +128,36 @@ impl<O> Highlight<O> {
         }
     }
 
-    /// Build a new instance of [`Highlight`]
+    /// Build a new instance of [`Highlight`] which colors the lines bounding a target,
+    /// keeping their chars intact.
+    ///
+    /// It can be used to make a header frame stand out from the rest of a table.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     Table,
+    ///     settings::{object::Rows, style::BorderColor, Color, Highlight},
+    /// };
+    ///
+    /// let color = BorderColor::new()
+    ///     .set_top(Color::FG_RED)
+    ///     .set_bottom(Color::FG_RED);
+    ///
+    /// let table = Table::new([["a", "b"]])
+    ///     .with(Highlight::color(Rows::first(), color))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+\u{1b}[31m---+---\u{1b}[39m+\n",
+    ///         "| 0 | 1 |\n",
+    ///         "+\u{1b}[31m---+---\u{1b}[39m+\n",
+    ///         "| a | b |\n",
+    ///         "+---+---+",
+    ///     )
+    /// );
+    /// ```
     ///
     /// BE AWARE: if target exceeds boundaries it may panic.
     // #[cfg(feature = "color")]
@@ -223,7 +256,7 @@ fn set_border_color(
     }
 }
 
-fn split_segments(
+pub(crate) fn split_segments(
     cells: impl Iterator<Item = Entity>,
     count_rows: usize,
     count_cols: usize,
@@ -303,7 +336,11 @@ fn is_segment_connected(
     false
 }
 
-fn set_border(cfg: &mut SpannedConfig, sector: &HashSet<(usize, usize)>, border: GridBorder<char>) {
+pub(crate) fn set_border(
+    cfg: &mut SpannedConfig,
+    sector: &HashSet<(usize, usize)>,
+    border: GridBorder<char>,
+) {
     if sector.is_empty() {
         return;
     }
@@ -314,7 +351,7 @@ fn set_border(cfg: &mut SpannedConfig, sector: &HashSet<(usize, usize)>, border:
     }
 }
 
-fn build_cell_border<T>(
+pub(crate) fn build_cell_border<T>(
     sector: &HashSet<(usize, usize)>,
     (row, col): Position,
     border: &GridBorder<T>,
@@ -0,0 +1,108 @@
+//! This module contains an [`Index`] setting of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, settings::{Index, Style}};
+//!
+//! let data = [["Debian", "2023"], ["Arch", "2022"]];
+//!
+//! let table = Table::new(data)
+//!     .with(Index::new())
+//!     .with(Style::psql())
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " # | 0      | 1    \n",
+//!         "---+--------+------\n",
+//!         " 1 | Debian | 2023 \n",
+//!         " 2 | Arch   | 2022 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::ColoredConfig,
+        records::{ExactRecords, Records, RecordsMut, Resizable},
+    },
+    settings::TableOption,
+};
+
+/// [`Index`] prepends a column with row numbers to a [`Table`].
+///
+/// The first row is considered to be a header,
+/// so it gets a header of the index and numbering starts from the next row.
+///
+/// All settings which were set before are shifted together with their columns.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Index {
+    start: usize,
+    header: Option<String>,
+}
+
+impl Index {
+    /// Creates a new [`Index`] which numbers rows starting from 1,
+    /// with a `#` header.
+    pub fn new() -> Self {
+        Self {
+            start: 1,
+            header: Some(String::from("#")),
+        }
+    }
+
+    /// Sets a number of a first row.
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets a header of the index column.
+    pub fn header<S: Into<String>>(mut self, header: S) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Numbers all rows, considering that the table has no header.
+    pub fn no_header(mut self) -> Self {
+        self.header = None;
+        self
+    }
+}
+
+impl Default for Index {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Index
+where
+    R: Records + ExactRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        if count_rows == 0 || records.count_columns() == 0 {
+            return;
+        }
+
+        records.insert_column(0);
+        cfg.insert_column(0);
+
+        let mut rows = 0..count_rows;
+        if let Some(header) = self.header {
+            let _ = rows.next();
+            records.set((0, 0), header);
+        }
+
+        for (i, row) in rows.enumerate() {
+            records.set((row, 0), (self.start + i).to_string());
+        }
+    }
+}
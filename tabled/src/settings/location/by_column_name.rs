@@ -9,6 +9,11 @@ use crate::{
 /// A name is considered be a value in a first row.
 ///
 /// So even if in reality there's no header, the first row will be considered to be one.
+///
+/// An unknown name produces no cells,
+/// use [`Table::try_modify`] to get an error instead.
+///
+/// [`Table::try_modify`]: crate::Table::try_modify
 #[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ByColumnName<S>(S);
 
@@ -22,6 +27,20 @@ impl<S> ByColumnName<S> {
     }
 }
 
+impl<S> ByColumnName<S>
+where
+    S: AsRef<str>,
+{
+    fn find<R>(&self, records: &R) -> Vec<usize>
+    where
+        R: Records + ExactRecords + PeekableRecords,
+    {
+        (0..records.count_columns())
+            .filter(|col| records.get_text((0, *col)) == self.0.as_ref())
+            .collect()
+    }
+}
+
 impl<R, S> Location<R> for ByColumnName<S>
 where
     S: AsRef<str>,
@@ -31,10 +50,7 @@ where
     type IntoIter = Vec<usize>;
 
     fn locate(&mut self, records: &R) -> Self::IntoIter {
-        // todo: can be optimized by creating Iterator
-        (0..records.count_columns())
-            .filter(|col| records.get_text((0, *col)) == self.0.as_ref())
-            .collect::<Vec<_>>()
+        self.find(records)
     }
 }
 
@@ -46,9 +62,8 @@ where
     type Iter = std::vec::IntoIter<Entity>;
 
     fn cells(&self, records: &R) -> Self::Iter {
-        // todo: can be optimized by creating Iterator
-        (0..records.count_columns())
-            .filter(|col| records.get_text((0, *col)) == self.0.as_ref())
+        self.find(records)
+            .into_iter()
             .map(Entity::Column)
             .collect::<Vec<_>>()
             .into_iter()
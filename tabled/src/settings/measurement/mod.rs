@@ -4,7 +4,7 @@ use crate::{
     grid::config::SpannedConfig,
     grid::dimension::SpannedGridDimension,
     grid::records::{ExactRecords, PeekableRecords, Records},
-    grid::util::string::{self, string_width_multiline},
+    grid::util::string::{self, string_width_multiline_ambiguous},
     settings::{Height, Width},
 };
 
@@ -34,9 +34,9 @@ impl Measurement<Width> for Max {
     fn measure<R: Records + ExactRecords + PeekableRecords>(
         &self,
         records: R,
-        _: &SpannedConfig,
+        cfg: &SpannedConfig,
     ) -> usize {
-        grid_widths(&records)
+        grid_widths(&records, cfg)
             .map(|r| r.max().unwrap_or(0))
             .max()
             .unwrap_or(0)
@@ -64,9 +64,9 @@ impl Measurement<Width> for Min {
     fn measure<R: Records + ExactRecords + PeekableRecords>(
         &self,
         records: R,
-        _: &SpannedConfig,
+        cfg: &SpannedConfig,
     ) -> usize {
-        grid_widths(&records)
+        grid_widths(&records, cfg)
             .map(|r| r.min().unwrap_or(0))
             .max()
             .unwrap_or(0)
@@ -110,12 +110,16 @@ impl Measurement<Height> for Percent {
     }
 }
 
-fn grid_widths<R: Records + ExactRecords + PeekableRecords>(
-    records: &R,
-) -> impl Iterator<Item = impl Iterator<Item = usize> + '_> + '_ {
+fn grid_widths<'a, R: Records + ExactRecords + PeekableRecords>(
+    records: &'a R,
+    cfg: &SpannedConfig,
+) -> impl Iterator<Item = impl Iterator<Item = usize> + 'a> + 'a {
     let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
+    let ambiguous_wide = cfg.is_ambiguous_width_wide();
     (0..count_rows).map(move |row| {
-        (0..count_cols).map(move |col| string_width_multiline(records.get_text((row, col))))
+        (0..count_cols).map(move |col| {
+            string_width_multiline_ambiguous(records.get_text((row, col)), ambiguous_wide)
+        })
     })
 }
 
@@ -58,12 +58,24 @@ mod margin;
 mod padding;
 mod rotate;
 
+#[cfg(feature = "std")]
+mod ansi_string;
+#[cfg(feature = "std")]
+mod caption;
 #[cfg(feature = "std")]
 mod color;
 #[cfg(feature = "std")]
 mod concat;
 #[cfg(feature = "std")]
+mod direction;
+#[cfg(feature = "std")]
 mod duplicate;
+#[cfg(feature = "std")]
+mod index;
+#[cfg(feature = "std")]
+mod sparkline;
+#[cfg(feature = "std")]
+mod tree;
 
 pub mod style;
 
@@ -129,7 +141,8 @@ pub use self::{
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
-    color::Color, concat::Concat, disable::Disable, duplicate::Dup, format::Format, height::Height,
-    highlight::Highlight, merge::Merge, panel::Panel, shadow::Shadow, span::Span, style::Border,
-    width::Width,
+    ansi_string::AnsiStr, caption::Caption, color::Color, concat::Concat, direction::Direction,
+    disable::Disable, duplicate::Dup, format::Format, height::Height, highlight::Highlight,
+    index::Index, merge::Merge, panel::Panel, shadow::Shadow, span::Span, sparkline::Sparkline,
+    style::Border, tree::Tree, width::Width,
 };
@@ -89,7 +89,7 @@ impl<O> Modify<O> {
 }
 
 /// This is a container of [`CellOption`]s which are applied to a set [`Object`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ModifyList<O, S> {
     obj: O,
     modifiers: S,
@@ -119,9 +119,8 @@ where
     R: Records + ExactRecords,
 {
     fn change(self, records: &mut R, cfg: &mut C, _: &mut D) {
-        for entity in self.obj.cells(records) {
-            self.modifiers.clone().change(records, cfg, entity);
-        }
+        let entities = self.obj.cells(records).collect::<Vec<_>>();
+        self.modifiers.change_region(records, cfg, &entities);
     }
 
     fn hint_change(&self) -> Option<Entity> {
@@ -1,9 +1,13 @@
 use std::ops::{Add, RangeBounds, Sub};
 
 use crate::{
-    grid::config::Entity,
+    grid::config::{ColoredConfig, Entity},
     grid::records::{ExactRecords, Records},
-    settings::object::{cell::EntityOnce, Object},
+    settings::{
+        location::ByColumnName,
+        object::{cell::EntityOnce, Object},
+        TableOption,
+    },
 };
 
 use super::util::bounds_to_usize;
@@ -11,7 +15,7 @@ use super::util::bounds_to_usize;
 /// Column denotes a set of cells on given columns on a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Columns<R> {
     range: R,
 }
@@ -53,6 +57,77 @@ impl Columns<()> {
     pub fn last() -> LastColumn {
         LastColumn
     }
+
+    /// Returns a column which has a given name in a first row.
+    ///
+    /// The name is resolved when the object is applied,
+    /// so it keeps pointing to the same column even if columns were reordered.
+    /// If there's no such column the object will produce no cells,
+    /// see [`Table::try_modify`] to get an error instead.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Columns, Alignment, Modify}};
+    ///
+    /// let data = [("Apple", 120), ("Banana", 7)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Modify::new(Columns::by_name("i32")).with(Alignment::right()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+-----+\n",
+    ///         "| &str   | i32 |\n",
+    ///         "+--------+-----+\n",
+    ///         "| Apple  | 120 |\n",
+    ///         "+--------+-----+\n",
+    ///         "| Banana |   7 |\n",
+    ///         "+--------+-----+",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Table::try_modify`]: crate::Table::try_modify
+    pub fn by_name<S>(name: S) -> ByColumnName<S>
+    where
+        S: AsRef<str>,
+    {
+        ByColumnName::new(name)
+    }
+
+    /// Returns a marker which pins a column,
+    /// so it's repeated on each table produced by [`Table::split_columns`].
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Columns, Style}};
+    ///
+    /// let data = [("Hello", "World", "!!!")];
+    ///
+    /// let mut table = Table::new(data);
+    /// table.with(Style::psql()).with(Columns::pin(1));
+    ///
+    /// let tables = table.split_columns(15, false);
+    ///
+    /// assert_eq!(tables.len(), 2);
+    /// assert_eq!(
+    ///     tables[0].to_string(),
+    ///     " &str  | &str  \n\
+    ///      -------+-------\n \
+    ///       World | Hello "
+    /// );
+    /// assert_eq!(
+    ///     tables[1].to_string(),
+    ///     " &str  | &str \n\
+    ///      -------+------\n \
+    ///       World | !!!  "
+    /// );
+    /// ```
+    ///
+    /// [`Table::split_columns`]: crate::Table::split_columns
+    pub fn pin(index: usize) -> PinnedColumn {
+        PinnedColumn(index)
+    }
 }
 
 impl<I, R> Object<I> for Columns<R>
@@ -73,7 +148,7 @@ where
 }
 
 /// `FirstColumn` represents the first column on a grid.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FirstColumn;
 
 impl<I> Object<I> for FirstColumn
@@ -100,7 +175,7 @@ impl Add<usize> for FirstColumn {
 }
 
 /// `LastColumn` represents the last column on a grid.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastColumn;
 
 impl<I> Object<I> for LastColumn
@@ -151,8 +226,20 @@ impl From<Column> for usize {
     }
 }
 
+/// PinnedColumn marks a column to be repeated when a table is split.
+///
+/// See [`Columns::pin`].
+#[derive(Debug, Clone, Copy)]
+pub struct PinnedColumn(usize);
+
+impl<R, D> TableOption<R, D, ColoredConfig> for PinnedColumn {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.pin_column(self.0);
+    }
+}
+
 /// `LastColumnOffset` represents a single column on a grid indexed via offset from the last column.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastColumnOffset {
     offset: usize,
 }
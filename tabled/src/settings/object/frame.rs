@@ -6,7 +6,7 @@ use crate::{
 
 /// Frame includes cells which are on the edges of each side.
 /// Therefore it's [`Object`] implementation returns a subset of cells which are present in frame.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Frame;
 
 impl<I> Object<I> for Frame
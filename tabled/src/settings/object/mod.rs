@@ -6,6 +6,7 @@
 mod cell;
 mod columns;
 mod frame;
+mod panel;
 mod rows;
 mod segment;
 pub(crate) mod util;
@@ -20,8 +21,11 @@ use crate::{
 };
 
 pub use cell::{Cell, EntityOnce};
-pub use columns::{Column, Columns, ColumnsIter, FirstColumn, LastColumn, LastColumnOffset};
+pub use columns::{
+    Column, Columns, ColumnsIter, FirstColumn, LastColumn, LastColumnOffset, PinnedColumn,
+};
 pub use frame::{Frame, FrameIter};
+pub use panel::Panel;
 pub use rows::{FirstRow, LastRow, LastRowOffset, Row, Rows, RowsIter};
 pub use segment::{SectorIter, Segment, SegmentAll};
 
@@ -0,0 +1,99 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, Records},
+    settings::object::{cell::EntityOnce, Object},
+};
+
+/// Panel denotes a cell created by [`settings::Panel`] on a [`Table`].
+///
+/// A panel is a single cell spread over a whole row (or column),
+/// so the object locates only its origin cell.
+/// It's handy for coloring, padding or changing borders of a panel
+/// without touching the cells it spans over.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     settings::{object, Alignment, Modify, Panel},
+///     Table,
+/// };
+///
+/// let data = [[1, 2, 3], [4, 5, 6]];
+///
+/// let table = Table::new(data)
+///     .with(Panel::header("Numbers"))
+///     .with(Modify::new(object::Panel::horizontal(0)).with(Alignment::center()))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+---+---+---+\n",
+///         "|  Numbers  |\n",
+///         "+---+---+---+\n",
+///         "| 0 | 1 | 2 |\n",
+///         "+---+---+---+\n",
+///         "| 1 | 2 | 3 |\n",
+///         "+---+---+---+\n",
+///         "| 4 | 5 | 6 |\n",
+///         "+---+---+---+",
+///     )
+/// );
+/// ```
+///
+/// [`settings::Panel`]: crate::settings::Panel
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Panel {
+    index: usize,
+    horizontal: bool,
+}
+
+impl Panel {
+    /// Locates a panel inserted by [`Panel::horizontal`] at the given row.
+    ///
+    /// [`Panel::horizontal`]: crate::settings::Panel::horizontal
+    pub const fn horizontal(row: usize) -> Self {
+        Self {
+            index: row,
+            horizontal: true,
+        }
+    }
+
+    /// Locates a panel inserted by [`Panel::vertical`] at the given column.
+    ///
+    /// [`Panel::vertical`]: crate::settings::Panel::vertical
+    pub const fn vertical(column: usize) -> Self {
+        Self {
+            index: column,
+            horizontal: false,
+        }
+    }
+}
+
+impl<I> Object<I> for Panel
+where
+    I: Records + ExactRecords,
+{
+    type Iter = EntityOnce;
+
+    fn cells(&self, records: &I) -> Self::Iter {
+        let (count_rows, count_cols) = (records.count_rows(), records.count_columns());
+        if count_rows == 0 || count_cols == 0 {
+            return EntityOnce::new(None);
+        }
+
+        let pos = if self.horizontal {
+            (self.index, 0)
+        } else {
+            (0, self.index)
+        };
+
+        if pos.0 >= count_rows || pos.1 >= count_cols {
+            return EntityOnce::new(None);
+        }
+
+        EntityOnce::new(Some(Entity::Cell(pos.0, pos.1)))
+    }
+}
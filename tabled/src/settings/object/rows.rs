@@ -1,4 +1,4 @@
-use std::ops::{Add, RangeBounds, Sub};
+use std::ops::{Add, RangeBounds, RangeFrom, Sub};
 
 use crate::{
     grid::config::Entity,
@@ -11,7 +11,7 @@ use super::util::bounds_to_usize;
 /// Row denotes a set of cells on given rows on a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Rows<R> {
     range: R,
 }
@@ -53,6 +53,34 @@ impl Rows<()> {
     pub const fn last() -> LastRow {
         LastRow
     }
+
+    /// Returns all rows except the first one, which is considered a header.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Rows, Alignment, Modify}};
+    ///
+    /// let data = [("Apple", 120), ("Banana", 7)];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Modify::new(Rows::data()).with(Alignment::right()))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+-----+\n",
+    ///         "| &str   | i32 |\n",
+    ///         "+--------+-----+\n",
+    ///         "|  Apple | 120 |\n",
+    ///         "+--------+-----+\n",
+    ///         "| Banana |   7 |\n",
+    ///         "+--------+-----+",
+    ///     )
+    /// );
+    /// ```
+    pub const fn data() -> Rows<RangeFrom<usize>> {
+        Rows { range: 1.. }
+    }
 }
 
 impl<I, R> Object<I> for Rows<R>
@@ -96,7 +124,7 @@ impl From<Row> for usize {
 /// It's often contains headers data.
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FirstRow;
 
 impl<I> Object<I> for FirstRow
@@ -125,7 +153,7 @@ impl Add<usize> for FirstRow {
 /// This structure represents the last row of a [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastRow;
 
 impl<I> Object<I> for LastRow
@@ -155,7 +183,7 @@ impl Sub<usize> for LastRow {
 }
 
 /// A row which is located by an offset from the last row.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LastRowOffset {
     offset: usize,
 }
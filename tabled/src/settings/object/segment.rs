@@ -11,7 +11,7 @@ use super::util::bounds_to_usize;
 /// This structure represents a sub table of [`Table`].
 ///
 /// [`Table`]: crate::Table
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Segment<C, R> {
     columns: C,
     rows: R,
@@ -61,7 +61,7 @@ where
 /// This is a segment which contains all cells on the table.
 ///
 /// Can be created from [`Segment::all`].
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SegmentAll;
 
 impl<I> Object<I> for SegmentAll {
@@ -4,7 +4,7 @@ use crate::{
     settings::TableOption,
 };
 
-use super::Panel;
+use super::{Panel, Summary};
 
 /// Footer renders a [`Panel`] at the bottom.
 /// See [`Panel`].
@@ -21,6 +21,15 @@ impl<S> Footer<S> {
     }
 }
 
+impl Footer<()> {
+    /// Creates a [`Summary`] footer, which aggregates numeric columns.
+    ///
+    /// See [`Summary`].
+    pub fn summary() -> Summary {
+        Summary::new()
+    }
+}
+
 impl<S, R, D> TableOption<R, D, ColoredConfig> for Footer<S>
 where
     S: AsRef<str>,
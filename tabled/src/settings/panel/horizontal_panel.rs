@@ -1,7 +1,8 @@
 use crate::{
-    grid::config::{ColoredConfig, SpannedConfig},
+    grid::config::{ColoredConfig, Entity, SpannedConfig},
+    grid::dimension::SpannedGridDimension,
     grid::records::{ExactRecords, Records, RecordsMut, Resizable},
-    settings::TableOption,
+    settings::{width::wrap::wrap_text_on_chars, TableOption},
 };
 
 /// A horizontal/column span from 0 to a count rows.
@@ -16,6 +17,39 @@ impl<S> HorizontalPanel<S> {
     pub fn new(row: usize, text: S) -> Self {
         Self { row, text }
     }
+
+    /// Wraps the panel text so it fits the current width of a table,
+    /// instead of making the table wider.
+    ///
+    /// Words are kept whole when possible,
+    /// but a word longer than the table is broken.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Panel};
+    ///
+    /// let data = [[1, 2, 3]];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Panel::horizontal(0, "Some numbers").wrap())
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+---+---+---+\n",
+    ///         "| Some      |\n",
+    ///         "| numbers   |\n",
+    ///         "+---+---+---+\n",
+    ///         "| 0 | 1 | 2 |\n",
+    ///         "+---+---+---+\n",
+    ///         "| 1 | 2 | 3 |\n",
+    ///         "+---+---+---+",
+    ///     )
+    /// )
+    /// ```
+    pub fn wrap(self) -> WrappedPanel<S> {
+        WrappedPanel(self)
+    }
 }
 
 impl<S, R, D> TableOption<R, D, ColoredConfig> for HorizontalPanel<S>
@@ -47,7 +81,45 @@ where
     }
 }
 
-fn move_rows_aside<R: ExactRecords + Resizable>(records: &mut R, row: usize) {
+/// A [`HorizontalPanel`] which text is wrapped to the width of a table.
+///
+/// It's created by [`HorizontalPanel::wrap`].
+#[derive(Debug)]
+pub struct WrappedPanel<S>(HorizontalPanel<S>);
+
+impl<S, R, D> TableOption<R, D, ColoredConfig> for WrappedPanel<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + Resizable + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        let HorizontalPanel { text, row } = self.0;
+
+        let padding = cfg.get_padding(Entity::Cell(row, 0));
+        let width = get_content_width(&*records, cfg)
+            .saturating_sub(padding.left.size + padding.right.size)
+            .max(1);
+
+        let ambiguous_wide = cfg.is_ambiguous_width_wide();
+        let text = wrap_text_on_chars(text.as_ref(), width, &[], true, ambiguous_wide);
+
+        HorizontalPanel::new(row, text).change(records, cfg, dims);
+    }
+}
+
+fn get_content_width<R: Records>(records: R, cfg: &SpannedConfig) -> usize {
+    let count_columns = records.count_columns();
+    let widths = SpannedGridDimension::width(records, cfg);
+
+    let verticals = (1..count_columns)
+        .map(|col| cfg.get_vertical_width(col, count_columns))
+        .sum::<usize>();
+
+    widths.iter().sum::<usize>() + verticals
+}
+
+pub(super) fn move_rows_aside<R: ExactRecords + Resizable>(records: &mut R, row: usize) {
     records.push_row();
 
     let count_rows = records.count_rows();
@@ -59,7 +131,7 @@ fn move_rows_aside<R: ExactRecords + Resizable>(records: &mut R, row: usize) {
     }
 }
 
-fn move_row_spans(cfg: &mut SpannedConfig, target_row: usize) {
+pub(super) fn move_row_spans(cfg: &mut SpannedConfig, target_row: usize) {
     for ((row, col), span) in cfg.get_column_spans() {
         if row < target_row {
             continue;
@@ -39,11 +39,15 @@
 mod footer;
 mod header;
 mod horizontal_panel;
+mod repeat_header;
+mod summary;
 mod vertical_panel;
 
 pub use footer::Footer;
 pub use header::Header;
-pub use horizontal_panel::HorizontalPanel;
+pub use horizontal_panel::{HorizontalPanel, WrappedPanel};
+pub use repeat_header::RepeatHeader;
+pub use summary::{Aggregation, Summary};
 pub use vertical_panel::VerticalPanel;
 
 /// Panel allows to add a Row which has 1 continues Cell to a [`Table`].
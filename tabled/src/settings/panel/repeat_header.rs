@@ -0,0 +1,99 @@
+use crate::{
+    grid::{
+        colors::Colors,
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    },
+    settings::TableOption,
+};
+
+use super::horizontal_panel::{move_row_spans, move_rows_aside};
+
+/// RepeatHeader inserts a copy of a first row after each `N` rows.
+///
+/// It's useful for long tables so a header stays visible in a paged output.
+/// Column spans and colors of the header are copied as well.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{panel::RepeatHeader, Style}};
+///
+/// let data = [[1, 2], [3, 4], [5, 6]];
+///
+/// let table = Table::new(data)
+///     .with(Style::modern())
+///     .with(RepeatHeader::new(2))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "┌───┬───┐\n",
+///         "│ 0 │ 1 │\n",
+///         "├───┼───┤\n",
+///         "│ 1 │ 2 │\n",
+///         "├───┼───┤\n",
+///         "│ 3 │ 4 │\n",
+///         "├───┼───┤\n",
+///         "│ 0 │ 1 │\n",
+///         "├───┼───┤\n",
+///         "│ 5 │ 6 │\n",
+///         "└───┴───┘",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RepeatHeader {
+    every: usize,
+}
+
+impl RepeatHeader {
+    /// Creates a new object which repeats a header every `every` rows.
+    ///
+    /// If `every` is `0` nothing is changed.
+    pub fn new(every: usize) -> Self {
+        Self { every }
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for RepeatHeader
+where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_columns = records.count_columns();
+        if self.every == 0 || records.count_rows() < 2 || count_columns == 0 {
+            return;
+        }
+
+        let header = (0..count_columns)
+            .map(|col| {
+                let text = records.get_text((0, col)).to_owned();
+                let span = cfg.get_column_span((0, col));
+                let color = cfg.get_colors().get_color((0, col)).cloned();
+                (text, span, color)
+            })
+            .collect::<Vec<_>>();
+
+        let mut row = self.every + 1;
+        while row < records.count_rows() {
+            move_rows_aside(records, row);
+            move_row_spans(cfg, row);
+
+            for (col, (text, span, color)) in header.iter().enumerate() {
+                records.set((row, col), text.clone());
+
+                if let Some(span) = span {
+                    cfg.set_column_span((row, col), *span);
+                }
+
+                if let Some(color) = color {
+                    let _ = cfg.set_color(Entity::Cell(row, col), color.clone());
+                }
+            }
+
+            row += self.every + 1;
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    grid::config::ColoredConfig,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut, Resizable},
+    settings::TableOption,
+};
+
+/// Summary adds a footer row with aggregated values of numeric columns.
+///
+/// A column is considered numeric if at least one of its cells can be parsed as a number,
+/// cells which can't be parsed are skipped.
+/// Non numeric columns are left blank, except a first one which is used for a [`Summary::label`].
+///
+/// The first row is considered to be a header and is not aggregated.
+/// It can be changed via [`Summary::offset`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{panel::{Aggregation, Footer}, Style}};
+///
+/// let data = [
+///     ("Apple", 3, 1.5),
+///     ("Orange", 2, 2.25),
+///     ("Banana", 5, 0.5),
+/// ];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql())
+///     .with(Footer::summary().label("Total").column(2, Aggregation::Avg))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str   | i32 | f64  \n",
+///         "--------+-----+------\n",
+///         " Apple  | 3   | 1.5  \n",
+///         " Orange | 2   | 2.25 \n",
+///         " Banana | 5   | 0.5  \n",
+///         " Total  | 10  | 1.42 ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Summary {
+    aggregation: Aggregation,
+    columns: Vec<(usize, Aggregation)>,
+    label: Option<String>,
+    offset: usize,
+}
+
+/// Aggregation defines how values of a column are summarized by [`Summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Aggregation {
+    /// A sum of values.
+    Sum,
+    /// A number of numeric values.
+    Count,
+    /// An average of values.
+    Avg,
+    /// A minimum value.
+    Min,
+    /// A maximum value.
+    Max,
+}
+
+impl Default for Aggregation {
+    fn default() -> Self {
+        Self::Sum
+    }
+}
+
+impl Summary {
+    /// Creates a new [`Summary`] which sums all numeric columns.
+    pub fn new() -> Self {
+        Self {
+            aggregation: Aggregation::Sum,
+            columns: Vec::new(),
+            label: None,
+            offset: 1,
+        }
+    }
+
+    /// Sets an aggregation used for all columns, which were not set via [`Summary::column`].
+    pub fn aggregation(mut self, aggregation: Aggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Sets an aggregation of a particular column.
+    pub fn column(mut self, column: usize, aggregation: Aggregation) -> Self {
+        self.columns.push((column, aggregation));
+        self
+    }
+
+    /// Sets a label which is put in a first non numeric column.
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets a row from which values are aggregated.
+    ///
+    /// By default it's 1, so a header is skipped.
+    pub fn offset(mut self, row: usize) -> Self {
+        self.offset = row;
+        self
+    }
+
+    fn get_aggregation(&self, column: usize) -> Aggregation {
+        self.columns
+            .iter()
+            .rev()
+            .find(|(col, _)| *col == column)
+            .map_or(self.aggregation, |(_, aggregation)| *aggregation)
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Summary
+where
+    R: Records + ExactRecords + PeekableRecords + Resizable + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_columns == 0 {
+            return;
+        }
+
+        let mut values = Vec::with_capacity(count_columns);
+        for col in 0..count_columns {
+            let mut column = Column::default();
+            for row in self.offset..count_rows {
+                column.push(records.get_text((row, col)));
+            }
+
+            let aggregation = self.get_aggregation(col);
+            values.push(column.aggregate(aggregation));
+        }
+
+        records.push_row();
+
+        let mut label = self.label;
+        for (col, value) in values.into_iter().enumerate() {
+            let text = match value {
+                Some(value) => value,
+                None => match label.take() {
+                    Some(label) => label,
+                    None => continue,
+                },
+            };
+
+            records.set((count_rows, col), text);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Column {
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    count: usize,
+    precision: usize,
+}
+
+impl Column {
+    fn push(&mut self, text: &str) {
+        let text = text.trim();
+        let value = match text.parse::<f64>() {
+            Ok(value) if value.is_finite() => value,
+            _ => return,
+        };
+
+        let precision = text.split_once('.').map_or(0, |(_, fraction)| {
+            fraction.chars().take_while(char::is_ascii_digit).count()
+        });
+
+        self.sum += value;
+        self.count += 1;
+        self.precision = std::cmp::max(self.precision, precision);
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+
+    fn aggregate(&self, aggregation: Aggregation) -> Option<String> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let precision = self.precision;
+        let text = match aggregation {
+            Aggregation::Sum => format!("{:.*}", precision, self.sum),
+            Aggregation::Count => self.count.to_string(),
+            Aggregation::Avg => {
+                let precision = std::cmp::max(precision, 2);
+                format!("{:.*}", precision, self.sum / self.count as f64)
+            }
+            Aggregation::Min => format!("{:.*}", precision, self.min.unwrap_or_default()),
+            Aggregation::Max => format!("{:.*}", precision, self.max.unwrap_or_default()),
+        };
+
+        Some(text)
+    }
+}
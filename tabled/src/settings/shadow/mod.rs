@@ -45,12 +45,16 @@ pub struct Shadow {
     size_offset: usize,
     direction: Sides<bool>,
     color: Option<Color>,
+    gradient: bool,
 }
 
 impl Shadow {
     /// A default fill character to be used.
     pub const DEFAULT_FILL: char = '▒';
 
+    /// Fill characters used by a gradient, from the closest to a table to the farthest.
+    pub const GRADIENT_FILL: [char; 3] = ['▓', '▒', '░'];
+
     /// Construct's an [`Shadow`] object with default fill [`Shadow::DEFAULT_FILL`].
     ///
     /// It uses space(' ') as a default fill character.
@@ -62,6 +66,7 @@ impl Shadow {
             size_offset: 1,
             direction: Sides::new(false, true, false, true),
             color: None,
+            gradient: false,
         }
     }
 
@@ -110,6 +115,33 @@ impl Shadow {
         self.color = Some(color);
         self
     }
+
+    /// Makes a shadow fade out using [`Shadow::GRADIENT_FILL`] characters across its size.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Shadow, Style}};
+    ///
+    /// let table = Table::new(["Hello"])
+    ///     .with(Style::markdown())
+    ///     .with(Shadow::new(3).gradient(true))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "| &str  |   \n",
+    ///         "|-------|▓▒░\n",
+    ///         "| Hello |▓▒░\n",
+    ///         " ▓▓▓▓▓▓▓▓▓▓▓\n",
+    ///         " ▒▒▒▒▒▒▒▒▒▒▒\n",
+    ///         " ░░░░░░░░░░░",
+    ///     )
+    /// );
+    /// ```
+    pub fn gradient(mut self, on: bool) -> Self {
+        self.gradient = on;
+        self
+    }
 }
 
 impl<R, D> TableOption<R, D, ColoredConfig> for Shadow {
@@ -117,6 +149,13 @@ impl<R, D> TableOption<R, D, ColoredConfig> for Shadow {
         set_margin(cfg, self.size, self.c, &self.direction);
         set_margin_offset(cfg, self.size_offset, &self.direction);
 
+        let layers = if self.gradient {
+            get_gradient(self.size)
+        } else {
+            Vec::new()
+        };
+        set_margin_layers(cfg, layers, &self.direction);
+
         if let Some(color) = &self.color {
             set_margin_color(cfg, color.clone().into(), &self.direction);
         }
@@ -148,6 +187,32 @@ fn set_margin(cfg: &mut ColoredConfig, size: usize, c: char, direction: &Sides<b
     cfg.set_margin(margin);
 }
 
+fn set_margin_layers(cfg: &mut ColoredConfig, layers: Vec<char>, direction: &Sides<bool>) {
+    let mut margin: Sides<Vec<char>> = Sides::default();
+    if direction.top {
+        margin.top = layers.clone();
+    }
+
+    if direction.bottom {
+        margin.bottom = layers.clone();
+    }
+
+    if direction.left {
+        margin.left = layers.clone();
+    }
+
+    if direction.right {
+        margin.right = layers;
+    }
+
+    cfg.set_margin_layers(margin);
+}
+
+fn get_gradient(size: usize) -> Vec<char> {
+    let fill = Shadow::GRADIENT_FILL;
+    (0..size).map(|i| fill[i * fill.len() / size]).collect()
+}
+
 fn set_margin_offset(cfg: &mut ColoredConfig, size: usize, direction: &Sides<bool>) {
     let mut margin = Sides::filled(Offset::Begin(0));
     if direction.right && direction.bottom {
@@ -1,27 +1,61 @@
 use crate::{
     grid::{
         config::{ColoredConfig, Entity, Position, SpannedConfig},
-        records::{ExactRecords, Records},
+        records::{ExactRecords, PeekableRecords, Records, RecordsMut},
     },
     settings::CellOption,
 };
 
 /// Columns (Vertical) span.
+///
+/// By default a content of the covered cells is hidden,
+/// but it can be combined with the spanned cell via [`ColumnSpan::join`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ColumnSpan {
+pub struct ColumnSpan<F = ()> {
     size: usize,
+    join: F,
 }
 
 impl ColumnSpan {
     /// Creates a new column (vertical) span.
     pub fn new(size: usize) -> Self {
-        Self { size }
+        Self { size, join: () }
     }
 
     /// Creates a new column (vertical) span with a maximux value possible.
     pub fn max() -> Self {
         Self::new(usize::MAX)
     }
+
+    /// Sets a function which combines a content of the spanned cell
+    /// with a content of each covered cell, from left to right.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Span, Modify, Style}};
+    ///
+    /// let data = [["John", "Smith", "42"]];
+    ///
+    /// let table = Table::new(data)
+    ///     .with(Style::psql())
+    ///     .with(Modify::new((1, 0)).with(Span::column(2).join(|a, b| format!("{a} {b}"))))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     " 0    | 1   | 2  \n\
+    ///      ------+-----+----\n \
+    ///       John Smith | 42 "
+    /// );
+    /// ```
+    pub fn join<F>(self, f: F) -> ColumnSpan<F>
+    where
+        F: FnMut(&str, &str) -> String,
+    {
+        ColumnSpan {
+            size: self.size,
+            join: f,
+        }
+    }
 }
 
 impl<R> CellOption<R, ColoredConfig> for ColumnSpan
@@ -32,12 +66,48 @@ where
         let count_rows = records.count_rows();
         let count_cols = records.count_columns();
 
-        set_col_spans(cfg, self.size, entity, (count_rows, count_cols));
+        set_col_spans(cfg, self.size, entity, (count_rows, count_cols), |_, _| {});
         remove_false_spans(cfg);
     }
 }
 
-fn set_col_spans(cfg: &mut SpannedConfig, span: usize, entity: Entity, shape: (usize, usize)) {
+impl<R, F> CellOption<R, ColoredConfig> for ColumnSpan<F>
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    F: FnMut(&str, &str) -> String,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        let join = &mut self.join;
+        set_col_spans(
+            cfg,
+            self.size,
+            entity,
+            (count_rows, count_cols),
+            |(row, col), span| {
+                let mut text = records.get_text((row, col)).to_owned();
+                for i in col + 1..col + span {
+                    text = join(&text, records.get_text((row, i)));
+                }
+
+                records.set((row, col), text);
+            },
+        );
+        remove_false_spans(cfg);
+    }
+}
+
+fn set_col_spans<F>(
+    cfg: &mut SpannedConfig,
+    span: usize,
+    entity: Entity,
+    shape: (usize, usize),
+    mut on_span: F,
+) where
+    F: FnMut(Position, usize),
+{
     for pos in entity.iter(shape.0, shape.1) {
         if !is_valid_pos(pos, shape) {
             continue;
@@ -52,6 +122,10 @@ fn set_col_spans(cfg: &mut SpannedConfig, span: usize, entity: Entity, shape: (u
             continue;
         }
 
+        if span > 1 {
+            on_span(pos, span);
+        }
+
         set_span_column(cfg, pos, span);
     }
 }
@@ -87,7 +161,7 @@ fn closest_visible(cfg: &SpannedConfig, mut pos: Position) -> Option<usize> {
 }
 
 fn is_column_span_valid(col: usize, span: usize, count_cols: usize) -> bool {
-    span + col <= count_cols
+    span <= count_cols - col
 }
 
 fn is_valid_pos((row, col): Position, (count_rows, count_cols): (usize, usize)) -> bool {
@@ -35,10 +35,10 @@ pub use row::RowSpan;
 
 /// Span represent a horizontal/column span setting for any cell on a [`Table`].
 ///
-/// It will be ignored if:
-///  - cell position is out of scope
-///  - size is bigger then the total number of columns.
-///  - size is bigger then the total number of rows.
+/// It will be ignored if a cell position is out of scope.
+///
+/// A size which is bigger then a number of columns (rows) left
+/// is clamped, so the cell spans up to the last column (row).
 ///
 /// ```rust,no_run
 /// # use tabled::{Table, settings::{Style, Span, Modify, object::Columns}};
@@ -54,14 +54,16 @@ pub struct Span;
 impl Span {
     /// New constructs a horizontal/column [`Span`].
     ///
-    /// If size is bigger then the total number of columns it will be ignored.
+    /// If size is bigger then a number of columns to the right of the cell (including it),
+    /// the span is clamped to cover all of them.
     pub fn column(size: usize) -> ColumnSpan {
         ColumnSpan::new(size)
     }
 
     /// New constructs a vertical/row [`Span`].
     ///
-    /// If size is bigger then the total number of rows it will be ignored.
+    /// If size is bigger then a number of rows below the cell (including it),
+    /// the span is clamped to cover all of them.
     pub fn row(size: usize) -> RowSpan {
         RowSpan::new(size)
     }
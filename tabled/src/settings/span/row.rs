@@ -88,7 +88,7 @@ fn closest_visible_row(cfg: &SpannedConfig, mut pos: Position) -> Option<usize>
 }
 
 fn is_row_span_valid(row: usize, span: usize, count_rows: usize) -> bool {
-    span + row <= count_rows
+    span <= count_rows - row
 }
 
 fn is_valid_pos((row, col): Position, (count_rows, count_cols): (usize, usize)) -> bool {
@@ -0,0 +1,152 @@
+//! This module contains a [`Sparkline`] renderer.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::settings::Sparkline;
+//!
+//! let line = Sparkline::new(8).render(&[1, 2, 3, 4, 5, 6, 7, 8]);
+//!
+//! assert_eq!(line, "▁▂▃▄▅▆▇█");
+//! ```
+
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// [`Sparkline`] turns a series of numbers into a fixed width string of block characters.
+///
+/// The range of values is mapped onto block heights `▁▂▃▄▅▆▇█`.
+/// A series longer than the width is squeezed by averaging neighbour values,
+/// a shorter one is padded by spaces.
+///
+/// An empty series is rendered as spaces, while a constant one as a flat line of lowest blocks.
+///
+/// It's meant to be used together with `#[tabled(display_with)]`.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, Tabled, settings::{Sparkline, Style}};
+///
+/// #[derive(Tabled)]
+/// struct Metric {
+///     name: &'static str,
+///     #[tabled(display_with = "sparkline")]
+///     history: Vec<f64>,
+/// }
+///
+/// fn sparkline(values: &[f64]) -> String {
+///     Sparkline::new(6).render(values)
+/// }
+///
+/// let data = [
+///     Metric { name: "cpu", history: vec![0.1, 0.5, 0.9, 0.4, 0.2, 0.3] },
+///     Metric { name: "mem", history: vec![0.5, 0.5, 0.5] },
+/// ];
+///
+/// let table = Table::new(data).with(Style::markdown()).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "| name | history |\n\
+///      |------|---------|\n\
+///      | cpu  | ▁▅█▄▂▃  |\n\
+///      | mem  | ▁▁▁     |",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Sparkline {
+    width: usize,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Sparkline {
+    /// Creates a [`Sparkline`] of a given width.
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Sets a fixed range of values.
+    ///
+    /// By default the range is taken from the series itself.
+    /// Values out of the range are clamped.
+    ///
+    /// ```
+    /// use tabled::settings::Sparkline;
+    ///
+    /// let line = Sparkline::new(4).range(0.0, 100.0).render(&[-10, 50, 100, 200]);
+    ///
+    /// assert_eq!(line, "▁▅██");
+    /// ```
+    pub const fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    /// Renders a series of numbers.
+    ///
+    /// Not a number values are rendered as spaces.
+    pub fn render<T>(&self, values: &[T]) -> String
+    where
+        T: Copy + Into<f64>,
+    {
+        let values = squeeze(values, self.width);
+
+        let finite = || values.iter().copied().filter(|v| v.is_finite());
+        let min = self
+            .min
+            .unwrap_or_else(|| finite().fold(f64::INFINITY, f64::min));
+        let max = self
+            .max
+            .unwrap_or_else(|| finite().fold(f64::NEG_INFINITY, f64::max));
+
+        let mut buf = String::with_capacity(self.width * 3);
+        for &value in &values {
+            buf.push(block(value, min, max));
+        }
+
+        let padding = self.width.saturating_sub(values.len());
+        buf.extend(std::iter::repeat(' ').take(padding));
+
+        buf
+    }
+}
+
+fn squeeze<T>(values: &[T], width: usize) -> Vec<f64>
+where
+    T: Copy + Into<f64>,
+{
+    if values.len() <= width {
+        return values.iter().map(|&v| v.into()).collect();
+    }
+
+    (0..width)
+        .map(|i| {
+            let start = i * values.len() / width;
+            let end = (i + 1) * values.len() / width;
+            let bucket = &values[start..end];
+            bucket.iter().map(|&v| v.into()).sum::<f64>() / bucket.len() as f64
+        })
+        .collect()
+}
+
+fn block(value: f64, min: f64, max: f64) -> char {
+    if !value.is_finite() {
+        return ' ';
+    }
+
+    let range = max - min;
+    if range.is_nan() || range <= 0.0 {
+        return BLOCKS[0];
+    }
+
+    let value = value.max(min).min(max);
+    let level = ((value - min) / range * (BLOCKS.len() - 1) as f64).round() as usize;
+
+    BLOCKS[level.min(BLOCKS.len() - 1)]
+}
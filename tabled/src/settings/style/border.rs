@@ -2,10 +2,14 @@ use core::marker::PhantomData;
 
 use crate::{
     grid::{
-        config::{Border as GridBorder, ColoredConfig, Entity},
+        config::{Border as GridBorder, ColoredConfig, Entity, Position, Sides},
         records::{ExactRecords, Records},
     },
-    settings::{style::On, CellOption},
+    settings::{
+        highlight::{build_cell_border, split_segments},
+        style::On,
+        CellOption,
+    },
 };
 
 /// Border represents a border of a Cell.
@@ -31,6 +35,10 @@ use crate::{
 ///     .with(Style::ascii())
 ///     .modify(Rows::single(0), Border::new().set_top('x'));
 /// ```
+///
+/// The getters of a set side never panic;
+/// if a character turned out to be missing a space (`' '`) is returned.
+/// Use `try_get_*` methods to distinguish such a case.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Border<T, B, L, R> {
     inner: GridBorder<char>,
@@ -92,7 +100,7 @@ impl Border<On, On, On, On> {
 
     /// Using this function you deconstruct the existing borders.
     pub const fn empty() -> EmptyBorder {
-        EmptyBorder
+        EmptyBorder::new()
     }
 }
 
@@ -125,6 +133,46 @@ impl<T, B, L, R> Border<T, B, L, R> {
     pub const fn into_inner(self) -> GridBorder<char> {
         self.inner
     }
+
+    /// Get a top character if it's set.
+    pub const fn try_get_top(&self) -> Option<char> {
+        self.inner.top
+    }
+
+    /// Get a bottom character if it's set.
+    pub const fn try_get_bottom(&self) -> Option<char> {
+        self.inner.bottom
+    }
+
+    /// Get a left character if it's set.
+    pub const fn try_get_left(&self) -> Option<char> {
+        self.inner.left
+    }
+
+    /// Get a right character if it's set.
+    pub const fn try_get_right(&self) -> Option<char> {
+        self.inner.right
+    }
+
+    /// Get a top left intersection character if it's set.
+    pub const fn try_get_corner_top_left(&self) -> Option<char> {
+        self.inner.left_top_corner
+    }
+
+    /// Get a top right intersection character if it's set.
+    pub const fn try_get_corner_top_right(&self) -> Option<char> {
+        self.inner.right_top_corner
+    }
+
+    /// Get a bottom left intersection character if it's set.
+    pub const fn try_get_corner_bottom_left(&self) -> Option<char> {
+        self.inner.left_bottom_corner
+    }
+
+    /// Get a bottom right intersection character if it's set.
+    pub const fn try_get_corner_bottom_right(&self) -> Option<char> {
+        self.inner.right_bottom_corner
+    }
 }
 
 impl<T, B, L> Border<T, B, L, On> {
@@ -220,10 +268,76 @@ where
     fn change(self, records: &mut Data, cfg: &mut ColoredConfig, entity: Entity) {
         CellOption::change(self.inner, records, cfg, entity)
     }
+
+    fn change_region(self, records: &mut Data, cfg: &mut ColoredConfig, entities: &[Entity]) {
+        let shape = (records.count_rows(), records.count_columns());
+
+        // only a perimeter of a region is set so inner lines are left untouched.
+        let segments = split_segments(entities.iter().copied(), shape.0, shape.1);
+        for sector in segments {
+            for &pos in &sector {
+                let mut border = build_cell_border(&sector, pos, &self.inner);
+                remove_dangling_corners(cfg, &mut border, pos, shape);
+                cfg.set_border(pos, border);
+            }
+        }
+    }
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct EmptyBorder;
+/// EmptyBorder removes a border of a cell.
+///
+/// By default all sides are removed,
+/// but it can be limited to a single side.
+///
+/// ```rust,no_run
+/// # use tabled::{Table, settings::{style::{Style, Border, EmptyBorder}, object::Rows}};
+/// # let data: Vec<&'static str> = Vec::new();
+/// let table = Table::new(&data)
+///     .with(Style::ascii())
+///     .modify(Rows::single(0), Border::filled('*'))
+///     .modify(Rows::single(0), EmptyBorder::bottom());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EmptyBorder {
+    sides: Sides<bool>,
+}
+
+impl EmptyBorder {
+    /// Removes all sides of a border.
+    pub const fn new() -> Self {
+        Self::from_sides(Sides::filled(true))
+    }
+
+    /// Removes only a top side of a border.
+    pub const fn top() -> Self {
+        Self::from_sides(Sides::new(false, false, true, false))
+    }
+
+    /// Removes only a bottom side of a border.
+    pub const fn bottom() -> Self {
+        Self::from_sides(Sides::new(false, false, false, true))
+    }
+
+    /// Removes only a left side of a border.
+    pub const fn left() -> Self {
+        Self::from_sides(Sides::new(true, false, false, false))
+    }
+
+    /// Removes only a right side of a border.
+    pub const fn right() -> Self {
+        Self::from_sides(Sides::new(false, true, false, false))
+    }
+
+    const fn from_sides(sides: Sides<bool>) -> Self {
+        Self { sides }
+    }
+}
+
+impl Default for EmptyBorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl<R> CellOption<R, ColoredConfig> for EmptyBorder
 where
@@ -233,15 +347,18 @@ where
         let shape = (records.count_rows(), records.count_columns());
 
         for pos in entity.iter(shape.0, shape.1) {
-            cfg.remove_border(pos, shape);
+            cfg.remove_border_side(pos, self.sides, shape);
         }
     }
 }
 
+// The type state is expected to guarantee that a char is set,
+// but corners are not tracked by it (e.g. a frame of a style with no corners),
+// so we fallback to a space as it's done for missing borders in a grid.
 const fn get_char(c: Option<char>) -> char {
     match c {
         Some(c) => c,
-        None => unreachable!(),
+        None => ' ',
     }
 }
 
@@ -257,3 +374,64 @@ where
         }
     }
 }
+
+// A perimeter crosses inner lines of a region by corners,
+// but we must not create a line which doesn't exist by setting a corner.
+fn remove_dangling_corners(
+    cfg: &ColoredConfig,
+    border: &mut GridBorder<char>,
+    pos: Position,
+    (count_rows, count_cols): (usize, usize),
+) {
+    if border.top.is_none() && !cfg.has_horizontal(pos.0, count_rows) {
+        border.left_top_corner = None;
+        border.right_top_corner = None;
+    }
+
+    if border.bottom.is_none() && !cfg.has_horizontal(pos.0 + 1, count_rows) {
+        border.left_bottom_corner = None;
+        border.right_bottom_corner = None;
+    }
+
+    if border.left.is_none() && !cfg.has_vertical(pos.1, count_cols) {
+        border.left_top_corner = None;
+        border.left_bottom_corner = None;
+    }
+
+    if border.right.is_none() && !cfg.has_vertical(pos.1 + 1, count_cols) {
+        border.right_top_corner = None;
+        border.right_bottom_corner = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_missing_char_doesnt_panic() {
+        let border = Border::<On, On, On, On>::from_border(GridBorder::empty());
+
+        assert_eq!(border.get_top(), ' ');
+        assert_eq!(border.get_bottom(), ' ');
+        assert_eq!(border.get_left(), ' ');
+        assert_eq!(border.get_right(), ' ');
+        assert_eq!(border.get_corner_top_left(), ' ');
+        assert_eq!(border.get_corner_top_right(), ' ');
+        assert_eq!(border.get_corner_bottom_left(), ' ');
+        assert_eq!(border.get_corner_bottom_right(), ' ');
+
+        assert_eq!(border.try_get_top(), None);
+        assert_eq!(border.try_get_corner_bottom_right(), None);
+    }
+
+    #[test]
+    fn test_try_get_char() {
+        let border = Border::new().set_top('-').set_left('|');
+
+        assert_eq!(border.try_get_top(), Some('-'));
+        assert_eq!(border.try_get_left(), Some('|'));
+        assert_eq!(border.try_get_right(), None);
+        assert_eq!(border.try_get_corner_top_left(), None);
+    }
+}
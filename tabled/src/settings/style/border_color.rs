@@ -133,6 +133,46 @@ impl<T, B, L, R> BorderColor<T, B, L, R> {
     pub fn into_inner(self) -> GridBorder<Color> {
         self.inner
     }
+
+    /// Get a top color if it's set.
+    pub fn try_get_top(&self) -> Option<&Color> {
+        self.inner.top.as_ref()
+    }
+
+    /// Get a bottom color if it's set.
+    pub fn try_get_bottom(&self) -> Option<&Color> {
+        self.inner.bottom.as_ref()
+    }
+
+    /// Get a left color if it's set.
+    pub fn try_get_left(&self) -> Option<&Color> {
+        self.inner.left.as_ref()
+    }
+
+    /// Get a right color if it's set.
+    pub fn try_get_right(&self) -> Option<&Color> {
+        self.inner.right.as_ref()
+    }
+
+    /// Get a top left intersection color if it's set.
+    pub fn try_get_corner_top_left(&self) -> Option<&Color> {
+        self.inner.left_top_corner.as_ref()
+    }
+
+    /// Get a top right intersection color if it's set.
+    pub fn try_get_corner_top_right(&self) -> Option<&Color> {
+        self.inner.right_top_corner.as_ref()
+    }
+
+    /// Get a bottom left intersection color if it's set.
+    pub fn try_get_corner_bottom_left(&self) -> Option<&Color> {
+        self.inner.left_bottom_corner.as_ref()
+    }
+
+    /// Get a bottom right intersection color if it's set.
+    pub fn try_get_corner_bottom_right(&self) -> Option<&Color> {
+        self.inner.right_bottom_corner.as_ref()
+    }
 }
 
 impl<T, B, L> BorderColor<T, B, L, On> {
@@ -156,7 +156,7 @@ fn set_horizontal_chars<D: Dimension>(
     };
 
     let mut chars = text.chars();
-    let mut i = cfg.has_vertical(0, count_columns) as usize;
+    let mut i = cfg.get_vertical_width(0, count_columns);
     if i == 1 && pos == 0 {
         let c = match chars.next() {
             Some(c) => c,
@@ -200,7 +200,8 @@ fn set_horizontal_chars<D: Dimension>(
 
         i += w;
 
-        if cfg.has_vertical(col + 1, count_columns) {
+        let vertical_width = cfg.get_vertical_width(col + 1, count_columns);
+        if vertical_width > 0 {
             i += 1;
 
             if i > pos {
@@ -219,6 +220,9 @@ fn set_horizontal_chars<D: Dimension>(
                     cfg.set_border_color((line, col), b);
                 }
             }
+
+            // a vertical suffix can't be changed so the text continues after it
+            i += vertical_width - 1;
         }
     }
 }
@@ -252,10 +256,10 @@ where
 }
 
 fn total_width<D: Dimension>(cfg: &SpannedConfig, dims: &D, count_columns: usize) -> usize {
-    let mut totalw = cfg.has_vertical(0, count_columns) as usize;
+    let mut totalw = cfg.get_vertical_width(0, count_columns);
     for col in 0..count_columns {
         totalw += dims.get_width(col);
-        totalw += cfg.has_vertical(col + 1, count_columns) as usize;
+        totalw += cfg.get_vertical_width(col + 1, count_columns);
     }
 
     totalw
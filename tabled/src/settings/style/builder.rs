@@ -13,7 +13,7 @@ use crate::{
     settings::Border,
 };
 
-use super::{HorizontalLine, Line, VerticalLine};
+use super::{HorizontalLine, Line, VerticalLine, Weight};
 
 /// Style is represents a theme of a [`Table`].
 ///
@@ -153,6 +153,25 @@ impl Style<(), (), (), (), (), ()> {
         )
     }
 
+    /// This style is a compact analog of `blank`, with a single space between columns.
+    ///
+    /// It also removes a padding of cells, so there's no indent on the edges.
+    ///
+    /// ```text
+    /// id distribution link
+    /// 0  Fedora       https://getfedora.org/
+    /// 2  OpenSUSE     https://www.opensuse.org/
+    /// 3  Endeavouros  https://endeavouros.com/
+    /// ```
+    ///
+    /// A header can be separated by a blank line using [`SpaceStyle::header_gap`].
+    ///
+    /// [`SpaceStyle::header_gap`]: crate::settings::style::SpaceStyle::header_gap
+    #[cfg(feature = "std")]
+    pub const fn space() -> crate::settings::style::SpaceStyle {
+        crate::settings::style::SpaceStyle::new()
+    }
+
     /// This is a style which relays only on ASCII charset.
     ///
     /// It has horizontal and vertical lines.
@@ -525,6 +544,22 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
         Style::new(self.borders, list, self.verticals)
     }
 
+    /// Sets a char of a vertical line after a given column,
+    /// to separate groups of columns.
+    ///
+    /// See [`GroupSeparator`].
+    ///
+    /// [`GroupSeparator`]: crate::settings::style::GroupSeparator
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const fn group_separator(
+        self,
+        column: usize,
+        c: char,
+    ) -> crate::settings::style::GroupSeparator<Self> {
+        crate::settings::style::GroupSeparator::new(self, column, c)
+    }
+
     /// Set border vertical lines.
     ///
     /// # Example
@@ -572,6 +607,46 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
         Style::new(self.borders, self.horizontals, [])
     }
 
+    /// Removes all inner lines, leaving only an outer frame.
+    ///
+    /// It's the same as calling [`Style::remove_horizontal`], [`Style::remove_vertical`],
+    /// [`Style::remove_horizontals`] and [`Style::remove_verticals`].
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "std", doc = "```")]
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    /// use tabled::{settings::style::Style, Table};
+    ///
+    /// let data = (0..3).map(|i| ("Hello", "World", i));
+    /// let table = Table::new(data)
+    ///    .with(Style::modern().frame_only())
+    ///    .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┌───────────────────┐\n",
+    ///         "│ &str   &str   i32 │\n",
+    ///         "│ Hello  World  0   │\n",
+    ///         "│ Hello  World  1   │\n",
+    ///         "│ Hello  World  2   │\n",
+    ///         "└───────────────────┘",
+    ///     )
+    /// )
+    /// ```
+    pub const fn frame_only(mut self) -> Style<T, B, L, R, (), (), 0, 0> {
+        self.borders.horizontal = None;
+        self.borders.vertical = None;
+        self.borders.intersection = None;
+        self.borders.left_intersection = None;
+        self.borders.right_intersection = None;
+        self.borders.top_intersection = None;
+        self.borders.bottom_intersection = None;
+
+        Style::new(self.borders, [], [])
+    }
+
     /// Sets a top border.
     ///
     /// Any corners and intersections which were set will be overridden.
@@ -984,6 +1059,57 @@ impl<T, B, L, R, H, V, const HN: usize, const VN: usize> Style<T, B, L, R, H, V,
     }
 }
 
+impl<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize> Style<T, B, L, R, H, V, HSIZE, VSIZE>
+where
+    T: Copy,
+    B: Copy,
+    L: Copy,
+    R: Copy,
+    H: Copy,
+    V: Copy,
+{
+    /// Sets a weight of box-drawing characters of the style,
+    /// including custom horizontal and vertical lines.
+    ///
+    /// Characters which are not box-drawing ones are left untouched.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "std", doc = "```")]
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    /// use tabled::{Table, settings::{style::Weight, Style}};
+    ///
+    /// let data = [["Hello", "World"]];
+    /// let table = Table::new(data)
+    ///     .with(Style::modern().weight(Weight::Heavy))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "┏━━━━━━━┳━━━━━━━┓\n",
+    ///         "┃ 0     ┃ 1     ┃\n",
+    ///         "┣━━━━━━━╋━━━━━━━┫\n",
+    ///         "┃ Hello ┃ World ┃\n",
+    ///         "┗━━━━━━━┻━━━━━━━┛",
+    ///     )
+    /// );
+    /// ```
+    pub const fn weight(mut self, weight: Weight) -> Self {
+        self.borders = borders_weight(self.borders, weight);
+
+        let horizontals = harray_convert(self.horizontals);
+        let horizontals = linearr_weight(horizontals, weight);
+        let horizontals = linearr_convert_to_harray(horizontals);
+
+        let verticals = varray_convert(self.verticals);
+        let verticals = linearr_weight(verticals, weight);
+        let verticals = linearr_convert_to_varray(verticals);
+
+        Style::new(self.borders, horizontals, verticals)
+    }
+}
+
 impl<T, B, L, R, H, V, const HSIZE: usize, const VSIZE: usize>
     Style<T, B, L, R, H, V, HSIZE, VSIZE>
 {
@@ -1088,6 +1214,42 @@ impl<T, R, H, V, const HN: usize, const VN: usize> Style<T, On, On, R, H, V, HN,
     }
 }
 
+impl<H, V, const HN: usize, const VN: usize> Style<On, On, On, On, H, V, HN, VN> {
+    /// Sets all 4 outer corners of a frame at once.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let data = [["Hello", "World"]];
+    /// let table = Table::new(data)
+    ///     .with(Style::modern().corners('╭', '╮', '╰', '╯'))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "╭───────┬───────╮\n\
+    ///      │ 0     │ 1     │\n\
+    ///      ├───────┼───────┤\n\
+    ///      │ Hello │ World │\n\
+    ///      ╰───────┴───────╯"
+    /// );
+    /// ```
+    pub const fn corners(
+        mut self,
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+    ) -> Self {
+        self.borders.top_left = Some(top_left);
+        self.borders.top_right = Some(top_right);
+        self.borders.bottom_left = Some(bottom_left);
+        self.borders.bottom_right = Some(bottom_right);
+
+        Style::new(self.borders, self.horizontals, self.verticals)
+    }
+}
+
 impl<T, B, R, V, const HN: usize, const VN: usize> Style<T, B, On, R, On, V, HN, VN> {
     /// Sets a left intersection char.
     pub const fn intersection_left(mut self, c: char) -> Self {
@@ -1150,6 +1312,20 @@ impl<T, B, L, R, const HN: usize, const VN: usize> Style<T, B, L, R, On, On, HN,
 
         Style::new(self.borders, horizontals, verticals)
     }
+
+    /// Sets a char which is drawn where an edge of a spanned cell meets a separator line.
+    ///
+    /// See [`SpanIntersection`].
+    ///
+    /// [`SpanIntersection`]: crate::settings::style::SpanIntersection
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub const fn span_intersection(
+        self,
+        c: char,
+    ) -> crate::settings::style::SpanIntersection<Self> {
+        crate::settings::style::SpanIntersection::new(self, c)
+    }
 }
 
 impl<B, L, R, H, V, const HN: usize, const VN: usize> Style<On, B, L, R, H, V, HN, VN> {
@@ -1303,6 +1479,37 @@ impl<T, B, L, R, V, const HN: usize, const VN: usize> Style<T, B, L, R, On, V, H
 }
 
 impl<T, B, L, R, H, const HN: usize, const VN: usize> Style<T, B, L, R, H, On, HN, VN> {
+    /// Sets a character of vertical split lines.
+    ///
+    /// In comparison to [`Style::vertical`] it changes neither intersections nor the frame.
+    /// Custom lines set by [`Style::verticals`] are not changed either.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "std", doc = "```")]
+    #[cfg_attr(not(feature = "std"), doc = "```ignore")]
+    /// use tabled::{settings::style::Style, Table};
+    ///
+    /// let data = (0..2).map(|i| ("Hello", i));
+    /// let table = Table::new(data)
+    ///    .with(Style::psql().vertical_char('¦'))
+    ///    .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         " &str  ¦ i32 \n",
+    ///         "-------+-----\n",
+    ///         " Hello ¦ 0   \n",
+    ///         " Hello ¦ 1   ",
+    ///     )
+    /// )
+    /// ```
+    pub const fn vertical_char(mut self, c: char) -> Self {
+        self.borders.vertical = Some(c);
+        self
+    }
+
     /// Removes vertical split lines.
     pub const fn remove_vertical(mut self) -> Style<T, B, L, R, H, (), HN, VN>
     where
@@ -1608,4 +1815,54 @@ const fn linearr_unset<const N: usize>(lines: [(usize, Line); N], set: Line) ->
     buf
 }
 
+const fn linearr_weight<const N: usize>(
+    lines: [(usize, Line); N],
+    weight: Weight,
+) -> [(usize, Line); N] {
+    let mut buf = [(0, Line::empty()); N];
+    let mut i = 0;
+    while i < N {
+        let (index, line) = lines[i];
+
+        buf[i].0 = index;
+        buf[i].1 = Line::new(
+            char_weight(line.main, weight),
+            char_weight(line.intersection, weight),
+            char_weight(line.connector1, weight),
+            char_weight(line.connector2, weight),
+        );
+
+        i += 1;
+    }
+
+    buf
+}
+
+const fn borders_weight(borders: Borders<char>, weight: Weight) -> Borders<char> {
+    Borders {
+        top: char_weight(borders.top, weight),
+        top_left: char_weight(borders.top_left, weight),
+        top_right: char_weight(borders.top_right, weight),
+        top_intersection: char_weight(borders.top_intersection, weight),
+        bottom: char_weight(borders.bottom, weight),
+        bottom_left: char_weight(borders.bottom_left, weight),
+        bottom_right: char_weight(borders.bottom_right, weight),
+        bottom_intersection: char_weight(borders.bottom_intersection, weight),
+        horizontal: char_weight(borders.horizontal, weight),
+        vertical: char_weight(borders.vertical, weight),
+        intersection: char_weight(borders.intersection, weight),
+        left: char_weight(borders.left, weight),
+        left_intersection: char_weight(borders.left_intersection, weight),
+        right: char_weight(borders.right, weight),
+        right_intersection: char_weight(borders.right_intersection, weight),
+    }
+}
+
+const fn char_weight(c: Option<char>, weight: Weight) -> Option<char> {
+    match c {
+        Some(c) => Some(weight.convert(c)),
+        None => None,
+    }
+}
+
 // todo: MACROS to reduce duplication of the same code for Varray and Harray
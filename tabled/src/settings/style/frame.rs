@@ -0,0 +1,141 @@
+//! This module contains a [`Frame`] setting, which sets an outer border of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::{
+        config::{Border as GridBorder, Borders, ColoredConfig},
+        records::{ExactRecords, Records},
+    },
+    settings::{style::Border, TableOption},
+};
+
+/// Frame sets an outer border of a [`Table`], the 4 edges and corners.
+///
+/// Inner lines are left untouched,
+/// so it can be combined with any [`Style`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{style::{Border, Frame}, Style}};
+///
+/// let data = [["Hello", "World"], ["123", "456"]];
+///
+/// let table = Table::new(data)
+///     .with(Style::blank())
+///     .with(Frame::new(Border::filled('*')))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "*****************\n",
+///         "* 0       1     *\n",
+///         "* Hello   World *\n",
+///         "* 123     456   *\n",
+///         "*****************",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Style`]: crate::settings::Style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Frame {
+    border: GridBorder<char>,
+}
+
+impl Frame {
+    /// Creates a new frame from a [`Border`].
+    ///
+    /// Not set sides of a border are left as they are.
+    pub const fn new<T, B, L, R>(border: Border<T, B, L, R>) -> Self {
+        Self {
+            border: border.into_inner(),
+        }
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Frame
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        let border = self.border;
+
+        let mut borders = *cfg.get_borders();
+        set_frame(&mut borders, &border);
+        cfg.set_borders(borders);
+
+        for (row, mut line) in cfg.get_horizontal_lines() {
+            if row == 0 {
+                set_if(&mut line.main, border.top);
+                set_if(&mut line.intersection, border.top);
+                set_if(&mut line.left, border.left_top_corner);
+                set_if(&mut line.right, border.right_top_corner);
+            } else if row == count_rows {
+                set_if(&mut line.main, border.bottom);
+                set_if(&mut line.intersection, border.bottom);
+                set_if(&mut line.left, border.left_bottom_corner);
+                set_if(&mut line.right, border.right_bottom_corner);
+            } else {
+                set_if(&mut line.left, border.left);
+                set_if(&mut line.right, border.right);
+            }
+
+            cfg.insert_horizontal_line(row, line);
+        }
+
+        for (col, mut line) in cfg.get_vertical_lines() {
+            if col == 0 {
+                set_if(&mut line.main, border.left);
+                set_if(&mut line.intersection, border.left);
+                set_if(&mut line.top, border.left_top_corner);
+                set_if(&mut line.bottom, border.left_bottom_corner);
+            } else if col == count_columns {
+                set_if(&mut line.main, border.right);
+                set_if(&mut line.intersection, border.right);
+                set_if(&mut line.top, border.right_top_corner);
+                set_if(&mut line.bottom, border.right_bottom_corner);
+            } else {
+                set_if(&mut line.top, border.top);
+                set_if(&mut line.bottom, border.bottom);
+            }
+
+            cfg.insert_vertical_line(col, line);
+        }
+    }
+}
+
+fn set_frame(borders: &mut Borders<char>, border: &GridBorder<char>) {
+    let has_vertical = borders.vertical.is_some();
+    let has_horizontal = borders.horizontal.is_some();
+
+    set_if(&mut borders.top, border.top);
+    set_if(&mut borders.bottom, border.bottom);
+    set_if(&mut borders.left, border.left);
+    set_if(&mut borders.right, border.right);
+    set_if(&mut borders.top_left, border.left_top_corner);
+    set_if(&mut borders.top_right, border.right_top_corner);
+    set_if(&mut borders.bottom_left, border.left_bottom_corner);
+    set_if(&mut borders.bottom_right, border.right_bottom_corner);
+
+    if has_vertical {
+        set_if(&mut borders.top_intersection, border.top);
+        set_if(&mut borders.bottom_intersection, border.bottom);
+    }
+
+    if has_horizontal {
+        set_if(&mut borders.left_intersection, border.left);
+        set_if(&mut borders.right_intersection, border.right);
+    }
+}
+
+fn set_if(value: &mut Option<char>, c: Option<char>) {
+    if c.is_some() {
+        *value = c;
+    }
+}
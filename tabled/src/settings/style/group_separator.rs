@@ -0,0 +1,65 @@
+//! This module contains [`GroupSeparator`] structure, which draws a distinct vertical line
+//! between groups of columns.
+
+use crate::{grid::config::ColoredConfig, settings::TableOption};
+
+/// [`GroupSeparator`] sets a char of a vertical line which goes after a given column,
+/// so logical groups of columns can be told apart, e.g. by a double line.
+///
+/// Intersections and a frame of the line are kept as they are in a style.
+/// The line is drawn even if a style has no vertical lines,
+/// and its width is taken into account like a width of any other line.
+///
+/// Can be created by [`Style::group_separator`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::Style};
+///
+/// let data = [("in", "out", 1)];
+///
+/// let table = Table::new(data)
+///     .with(Style::psql().group_separator(0, '‖'))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " &str ‖ &str | i32 \n",
+///         "------+------+-----\n",
+///         " in   ‖ out  | 1   ",
+///     )
+/// );
+/// ```
+///
+/// [`Style::group_separator`]: crate::settings::Style::group_separator
+#[derive(Debug, Clone)]
+pub struct GroupSeparator<S> {
+    style: S,
+    column: usize,
+    c: char,
+}
+
+impl<S> GroupSeparator<S> {
+    /// Creates a [`GroupSeparator`] which applies a given style
+    /// and then sets the line after a given column to `c`.
+    pub const fn new(style: S, column: usize, c: char) -> Self {
+        Self { style, column, c }
+    }
+}
+
+impl<S, R, D> TableOption<R, D, ColoredConfig> for GroupSeparator<S>
+where
+    S: TableOption<R, D, ColoredConfig>,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        self.style.change(records, cfg, dims);
+
+        let index = self.column + 1;
+        let mut line = cfg.get_vertical_line(index).copied().unwrap_or_default();
+        line.main = Some(self.c);
+
+        cfg.insert_vertical_line(index, line);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    grid::records::{ExactRecords, Records},
+    settings::CellOption,
+};
+
+/// [`Intersection`] sets a char to a specific crossing of a horizontal and a vertical line.
+///
+/// It's targeting a top left corner of a cell,
+/// so the crossing `(row, col)` is changed by [`Modify`] of a cell `(row, col)`.
+///
+/// # Example
+///
+/// ```rust
+/// use tabled::{Table, settings::{style::{Intersection, Style}, Modify}};
+///
+/// let data = [["a", "b"], ["c", "d"]];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::modern())
+///     .with(Modify::new((1, 1)).with(Intersection::new('╬')));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "┌───┬───┐\n",
+///         "│ 0 │ 1 │\n",
+///         "├───╬───┤\n",
+///         "│ a │ b │\n",
+///         "├───┼───┤\n",
+///         "│ c │ d │\n",
+///         "└───┴───┘",
+///     ),
+/// );
+/// ```
+///
+/// [`Modify`]: crate::settings::Modify
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Intersection {
+    c: char,
+}
+
+impl Intersection {
+    /// Creates an [`Intersection`] which overrides a top left crossing of a cell.
+    pub fn new(c: char) -> Self {
+        Self { c }
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for Intersection
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_columns) {
+            cfg.set_intersection(pos, self.c);
+        }
+    }
+}
@@ -101,27 +101,49 @@ mod border_color;
 #[cfg(feature = "std")]
 mod border_text;
 #[cfg(feature = "std")]
+mod frame;
+#[cfg(feature = "std")]
+mod group_separator;
+#[cfg(feature = "std")]
+mod intersection;
+#[cfg(feature = "std")]
 mod line_char;
 #[cfg(feature = "std")]
 mod offset;
 #[cfg(feature = "std")]
 mod raw_style;
 #[cfg(feature = "std")]
+mod space;
+#[cfg(feature = "std")]
 mod span_border_correction;
+#[cfg(feature = "std")]
+mod span_intersection;
 
 mod builder;
 mod horizontal_line;
 mod line;
 mod vertical_line;
+mod weight;
 
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
-    border::Border, border_color::BorderColor, border_text::LineText, line_char::LineChar,
-    offset::Offset, raw_style::RawStyle, span_border_correction::BorderSpanCorrection,
+    border::{Border, EmptyBorder},
+    border_color::BorderColor,
+    border_text::LineText,
+    frame::Frame,
+    group_separator::GroupSeparator,
+    intersection::Intersection,
+    line_char::LineChar,
+    offset::Offset,
+    raw_style::RawStyle,
+    space::SpaceStyle,
+    span_border_correction::BorderSpanCorrection,
+    span_intersection::SpanIntersection,
 };
 
 pub use builder::{On, Style};
 pub use horizontal_line::HorizontalLine;
 pub use line::Line;
 pub use vertical_line::VerticalLine;
+pub use weight::Weight;
@@ -23,6 +23,7 @@ pub struct RawStyle {
     colors: Borders<AnsiColor<'static>>,
     horizontals: HashMap<usize, Line>,
     verticals: HashMap<usize, Line>,
+    vertical_suffix: String,
 }
 
 impl RawStyle {
@@ -140,6 +141,40 @@ impl RawStyle {
         self
     }
 
+    /// Set a vertical separator which may consist of multiple characters, like `║│`.
+    ///
+    /// The first character is used as a vertical character,
+    /// while the rest is printed right after it on inner vertical lines.
+    /// Horizontal lines are extended by their horizontal character to keep the table aligned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::style::{RawStyle, Style}};
+    ///
+    /// let mut style = RawStyle::from(Style::ascii());
+    /// style.set_vertical_str("||");
+    ///
+    /// let table = Table::new([(1, 2)]).with(style).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+-----+------+\n",
+    ///         "| i32 || i32 |\n",
+    ///         "+-----+------+\n",
+    ///         "| 1   || 2   |\n",
+    ///         "+-----+------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn set_vertical_str(&mut self, s: &str) -> &mut Self {
+        let mut chars = s.chars();
+        self.borders.vertical = chars.next();
+        self.vertical_suffix = chars.collect();
+        self
+    }
+
     /// Set a bottom intersection color.
     pub fn set_color_vertical(&mut self, color: Color) -> &mut Self {
         self.colors.vertical = Some(color.into());
@@ -368,6 +403,89 @@ impl RawStyle {
     pub fn get_borders(&self) -> Borders<char> {
         self.borders
     }
+
+    /// Overlays a given style on top of this one.
+    ///
+    /// Everything which is set in the overlay wins,
+    /// while everything which is not set in it is kept from this style.
+    /// The same goes for colors and for custom horizontal and vertical lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{Table, settings::style::{RawStyle, Style}};
+    ///
+    /// let mut frame = RawStyle::from(Style::rounded());
+    /// frame.set_horizontal(None).set_vertical(None).set_intersection(None);
+    /// frame.set_horizontals(Default::default());
+    ///
+    /// let mut style = RawStyle::from(Style::ascii());
+    /// style.merge(&frame);
+    ///
+    /// let table = Table::new([(1, 2)]).with(style).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "╭─────┬─────╮\n",
+    ///         "│ i32 | i32 │\n",
+    ///         "+-----+-----+\n",
+    ///         "│ 1   | 2   │\n",
+    ///         "╰─────┴─────╯",
+    ///     )
+    /// );
+    /// ```
+    pub fn merge(&mut self, overlay: &RawStyle) -> &mut Self {
+        self.borders = merge_borders(self.borders, &overlay.borders);
+        self.colors = merge_borders(self.colors.clone(), &overlay.colors);
+
+        merge_lines(&mut self.horizontals, &overlay.horizontals);
+        merge_lines(&mut self.verticals, &overlay.verticals);
+
+        if !overlay.vertical_suffix.is_empty() {
+            self.vertical_suffix = overlay.vertical_suffix.clone();
+        }
+
+        self
+    }
+}
+
+fn merge_borders<T: Clone>(base: Borders<T>, overlay: &Borders<T>) -> Borders<T> {
+    let pick = |base: Option<T>, overlay: &Option<T>| overlay.clone().or(base);
+
+    Borders {
+        top: pick(base.top, &overlay.top),
+        top_left: pick(base.top_left, &overlay.top_left),
+        top_right: pick(base.top_right, &overlay.top_right),
+        top_intersection: pick(base.top_intersection, &overlay.top_intersection),
+        bottom: pick(base.bottom, &overlay.bottom),
+        bottom_left: pick(base.bottom_left, &overlay.bottom_left),
+        bottom_right: pick(base.bottom_right, &overlay.bottom_right),
+        bottom_intersection: pick(base.bottom_intersection, &overlay.bottom_intersection),
+        horizontal: pick(base.horizontal, &overlay.horizontal),
+        vertical: pick(base.vertical, &overlay.vertical),
+        intersection: pick(base.intersection, &overlay.intersection),
+        left: pick(base.left, &overlay.left),
+        left_intersection: pick(base.left_intersection, &overlay.left_intersection),
+        right: pick(base.right, &overlay.right),
+        right_intersection: pick(base.right_intersection, &overlay.right_intersection),
+    }
+}
+
+fn merge_lines(base: &mut HashMap<usize, Line>, overlay: &HashMap<usize, Line>) {
+    for (&i, line) in overlay {
+        let merged = match base.get(&i) {
+            Some(b) => Line::new(
+                line.main.or(b.main),
+                line.intersection.or(b.intersection),
+                line.connector1.or(b.connector1),
+                line.connector2.or(b.connector2),
+            ),
+            None => *line,
+        };
+
+        let _ = base.insert(i, merged);
+    }
 }
 
 impl From<Borders<char>> for RawStyle {
@@ -377,6 +495,7 @@ impl From<Borders<char>> for RawStyle {
             horizontals: HashMap::new(),
             verticals: HashMap::new(),
             colors: Borders::default(),
+            vertical_suffix: String::new(),
         }
     }
 }
@@ -400,6 +519,8 @@ where
         if !self.colors.is_empty() {
             cfg.set_borders_color(self.colors.clone());
         }
+
+        cfg.set_vertical_suffix(self.vertical_suffix);
     }
 }
 
@@ -433,6 +554,7 @@ where
             horizontals,
             verticals,
             colors: Borders::default(),
+            vertical_suffix: String::new(),
         }
     }
 }
@@ -452,12 +574,14 @@ impl From<ColoredConfig> for RawStyle {
 
         let borders = *cfg.get_borders();
         let colors = cfg.get_color_borders().clone();
+        let vertical_suffix = cfg.get_vertical_suffix().to_owned();
 
         Self {
             borders,
             horizontals,
             verticals,
             colors,
+            vertical_suffix,
         }
     }
 }
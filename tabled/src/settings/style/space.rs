@@ -0,0 +1,69 @@
+//! This module contains a [`SpaceStyle`] setting, a compact borderless style.
+
+use crate::{
+    grid::config::ColoredConfig,
+    settings::{
+        style::{HorizontalLine, Style},
+        Padding, TableOption,
+    },
+};
+
+/// A borderless style where columns are separated by a single space.
+///
+/// In comparison to [`Style::blank`] it also removes a padding of cells,
+/// so there's exactly one space between columns and no indent on the edges.
+///
+/// Can be created by [`Style::space`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::Style};
+///
+/// let data = [("Apple", 3), ("Orange", 12)];
+///
+/// let table = Table::new(data).with(Style::space()).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "&str   i32\n",
+///         "Apple  3  \n",
+///         "Orange 12 ",
+///     )
+/// );
+/// ```
+///
+/// [`Style::blank`]: crate::settings::Style::blank
+/// [`Style::space`]: crate::settings::Style::space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpaceStyle {
+    header_gap: bool,
+}
+
+impl SpaceStyle {
+    /// Creates a new [`SpaceStyle`].
+    pub const fn new() -> Self {
+        Self { header_gap: false }
+    }
+
+    /// Sets whether a header is separated from the rest of the table by a blank line.
+    pub const fn header_gap(mut self, on: bool) -> Self {
+        self.header_gap = on;
+        self
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for SpaceStyle {
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        if self.header_gap {
+            Style::blank()
+                .horizontals([(1, HorizontalLine::new(' ').intersection(' '))])
+                .change(records, cfg, dims);
+        } else {
+            Style::blank().change(records, cfg, dims);
+        }
+
+        Padding::zero().change(records, cfg, dims);
+    }
+}
@@ -88,6 +88,10 @@ where
 
 fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
     for ((row, c), span) in cfg.get_column_spans() {
+        // a cell may be spanned in both directions,
+        // so its bottom side is located at the last spanned row.
+        let last_row = row + cfg.get_row_span((row, c)).unwrap_or(1) - 1;
+
         for col in c..c + span {
             if col == 0 {
                 continue;
@@ -95,7 +99,7 @@ fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
 
             let is_first = col == c;
             let has_up = row > 0 && has_left(cfg, (row - 1, col), shape);
-            let has_down = row + 1 < shape.0 && has_left(cfg, (row + 1, col), shape);
+            let has_down = last_row + 1 < shape.0 && has_left(cfg, (last_row + 1, col), shape);
 
             let borders = cfg.get_borders();
 
@@ -114,6 +118,11 @@ fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
                 }
             }
 
+            cfg.set_border((row, col), border);
+
+            let mut border = cfg.get_border((last_row, col), shape);
+            let borders = cfg.get_borders();
+
             let has_bottom_border = border.left_bottom_corner.is_some() && border.bottom.is_some();
             if has_bottom_border {
                 if has_down && is_first {
@@ -127,11 +136,15 @@ fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
                 }
             }
 
-            cfg.set_border((row, col), border);
+            cfg.set_border((last_row, col), border);
         }
     }
 
     for ((r, col), span) in cfg.get_row_spans() {
+        // a cell may be spanned in both directions,
+        // so its right side is located at the last spanned column.
+        let last_col = col + cfg.get_column_span((r, col)).unwrap_or(1) - 1;
+
         for row in r + 1..r + span {
             let mut border = cfg.get_border((row, col), shape);
             let borders = cfg.get_borders();
@@ -146,9 +159,14 @@ fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
                 }
             }
 
+            cfg.set_border((row, col), border);
+
+            let mut border = cfg.get_border((row, last_col), shape);
+            let borders = cfg.get_borders();
+
             let has_right_border = border.right_top_corner.is_some();
             if has_right_border {
-                let has_right = col + 1 < shape.1 && has_top(cfg, (row, col + 1), shape);
+                let has_right = last_col + 1 < shape.1 && has_top(cfg, (row, last_col + 1), shape);
                 if has_right {
                     border.right_top_corner = borders.left_intersection;
                 } else {
@@ -156,7 +174,7 @@ fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
                 }
             }
 
-            cfg.set_border((row, col), border);
+            cfg.set_border((row, last_col), border);
         }
     }
 
@@ -184,7 +202,7 @@ fn correct_span_styles(cfg: &mut SpannedConfig, shape: (usize, usize)) {
     }
 }
 
-fn has_left(cfg: &SpannedConfig, pos: Position, shape: (usize, usize)) -> bool {
+pub(super) fn has_left(cfg: &SpannedConfig, pos: Position, shape: (usize, usize)) -> bool {
     if cfg.is_cell_covered_by_both_spans(pos) || cfg.is_cell_covered_by_column_span(pos) {
         return false;
     }
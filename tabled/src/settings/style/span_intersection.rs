@@ -0,0 +1,91 @@
+//! This module contains [`SpanIntersection`] structure, which sets a char
+//! where an edge of a spanned cell meets a separator line.
+
+use crate::{
+    grid::config::ColoredConfig,
+    grid::records::{ExactRecords, Records},
+    settings::TableOption,
+};
+
+use super::span_border_correction::has_left;
+
+/// [`SpanIntersection`] sets a char which is drawn where a horizontal edge of a column spanned cell
+/// meets a vertical line of a neighbour row.
+///
+/// By default such crossings use a general intersection char of a style.
+/// Unlike [`BorderSpanCorrection`] the char is given explicitly,
+/// so it's possible to either keep a join or to draw a plain line.
+///
+/// Be aware that the spans must be set before the option is applied.
+///
+/// Can be created by [`Style::span_intersection`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{Panel, Style}};
+///
+/// let data = [[1, 2]];
+///
+/// let table = Table::new(data)
+///     .with(Panel::header("Numbers"))
+///     .with(Style::modern().span_intersection('─'))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "┌────┬────┐\n",
+///         "│ Numbers │\n",
+///         "├─────────┤\n",
+///         "│ 0  │ 1  │\n",
+///         "├────┼────┤\n",
+///         "│ 1  │ 2  │\n",
+///         "└────┴────┘",
+///     )
+/// );
+/// ```
+///
+/// [`BorderSpanCorrection`]: crate::settings::style::BorderSpanCorrection
+/// [`Style::span_intersection`]: crate::settings::Style::span_intersection
+#[derive(Debug, Clone)]
+pub struct SpanIntersection<S> {
+    style: S,
+    c: char,
+}
+
+impl<S> SpanIntersection<S> {
+    /// Creates a [`SpanIntersection`] which applies a given style and then sets the crossings to `c`.
+    pub const fn new(style: S, c: char) -> Self {
+        Self { style, c }
+    }
+}
+
+impl<S, R, D> TableOption<R, D, ColoredConfig> for SpanIntersection<S>
+where
+    S: TableOption<R, D, ColoredConfig>,
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, dims: &mut D) {
+        self.style.change(records, cfg, dims);
+
+        let shape = (records.count_rows(), records.count_columns());
+
+        for ((row, c), span) in cfg.get_column_spans() {
+            let last_row = row + cfg.get_row_span((row, c)).unwrap_or(1) - 1;
+
+            for col in c + 1..c + span {
+                let has_up = row > 0 && has_left(cfg, (row - 1, col), shape);
+                if has_up && cfg.has_horizontal(row, shape.0) {
+                    cfg.set_intersection((row, col), self.c);
+                }
+
+                let next_row = last_row + 1;
+                let has_down = next_row < shape.0 && has_left(cfg, (next_row, col), shape);
+                if has_down && cfg.has_horizontal(next_row, shape.0) {
+                    cfg.set_intersection((next_row, col), self.c);
+                }
+            }
+        }
+    }
+}
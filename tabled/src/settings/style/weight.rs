@@ -0,0 +1,56 @@
+/// A weight of box-drawing characters used by [`Style::weight`].
+///
+/// [`Style::weight`]: crate::settings::Style::weight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Weight {
+    /// Light lines, e.g. `─`, `│`, `┌`.
+    Light,
+    /// Heavy lines, e.g. `━`, `┃`, `┏`.
+    Heavy,
+    /// Double lines, e.g. `═`, `║`, `╔`.
+    Double,
+}
+
+impl Weight {
+    /// Converts a box-drawing character into a character of the weight.
+    ///
+    /// Characters which are not box-drawing ones are returned as they are.
+    /// Rounded corners are kept as they are for [`Weight::Light`].
+    pub const fn convert(self, c: char) -> char {
+        const CHARS: [[char; 3]; 11] = [
+            ['─', '━', '═'],
+            ['│', '┃', '║'],
+            ['┌', '┏', '╔'],
+            ['┐', '┓', '╗'],
+            ['└', '┗', '╚'],
+            ['┘', '┛', '╝'],
+            ['├', '┣', '╠'],
+            ['┤', '┫', '╣'],
+            ['┬', '┳', '╦'],
+            ['┴', '┻', '╩'],
+            ['┼', '╋', '╬'],
+        ];
+
+        let index = match c {
+            '─' | '━' | '═' => 0,
+            '│' | '┃' | '║' => 1,
+            '┌' | '┏' | '╔' | '╭' => 2,
+            '┐' | '┓' | '╗' | '╮' => 3,
+            '└' | '┗' | '╚' | '╰' => 4,
+            '┘' | '┛' | '╝' | '╯' => 5,
+            '├' | '┣' | '╠' => 6,
+            '┤' | '┫' | '╣' => 7,
+            '┬' | '┳' | '╦' => 8,
+            '┴' | '┻' | '╩' => 9,
+            '┼' | '╋' | '╬' => 10,
+            _ => return c,
+        };
+
+        let is_rounded = matches!(c, '╭' | '╮' | '╰' | '╯');
+        if is_rounded && matches!(self, Weight::Light) {
+            return c;
+        }
+
+        CHARS[index][self as usize]
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
         config::ColoredConfig,
         records::{ExactRecords, Records},
     },
-    settings::{object::Object, Color, TableOption},
+    settings::{object::Object, CellOption, Color, TableOption},
 };
 
 /// [`Colorization`] sets a color for the whole table data (so it's not include the borders).
@@ -38,7 +38,11 @@ use crate::{
 /// println!("{table}");
 /// ```
 ///
+/// It can also be used as a [`CellOption`] via [`Modify`],
+/// in which case the pattern is applied only to the given cells.
+///
 /// [`RawStyle`]: crate::settings::style::RawStyle
+/// [`Modify`]: crate::settings::Modify
 /// [`BorderColor`]: crate::settings::style::BorderColor
 #[derive(Debug, Clone)]
 pub struct Colorization {
@@ -264,6 +268,39 @@ where
     }
 }
 
+impl<R> CellOption<R, ColoredConfig> for Colorization
+where
+    R: Records + ExactRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        if self.colors.is_empty() {
+            return;
+        }
+
+        let count_columns = records.count_columns();
+        let count_rows = records.count_rows();
+
+        for (row, col) in entity.iter(count_rows, count_columns) {
+            let is_valid_pos = row < count_rows && col < count_columns;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let index = match self.pattern {
+                ColorizationPattern::Column => col,
+                ColorizationPattern::Row => row,
+                ColorizationPattern::ByRow => row * count_columns + col,
+                ColorizationPattern::ByColumn => col * count_rows + row,
+                ColorizationPattern::Chess if row >= col => row - col,
+                ColorizationPattern::Chess => count_rows + col - row,
+            };
+
+            let color = &self.colors[index % self.colors.len()];
+            colorize_entity(color, Entity::Cell(row, col), cfg);
+        }
+    }
+}
+
 fn colorize_columns(colors: &[Color], count_columns: usize, cfg: &mut ColoredConfig) {
     for (col, color) in (0..count_columns).zip(colors.iter().cycle()) {
         colorize_entity(color, Entity::Column(col), cfg);
@@ -340,7 +377,7 @@ fn colorize_diogonals(
     }
 }
 
-fn colorize_entity(color: &Color, pos: Entity, cfg: &mut ColoredConfig) {
+pub(super) fn colorize_entity(color: &Color, pos: Entity, cfg: &mut ColoredConfig) {
     let ansi_color = AnsiColor::from(color.clone());
     let _ = cfg.set_color(pos, ansi_color.clone());
     cfg.set_justification_color(pos, Some(ansi_color.clone()));
@@ -8,7 +8,7 @@ use crate::{
             vec_records::{CellInfo, VecRecords},
             ExactRecords, PeekableRecords, Records, Resizable,
         },
-        util::string::string_width,
+        util::string::{get_char_width, string_width},
     },
     settings::{
         style::{LineText, Offset},
@@ -329,7 +329,7 @@ fn get_indent(text: &str, align: AlignmentHorizontal, available: usize) -> usize
 
 fn get_vertical_width(cfg: &mut ColoredConfig, pos: Position, count_columns: usize) -> usize {
     cfg.get_vertical(pos, count_columns)
-        .and_then(unicode_width::UnicodeWidthChar::width)
+        .map(get_char_width)
         .unwrap_or(0)
 }
 
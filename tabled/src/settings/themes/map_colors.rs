@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{CellOption, Color, TableOption},
+};
+
+use super::colorization::colorize_entity;
+
+/// [`MapColors`] colorizes cells which content matches a given value.
+///
+/// A content is trimmed before it's matched.
+/// Cells which don't match any value are left untouched.
+///
+/// Being used as a [`TableOption`] it skips the first row (a header),
+/// which can be changed via [`MapColors::header`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{themes::MapColors, Color}};
+///
+/// let data = [("db", "OK"), ("cache", "FAIL")];
+///
+/// let colors = MapColors::new([("OK", Color::BG_GREEN), ("FAIL", Color::BG_RED)]);
+///
+/// let mut table = Table::new(data);
+/// table.with(colors);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-------+------+\n",
+///         "| &str  | &str |\n",
+///         "+-------+------+\n",
+///         "| db    |\u{1b}[42m \u{1b}[49m\u{1b}[42mOK\u{1b}[49m\u{1b}[42m  \u{1b}[49m\u{1b}[42m \u{1b}[49m|\n",
+///         "+-------+------+\n",
+///         "| cache |\u{1b}[41m \u{1b}[49m\u{1b}[41mFAIL\u{1b}[49m\u{1b}[41m \u{1b}[49m|\n",
+///         "+-------+------+",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct MapColors {
+    colors: HashMap<String, Color>,
+    header: bool,
+}
+
+impl MapColors {
+    /// Creates a new [`MapColors`] from a list of (value, color) pairs.
+    pub fn new<I, K>(colors: I) -> Self
+    where
+        I: IntoIterator<Item = (K, Color)>,
+        K: Into<String>,
+    {
+        let colors = colors
+            .into_iter()
+            .map(|(value, color)| (value.into(), color))
+            .collect();
+
+        Self {
+            colors,
+            header: false,
+        }
+    }
+
+    /// Sets whether the first row (a header) is colorized too,
+    /// when [`MapColors`] is used as a [`TableOption`].
+    ///
+    /// By default it's skipped.
+    pub fn header(mut self, on: bool) -> Self {
+        self.header = on;
+        self
+    }
+}
+
+impl From<HashMap<String, Color>> for MapColors {
+    fn from(colors: HashMap<String, Color>) -> Self {
+        Self::new(colors)
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for MapColors
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self.header {
+            self.colorize(records, cfg, Entity::Global);
+            return;
+        }
+
+        for row in 1..records.count_rows() {
+            self.colorize(records, cfg, Entity::Row(row));
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for MapColors
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        self.colorize(records, cfg, entity);
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+impl MapColors {
+    fn colorize<R>(&self, records: &R, cfg: &mut ColoredConfig, entity: Entity)
+    where
+        R: Records + ExactRecords + PeekableRecords,
+    {
+        if self.colors.is_empty() {
+            return;
+        }
+
+        let count_rows = records.count_rows();
+        let count_cols = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_cols) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_cols;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let text = records.get_text(pos).trim();
+            if let Some(color) = self.colors.get(text) {
+                colorize_entity(color, Entity::Cell(pos.0, pos.1), cfg);
+            }
+        }
+    }
+}
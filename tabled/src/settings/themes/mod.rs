@@ -4,6 +4,14 @@
 
 mod colorization;
 mod column_names;
+mod map_colors;
+mod rules;
+mod style_rows_by;
+mod theme;
 
 pub use colorization::{Colorization, ExactColorization};
 pub use column_names::ColumnNames;
+pub use map_colors::MapColors;
+pub use rules::{RuleCondition, Rules};
+pub use style_rows_by::StyleRowsBy;
+pub use theme::Theme;
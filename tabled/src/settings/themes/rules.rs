@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity, Position},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{Color, TableOption},
+};
+
+use super::colorization::colorize_entity;
+
+type Predicate = Box<dyn Fn(Position, &str) -> bool>;
+
+/// [`Rules`] is a list of conditional colorings which are applied to each cell of a table.
+///
+/// A rule is declared by [`Rules::when`] followed by [`RuleCondition::then`].
+/// A condition gets a position of a cell and its content.
+///
+/// Rules are checked in the order they were declared.
+/// By default the first matched rule wins and the rest are skipped.
+/// With [`Rules::all_matches`] colors of every matched rule are combined,
+/// in the order of declaration.
+///
+/// Cells which don't match any rule are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{themes::Rules, Color}};
+///
+/// let data = [("db", 3), ("cache", 120)];
+///
+/// let rules = Rules::new()
+///     .when(|(row, _), _| row == 0)
+///     .then(Color::BOLD)
+///     .when(|_, text| text.parse::<usize>().map_or(false, |n| n > 100))
+///     .then(Color::FG_RED);
+///
+/// let mut table = Table::new(data);
+/// table.with(rules);
+///
+/// println!("{table}");
+/// ```
+#[derive(Default)]
+pub struct Rules {
+    rules: Vec<(Predicate, Color)>,
+    all_matches: bool,
+}
+
+impl Rules {
+    /// Creates an empty list of rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new rule with a given condition.
+    ///
+    /// The rule is added once a color is set by [`RuleCondition::then`].
+    pub fn when<F>(self, f: F) -> RuleCondition
+    where
+        F: Fn(Position, &str) -> bool + 'static,
+    {
+        RuleCondition {
+            rules: self,
+            predicate: Box::new(f),
+        }
+    }
+
+    /// Makes every matched rule to be applied, instead of only the first one.
+    ///
+    /// The colors are combined in the order of declaration.
+    pub fn all_matches(mut self) -> Self {
+        self.all_matches = true;
+        self
+    }
+
+    /// Makes only the first matched rule to be applied.
+    ///
+    /// It's a default behaviour.
+    pub fn first_match(mut self) -> Self {
+        self.all_matches = false;
+        self
+    }
+
+    fn find_color(&self, pos: Position, text: &str) -> Option<Color> {
+        let mut matched = self
+            .rules
+            .iter()
+            .filter(|(predicate, _)| predicate(pos, text))
+            .map(|(_, color)| color.clone());
+
+        if self.all_matches {
+            matched.reduce(|acc, color| acc | color)
+        } else {
+            matched.next()
+        }
+    }
+}
+
+impl fmt::Debug for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rules")
+            .field("rules", &self.rules.len())
+            .field("all_matches", &self.all_matches)
+            .finish()
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Rules
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        if self.rules.is_empty() {
+            return;
+        }
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                let text = records.get_text((row, col));
+                if let Some(color) = self.find_color((row, col), text) {
+                    colorize_entity(&color, Entity::Cell(row, col), cfg);
+                }
+            }
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
+
+/// A rule of [`Rules`] which is waiting for a color.
+///
+/// Can be created by [`Rules::when`].
+pub struct RuleCondition {
+    rules: Rules,
+    predicate: Predicate,
+}
+
+impl RuleCondition {
+    /// Sets a color which is used when the condition is met.
+    pub fn then(mut self, color: Color) -> Rules {
+        self.rules.rules.push((self.predicate, color));
+        self.rules
+    }
+}
+
+impl fmt::Debug for RuleCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuleCondition")
+            .field("rules", &self.rules)
+            .finish()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        records::{ExactRecords, PeekableRecords, Records},
+    },
+    settings::{Color, TableOption},
+};
+
+use super::colorization::colorize_entity;
+
+/// [`StyleRowsBy`] colorizes a whole row depending on its content.
+///
+/// A function is called for each row with a list of its cells,
+/// and a returned color is set to every cell of the row.
+/// Rows for which `None` is returned are left untouched.
+///
+/// The first row (a header) is skipped, which can be changed via [`StyleRowsBy::header`].
+///
+/// In comparison to per cell settings it guarantees that a row is colored consistently.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{themes::StyleRowsBy, Color}};
+///
+/// let data = [("db", "UP"), ("cache", "DOWN")];
+///
+/// let mut table = Table::new(data);
+/// table.with(StyleRowsBy::new(|row: &[&str]| {
+///     (row[1] == "DOWN").then(|| Color::FG_RED)
+/// }));
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         "+-------+------+\n",
+///         "| &str  | &str |\n",
+///         "+-------+------+\n",
+///         "| db    | UP   |\n",
+///         "+-------+------+\n",
+///         "|\u{1b}[31m \u{1b}[39m\u{1b}[31mcache\u{1b}[39m\u{1b}[31m \u{1b}[39m|\u{1b}[31m \u{1b}[39m\u{1b}[31mDOWN\u{1b}[39m\u{1b}[31m \u{1b}[39m|\n",
+///         "+-------+------+",
+///     ),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct StyleRowsBy<F> {
+    f: F,
+    header: bool,
+}
+
+impl<F> StyleRowsBy<F> {
+    /// Creates a new [`StyleRowsBy`] from a function which chooses a color of a row.
+    pub fn new(f: F) -> Self
+    where
+        F: FnMut(&[&str]) -> Option<Color>,
+    {
+        Self { f, header: false }
+    }
+
+    /// Sets whether the first row (a header) is checked and colorized too.
+    ///
+    /// By default it's skipped.
+    pub fn header(mut self, on: bool) -> Self {
+        self.header = on;
+        self
+    }
+}
+
+impl<F, R, D> TableOption<R, D, ColoredConfig> for StyleRowsBy<F>
+where
+    F: FnMut(&[&str]) -> Option<Color>,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    fn change(mut self, records: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        let start = if self.header { 0 } else { 1 };
+
+        for row in start..count_rows {
+            let cells = (0..count_columns)
+                .map(|col| records.get_text((row, col)))
+                .collect::<Vec<_>>();
+
+            let color = match (self.f)(&cells) {
+                Some(color) => color,
+                None => continue,
+            };
+
+            for col in 0..count_columns {
+                colorize_entity(&color, Entity::Cell(row, col), cfg);
+            }
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        None
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        dimension::{dimension_reastimate, CompleteDimensionVecRecords},
+        records::vec_records::{CellInfo, VecRecords},
+    },
+    settings::{
+        object::{Rows, Segment},
+        style::HorizontalLine,
+        themes::Colorization,
+        Alignment, Color, Modify, Padding, Style, TableOption,
+    },
+};
+
+/// Theme is a reusable list of [`TableOption`]s.
+///
+/// Options are applied in the order they were added,
+/// so a single theme can be used to keep a look of many tables consistent.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{
+///     Table,
+///     settings::{object::Columns, themes::Theme, Alignment, Modify, Style},
+/// };
+///
+/// let theme = Theme::new()
+///     .with(Style::psql())
+///     .with(Modify::new(Columns::single(1)).with(Alignment::right()));
+///
+/// let table1 = Table::new([("Apple", 3)]).with(theme.clone()).to_string();
+/// let table2 = Table::new([("Orange", 12)]).with(theme).to_string();
+///
+/// assert_eq!(
+///     table1,
+///     concat!(
+///         " &str  | i32 \n",
+///         "-------+-----\n",
+///         " Apple |   3 ",
+///     )
+/// );
+/// assert_eq!(
+///     table2,
+///     concat!(
+///         " &str   | i32 \n",
+///         "--------+-----\n",
+///         " Orange |  12 ",
+///     )
+/// );
+/// ```
+#[derive(Default)]
+pub struct Theme {
+    options: Vec<Box<dyn ThemeOption>>,
+}
+
+impl Theme {
+    /// Creates an empty [`Theme`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an option to a [`Theme`].
+    pub fn with<O>(mut self, option: O) -> Self
+    where
+        O: for<'a> TableOption<
+                VecRecords<CellInfo<String>>,
+                CompleteDimensionVecRecords<'a>,
+                ColoredConfig,
+            > + Clone
+            + 'static,
+    {
+        self.options.push(Box::new(option));
+        self
+    }
+
+    /// A theme with rounded borders, a bold header and striped rows.
+    pub fn dark() -> Self {
+        Self::new()
+            .with(Style::rounded())
+            .with(Padding::new(1, 1, 0, 0))
+            .with(Modify::new(Rows::first()).with(Color::BOLD | Color::FG_BRIGHT_WHITE))
+            .with(Modify::new(Rows::new(1..)).with(Colorization::rows([
+                Color::BG_BRIGHT_BLACK,
+                Color::BG_BLACK,
+            ])))
+    }
+
+    /// A theme with no borders except a header line, and left aligned cells.
+    pub fn minimal() -> Self {
+        Self::new()
+            .with(Style::blank().horizontals([(1, HorizontalLine::new('-').intersection(' '))]))
+            .with(Padding::new(0, 2, 0, 0))
+            .with(Modify::new(Segment::all()).with(Alignment::left()))
+    }
+}
+
+impl Clone for Theme {
+    fn clone(&self) -> Self {
+        Self {
+            options: self
+                .options
+                .iter()
+                .map(|option| option.clone_box())
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Debug for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Theme")
+            .field("options", &self.options.len())
+            .finish()
+    }
+}
+
+impl<'a> TableOption<VecRecords<CellInfo<String>>, CompleteDimensionVecRecords<'a>, ColoredConfig>
+    for Theme
+{
+    fn change(
+        self,
+        records: &mut VecRecords<CellInfo<String>>,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'a>,
+    ) {
+        for option in self.options {
+            let hint = option.hint_change_boxed();
+
+            let mut option_dims = dims.from_origin();
+            option.change_boxed(records, cfg, &mut option_dims);
+
+            let (widths, heights) = option_dims.into_inner();
+            dimension_reastimate(dims, widths, heights, hint);
+        }
+    }
+
+    fn hint_change(&self) -> Option<Entity> {
+        if self.options.is_empty() {
+            return None;
+        }
+
+        Some(Entity::Global)
+    }
+}
+
+/// An object safe version of a [`TableOption`] which can be cloned.
+trait ThemeOption {
+    fn change_boxed(
+        self: Box<Self>,
+        records: &mut VecRecords<CellInfo<String>>,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    );
+
+    fn hint_change_boxed(&self) -> Option<Entity>;
+
+    fn clone_box(&self) -> Box<dyn ThemeOption>;
+}
+
+impl<O> ThemeOption for O
+where
+    O: for<'a> TableOption<
+            VecRecords<CellInfo<String>>,
+            CompleteDimensionVecRecords<'a>,
+            ColoredConfig,
+        > + Clone
+        + 'static,
+{
+    fn change_boxed(
+        self: Box<Self>,
+        records: &mut VecRecords<CellInfo<String>>,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        (*self).change(records, cfg, dims);
+    }
+
+    fn hint_change_boxed(&self) -> Option<Entity> {
+        self.hint_change()
+    }
+
+    fn clone_box(&self) -> Box<dyn ThemeOption> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,168 @@
+//! This module contains a [`Tree`] setting which renders a hierarchy in a column of a [`Table`].
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use tabled::{Table, settings::{Style, Tree}};
+//!
+//! let data = [
+//!     ["src", "dir"],
+//!     ["main.rs", "file"],
+//!     ["lib.rs", "file"],
+//!     ["Cargo.toml", "file"],
+//! ];
+//!
+//! let table = Table::new(data)
+//!     .with(Style::psql())
+//!     .with(Tree::new([0, 1, 1, 0]).offset(1))
+//!     .to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     concat!(
+//!         " 0           | 1    \n",
+//!         "-------------+------\n",
+//!         " src         | dir  \n",
+//!         " ├── main.rs | file \n",
+//!         " └── lib.rs  | file \n",
+//!         " Cargo.toml  | file ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::config::ColoredConfig,
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    grid::util::string::get_lines,
+    settings::TableOption,
+};
+
+const BRANCH: &str = "├── ";
+const LAST_BRANCH: &str = "└── ";
+const PIPE: &str = "│   ";
+const SPACE: &str = "    ";
+
+/// Tree prefixes cells of a column with box-drawing connectors
+/// to visualize a hierarchy of rows.
+///
+/// It's given a depth of each row.
+/// Rows of depth 0 are roots and are left as they are,
+/// other rows are considered children of the closest previous row with a smaller depth.
+///
+/// Multiline cells are continued with a vertical connector.
+///
+/// Notice that the connectors are aligned only when the column is aligned to the left,
+/// which is a default alignment.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tree {
+    depths: Vec<usize>,
+    column: usize,
+    offset: usize,
+}
+
+impl Tree {
+    /// Creates a new [`Tree`] from a list of depths of rows.
+    pub fn new<I>(depths: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        Self {
+            depths: depths.into_iter().collect(),
+            column: 0,
+            offset: 0,
+        }
+    }
+
+    /// Sets a column where a tree is rendered.
+    ///
+    /// By default it's a first column.
+    pub fn column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    /// Sets a row from which a list of depths is applied.
+    ///
+    /// It can be used to skip a header.
+    pub fn offset(mut self, row: usize) -> Self {
+        self.offset = row;
+        self
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for Tree
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+{
+    fn change(self, records: &mut R, _: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if self.column >= count_columns || self.offset >= count_rows {
+            return;
+        }
+
+        let count_depths = std::cmp::min(self.depths.len(), count_rows - self.offset);
+        let depths = &self.depths[..count_depths];
+
+        for (i, &depth) in depths.iter().enumerate() {
+            if depth == 0 {
+                continue;
+            }
+
+            let mut indent = String::new();
+            for level in 1..depth {
+                let connector = if has_next_sibling(depths, i, level) {
+                    PIPE
+                } else {
+                    SPACE
+                };
+
+                indent.push_str(connector);
+            }
+
+            let has_next = has_next_sibling(depths, i, depth);
+            let (branch, continuation) = if has_next {
+                (BRANCH, PIPE)
+            } else {
+                (LAST_BRANCH, SPACE)
+            };
+
+            let pos = (self.offset + i, self.column);
+            let text = records.get_text(pos);
+
+            let mut buf = String::with_capacity(text.len() + indent.len() + branch.len());
+            for (n, line) in get_lines(text).enumerate() {
+                if n > 0 {
+                    buf.push('\n');
+                }
+
+                buf.push_str(&indent);
+                buf.push_str(if n == 0 { branch } else { continuation });
+                buf.push_str(&line);
+            }
+
+            records.set(pos, buf);
+        }
+    }
+}
+
+/// Checks whether there's a following row at a given level
+/// before the current branch is closed.
+fn has_next_sibling(depths: &[usize], row: usize, level: usize) -> bool {
+    for &depth in &depths[row + 1..] {
+        if depth == level {
+            return true;
+        }
+
+        if depth < level {
+            return false;
+        }
+    }
+
+    false
+}
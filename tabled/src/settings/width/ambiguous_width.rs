@@ -0,0 +1,44 @@
+use crate::{grid::config::ColoredConfig, settings::TableOption};
+
+/// A setting of how characters of an ambiguous East Asian width are measured.
+///
+/// Such characters (e.g. `±`, `α`, `─`) are rendered as 1 or 2 columns
+/// depending on a terminal and a locale.
+/// By default they are measured as narrow ones.
+///
+/// The setting is kept in a table configuration,
+/// so it affects only the table it's applied to.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::{width::AmbiguousWidth, Width}};
+///
+/// let mut table = Table::new(["±±±±±"]);
+/// table.with(Width::ambiguous(AmbiguousWidth::AsWide));
+///
+/// assert_eq!(table.total_width(), 14);
+///
+/// table.with(Width::ambiguous(AmbiguousWidth::AsNarrow));
+///
+/// assert_eq!(table.total_width(), 9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AmbiguousWidth {
+    /// Measure ambiguous characters as 1 column wide.
+    AsNarrow,
+    /// Measure ambiguous characters as 2 columns wide.
+    AsWide,
+}
+
+impl Default for AmbiguousWidth {
+    fn default() -> Self {
+        Self::AsNarrow
+    }
+}
+
+impl<R, D> TableOption<R, D, ColoredConfig> for AmbiguousWidth {
+    fn change(self, _: &mut R, cfg: &mut ColoredConfig, _: &mut D) {
+        cfg.set_ambiguous_width_wide(self == AmbiguousWidth::AsWide);
+    }
+}
@@ -0,0 +1,206 @@
+//! This module contains [`FitWidth`] structure, used to fit a [`Table`] into a given width by truncating its widest columns.
+//!
+//! [`Table`]: crate::Table
+
+use std::borrow::Cow;
+
+use crate::{
+    grid::{
+        config::{ColoredConfig, Entity},
+        dimension::CompleteDimensionVecRecords,
+        records::{EmptyRecords, ExactRecords, PeekableRecords, Records, RecordsMut},
+        util::string::string_width_multiline_ambiguous,
+    },
+    settings::{measurement::Measurement, CellOption, TableOption, Width},
+};
+
+use super::{
+    util::{count_borders, get_table_widths, get_table_widths_with_total},
+    Truncate,
+};
+
+/// [`FitWidth`] truncates the widest columns of a [`Table`] until it fits a given width.
+///
+/// On each round the widest column is shaved by 1,
+/// but no column goes below its minimum width.
+/// A truncated content is ended by a suffix, which is `...` by default.
+///
+/// The first and the last columns can be pinned so they're never shrunk,
+/// see [`FitWidth::pin_first`] and [`FitWidth::pin_last`].
+///
+/// It's meant for single line rows, so a multiline content is truncated line by line.
+/// A spanned cell is truncated to a combined width of the columns it spans.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::Width};
+///
+/// let data = [("Hello World", "Something long here", 123)];
+///
+/// let table = Table::new(data).with(Width::fit(30)).to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         "+-----------+----------+-----+\n",
+///         "| &str      | &str     | i32 |\n",
+///         "+-----------+----------+-----+\n",
+///         "| Hello ... | Somet... | 123 |\n",
+///         "+-----------+----------+-----+",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct FitWidth<W = usize> {
+    width: W,
+    min_width: usize,
+    suffix: Cow<'static, str>,
+    pin_first: bool,
+    pin_last: bool,
+}
+
+impl<W> FitWidth<W>
+where
+    W: Measurement<Width>,
+{
+    /// Creates a new [`FitWidth`] object.
+    pub fn new(width: W) -> Self {
+        Self {
+            width,
+            min_width: 1,
+            suffix: Cow::Borrowed("..."),
+            pin_first: false,
+            pin_last: false,
+        }
+    }
+}
+
+impl<W> FitWidth<W> {
+    /// Sets a minimum width of a column content (padding is not included).
+    ///
+    /// The default value is 1.
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Sets a suffix which is used in place of a truncated content.
+    pub fn suffix<S: Into<Cow<'static, str>>>(mut self, suffix: S) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Keeps the first column at its full width, shrinking the others instead.
+    pub fn pin_first(mut self) -> Self {
+        self.pin_first = true;
+        self
+    }
+
+    /// Keeps the last column at its full width, shrinking the others instead.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Width};
+    ///
+    /// let data = [("Hello World", "Something long here", "waiting for review")];
+    ///
+    /// let table = Table::new(data).with(Width::fit(40).pin_last()).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "+--------+--------+--------------------+\n",
+    ///         "| &str   | &str   | &str               |\n",
+    ///         "+--------+--------+--------------------+\n",
+    ///         "| Hel... | Som... | waiting for review |\n",
+    ///         "+--------+--------+--------------------+",
+    ///     )
+    /// );
+    /// ```
+    pub fn pin_last(mut self) -> Self {
+        self.pin_last = true;
+        self
+    }
+
+    fn is_pinned(&self, col: usize, count_columns: usize) -> bool {
+        (self.pin_first && col == 0) || (self.pin_last && col + 1 == count_columns)
+    }
+}
+
+impl<W, R> TableOption<R, CompleteDimensionVecRecords<'_>, ColoredConfig> for FitWidth<W>
+where
+    W: Measurement<Width>,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    fn change(
+        self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let width = self.width.measure(&*records, cfg);
+        let (mut widths, mut total) = get_table_widths_with_total(&*records, cfg);
+        if total <= width {
+            return;
+        }
+
+        let paddings = get_table_widths(EmptyRecords::new(count_rows, count_columns), cfg);
+        let min_widths = paddings
+            .iter()
+            .map(|padding| padding + self.min_width)
+            .collect::<Vec<_>>();
+
+        while total > width {
+            let col = (0..count_columns)
+                .filter(|&col| !self.is_pinned(col, count_columns))
+                .filter(|&col| widths[col] > min_widths[col])
+                .max_by_key(|&col| widths[col]);
+
+            match col {
+                Some(col) => {
+                    widths[col] -= 1;
+                    total -= 1;
+                }
+                None => break,
+            }
+        }
+
+        for col in 0..count_columns {
+            for row in 0..count_rows {
+                let pos = (row, col);
+                if !cfg.is_cell_visible(pos) {
+                    continue;
+                }
+
+                let span = cfg.get_column_span(pos).unwrap_or(1);
+                let width = widths[col..col + span].iter().sum::<usize>()
+                    + count_borders(cfg, col, col + span, count_columns);
+
+                let padding = cfg.get_padding(pos.into());
+                let width = width.saturating_sub(padding.left.size + padding.right.size);
+
+                let text_width = string_width_multiline_ambiguous(
+                    records.get_text(pos),
+                    cfg.is_ambiguous_width_wide(),
+                );
+                if text_width <= width {
+                    continue;
+                }
+
+                let truncate = Truncate::new(width).suffix(self.suffix.clone());
+                CellOption::change(truncate, records, cfg, Entity::Cell(row, col));
+            }
+        }
+
+        dims.set_widths(widths);
+    }
+}
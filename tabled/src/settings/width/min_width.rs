@@ -9,7 +9,7 @@ use crate::{
     grid::config::Entity,
     grid::dimension::CompleteDimensionVecRecords,
     grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
-    grid::util::string::{get_lines, string_width_multiline},
+    grid::util::string::{get_lines, string_width_multiline_ambiguous},
     settings::{
         measurement::Measurement,
         peaker::{Peaker, PriorityNone},
@@ -110,6 +110,7 @@ where
 {
     fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
         let width = self.width.measure(&*records, cfg);
+        let ambiguous_wide = cfg.is_ambiguous_width_wide();
 
         let count_rows = records.count_rows();
         let count_columns = records.count_columns();
@@ -121,12 +122,12 @@ where
             }
 
             let cell = records.get_text(pos);
-            let cell_width = string_width_multiline(cell);
+            let cell_width = string_width_multiline_ambiguous(cell, ambiguous_wide);
             if cell_width >= width {
                 continue;
             }
 
-            let content = increase_width(cell, width, self.fill);
+            let content = increase_width(cell, width, self.fill, ambiguous_wide);
             records.set(pos, content);
         }
     }
@@ -191,13 +192,13 @@ where
     widths
 }
 
-fn increase_width(s: &str, width: usize, fill_with: char) -> String {
-    use crate::grid::util::string::string_width;
+fn increase_width(s: &str, width: usize, fill_with: char, ambiguous_wide: bool) -> String {
+    use crate::grid::util::string::string_width_ambiguous;
     use std::{borrow::Cow, iter::repeat};
 
     get_lines(s)
         .map(|line| {
-            let length = string_width(&line);
+            let length = string_width_ambiguous(&line, ambiguous_wide);
 
             if length < width {
                 let mut line = line.into_owned();
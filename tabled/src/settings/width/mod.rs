@@ -3,6 +3,7 @@
 //! - [`Truncate`] cuts a cell content to limit width.
 //! - [`Wrap`] split the content via new lines in order to fit max width.
 //! - [`Justify`] sets columns width to the same value.
+//! - [`FitWidth`] truncates the widest columns to fit a table into a width.
 //!
 //! To set a a table width, a combination of [`Width::truncate`] or [`Width::wrap`] and [`Width::increase`] can be used.
 //!
@@ -32,18 +33,24 @@
 //! );
 //! ```
 
+mod ambiguous_width;
+mod fit;
 mod justify;
 mod min_width;
+mod percent;
 mod truncate;
-mod util;
+pub(crate) mod util;
 mod width_list;
-mod wrap;
+pub(crate) mod wrap;
 
 use crate::settings::measurement::Measurement;
 
 pub use self::{
+    ambiguous_width::AmbiguousWidth,
+    fit::FitWidth,
     justify::Justify,
     min_width::MinWidth,
+    percent::WidthPercent,
     truncate::{SuffixLimit, Truncate},
     width_list::WidthList,
     wrap::Wrap,
@@ -114,6 +121,12 @@ impl Width {
         Truncate::new(width)
     }
 
+    /// Returns a [`FitWidth`] structure,
+    /// which truncates the widest columns with a suffix until a table fits the width.
+    pub fn fit<W: Measurement<Width>>(width: W) -> FitWidth<W> {
+        FitWidth::new(width)
+    }
+
     /// Returns a [`MinWidth`] structure.
     pub fn increase<W: Measurement<Width>>(width: W) -> MinWidth<W> {
         MinWidth::new(width)
@@ -124,11 +137,33 @@ impl Width {
         Justify::new(width)
     }
 
+    /// Returns a [`WidthPercent`] structure,
+    /// which sets columns widths as a percentage of a total width.
+    ///
+    /// Columns which are not in the list share the leftover space.
+    ///
+    /// Being used on cells via [`Modify`] it caps their width by a percentage of a total width.
+    ///
+    /// [`Modify`]: crate::settings::Modify
+    pub fn percent<P: Into<WidthPercent>>(percent: P) -> WidthPercent {
+        percent.into()
+    }
+
+    /// Returns a [`AmbiguousWidth`] setting,
+    /// which sets how characters of an ambiguous East Asian width are measured.
+    pub fn ambiguous(width: AmbiguousWidth) -> AmbiguousWidth {
+        width
+    }
+
     /// Create [`WidthList`] to set a table width to a constant list of column widths.
     ///
-    /// Notice if you provide a list with `.len()` smaller than `Table::count_columns` then it will have no affect.
+    /// A width includes a padding of a column,
+    /// so a content is wrapped to the width left after the padding.
+    /// Set a [`Padding::zero`] to make the widths be widths of a content itself.
+    ///
+    /// Columns without an entry in the list keep their natural width.
     ///
-    /// Also notice that you must provide values bigger than or equal to a real content width, otherwise it may panic.
+    /// [`Padding::zero`]: crate::settings::Padding::zero
     ///
     /// # Example
     ///
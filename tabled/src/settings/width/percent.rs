@@ -0,0 +1,262 @@
+use crate::{
+    grid::config::{ColoredConfig, Entity},
+    grid::dimension::CompleteDimensionVecRecords,
+    grid::records::{EmptyRecords, ExactRecords, PeekableRecords, Records, RecordsMut},
+    grid::util::string::string_width_multiline_ambiguous,
+    settings::{CellOption, TableOption},
+};
+
+use super::{
+    util::{get_table_widths, get_table_widths_with_total},
+    wrap::{wrap_columns, wrap_text},
+};
+
+/// A structure used to set columns widths as a percentage of a total table width.
+///
+/// Percentages are set for columns in order,
+/// the rest of the columns share the leftover space equally.
+///
+/// A total width is either set explicitly via [`WidthPercent::total`],
+/// or taken from a terminal via [`WidthPercent::terminal`] (requires `terminal` feature).
+/// If neither is set the current table width is used.
+///
+/// Cells which exceed their column width are wrapped.
+///
+/// Being used as a [`CellOption`] it caps a width of cells instead.
+/// Only cells which are wider than a first percentage of a total width are wrapped,
+/// others are left as they are.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, settings::Width};
+///
+/// let data = [["Hello", "World", "!"]];
+///
+/// let table = Table::new(data)
+///     .with(Width::percent([50]).total(30))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-------------+-------+------+\n\
+///      | 0           | 1     | 2    |\n\
+///      +-------------+-------+------+\n\
+///      | Hello       | World | !    |\n\
+///      +-------------+-------+------+"
+/// )
+/// ```
+///
+/// ## Cap a column
+///
+/// ```
+/// use tabled::{Table, settings::{object::Columns, Modify, Width}};
+///
+/// let data = [
+///     ["1", "A very long description of an item"],
+///     ["2", "Short"],
+/// ];
+///
+/// let table = Table::new(data)
+///     .with(Modify::new(Columns::single(1)).with(Width::percent(50).total(40)))
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+---+------------------+\n\
+///      | 0 | 1                |\n\
+///      +---+------------------+\n\
+///      | 1 | A very long desc |\n\
+///      |   | ription of an it |\n\
+///      |   | em               |\n\
+///      +---+------------------+\n\
+///      | 2 | Short            |\n\
+///      +---+------------------+"
+/// )
+/// ```
+#[derive(Debug, Clone)]
+pub struct WidthPercent {
+    list: Vec<usize>,
+    total: Option<usize>,
+    keep_words: bool,
+}
+
+impl WidthPercent {
+    /// Creates a new object from a list of percentages of columns.
+    pub fn new(list: Vec<usize>) -> Self {
+        Self {
+            list,
+            total: None,
+            keep_words: false,
+        }
+    }
+
+    /// Sets a total width against which the percentages are resolved.
+    pub fn total(mut self, width: usize) -> Self {
+        self.total = Some(width);
+        self
+    }
+
+    /// Sets a total width to a width of a terminal.
+    ///
+    /// The width is queried from a terminal attached to the process,
+    /// if there's none it's taken from a `COLUMNS` environment variable.
+    /// If neither of them is available nothing is changed.
+    #[cfg(feature = "terminal")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "terminal")))]
+    pub fn terminal(mut self) -> Self {
+        if let Some(width) = terminal_width() {
+            self.total = Some(width);
+        }
+
+        self
+    }
+
+    /// Set the keep words option.
+    ///
+    /// If a wrapping point will be in a word, [`WidthPercent`] will
+    /// preserve a word (if possible) and wrap the string before it.
+    pub fn keep_words(mut self) -> Self {
+        self.keep_words = true;
+        self
+    }
+}
+
+impl From<usize> for WidthPercent {
+    fn from(percent: usize) -> Self {
+        Self::new(vec![percent])
+    }
+}
+
+impl From<Vec<usize>> for WidthPercent {
+    fn from(list: Vec<usize>) -> Self {
+        Self::new(list)
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for WidthPercent {
+    fn from(list: [usize; N]) -> Self {
+        Self::new(list.to_vec())
+    }
+}
+
+impl<R> TableOption<R, CompleteDimensionVecRecords<'_>, ColoredConfig> for WidthPercent
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    fn change(
+        self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let total = match self.total {
+            Some(total) => total,
+            None => get_table_widths_with_total(&*records, cfg).1,
+        };
+
+        let available = get_available_width(cfg, total, count_columns);
+
+        let min_widths = get_table_widths(EmptyRecords::from((count_rows, count_columns)), cfg);
+        let widths = get_percent_widths(&self.list, available, count_columns)
+            .into_iter()
+            .zip(min_widths)
+            .map(|(width, min)| std::cmp::max(width, min))
+            .collect::<Vec<_>>();
+
+        wrap_columns(records, cfg, &widths, self.keep_words, &[]);
+
+        dims.set_widths(widths);
+    }
+}
+
+impl<R> CellOption<R, ColoredConfig> for WidthPercent
+where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+        if count_rows == 0 || count_columns == 0 {
+            return;
+        }
+
+        let percent = match self.list.first() {
+            Some(&percent) => percent,
+            None => return,
+        };
+
+        let total = match self.total {
+            Some(total) => total,
+            None => get_table_widths_with_total(&*records, cfg).1,
+        };
+
+        let available = get_available_width(cfg, total, count_columns);
+        let cap = (available * percent) / 100;
+        let ambiguous_wide = cfg.is_ambiguous_width_wide();
+
+        for pos in entity.iter(count_rows, count_columns) {
+            let is_valid_pos = pos.0 < count_rows && pos.1 < count_columns;
+            if !is_valid_pos {
+                continue;
+            }
+
+            let padding = cfg.get_padding(pos.into());
+            let width = cap.saturating_sub(padding.left.size + padding.right.size);
+
+            let text = records.get_text(pos);
+            let cell_width = string_width_multiline_ambiguous(text, ambiguous_wide);
+            if cell_width <= width {
+                continue;
+            }
+
+            let wrapped = wrap_text(text, width, self.keep_words, ambiguous_wide);
+            records.set(pos, wrapped);
+        }
+    }
+}
+
+fn get_available_width(cfg: &ColoredConfig, total: usize, count_columns: usize) -> usize {
+    let margin = cfg.get_margin();
+    let margin = margin.left.size + margin.right.size;
+
+    total.saturating_sub(margin + cfg.count_vertical(count_columns))
+}
+
+fn get_percent_widths(list: &[usize], available: usize, count_columns: usize) -> Vec<usize> {
+    let mut widths = list
+        .iter()
+        .take(count_columns)
+        .map(|&percent| (available * percent) / 100)
+        .collect::<Vec<_>>();
+
+    let count_rest = count_columns - widths.len();
+    if count_rest == 0 {
+        return widths;
+    }
+
+    let leftover = available.saturating_sub(widths.iter().sum::<usize>());
+    let width = leftover / count_rest;
+    let rest = leftover % count_rest;
+
+    widths.extend((0..count_rest).map(|i| width + usize::from(i < rest)));
+
+    widths
+}
+
+#[cfg(feature = "terminal")]
+fn terminal_width() -> Option<usize> {
+    if let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() {
+        return Some(usize::from(width));
+    }
+
+    std::env::var("COLUMNS").ok()?.trim().parse().ok()
+}
@@ -9,7 +9,7 @@ use crate::{
         config::{ColoredConfig, SpannedConfig},
         dimension::CompleteDimensionVecRecords,
         records::{EmptyRecords, ExactRecords, PeekableRecords, Records, RecordsMut},
-        util::string::{string_width, string_width_multiline},
+        util::string::{string_width_ambiguous, string_width_multiline_ambiguous},
     },
     settings::{
         measurement::Measurement,
@@ -18,7 +18,7 @@ use crate::{
     },
 };
 
-use super::util::{cut_str, get_table_widths, get_table_widths_with_total};
+use super::util::{count_borders, cut_str, get_table_widths, get_table_widths_with_total};
 
 /// Truncate cut the string to a given width if its length exceeds it.
 /// Otherwise keeps the content of a cell untouched.
@@ -178,7 +178,7 @@ impl<'a, W, P> Truncate<'a, W, P> {
 impl Truncate<'_, (), ()> {
     /// Truncate a given string
     pub fn truncate_text(text: &str, width: usize) -> Cow<'_, str> {
-        truncate_text(text, width, "", false)
+        truncate_text(text, width, "", false, false)
     }
 }
 
@@ -190,12 +190,13 @@ where
 {
     fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: papergrid::config::Entity) {
         let available = self.width.measure(&*records, cfg);
+        let ambiguous_wide = cfg.is_ambiguous_width_wide();
 
         let mut width = available;
         let mut suffix = Cow::Borrowed("");
 
         if let Some(x) = self.suffix.as_ref() {
-            let (cutted_suffix, rest_width) = make_suffix(x, width);
+            let (cutted_suffix, rest_width) = make_suffix(x, width, ambiguous_wide);
             suffix = cutted_suffix;
             width = rest_width;
         };
@@ -213,13 +214,20 @@ where
 
             let text = records.get_text(pos);
 
-            let cell_width = string_width_multiline(text);
+            let cell_width = string_width_multiline_ambiguous(text, ambiguous_wide);
             if available >= cell_width {
                 continue;
             }
 
-            let text =
-                truncate_multiline(text, &suffix, width, available, colorize, self.multiline);
+            let text = truncate_multiline(
+                text,
+                &suffix,
+                width,
+                available,
+                colorize,
+                self.multiline,
+                ambiguous_wide,
+            );
 
             records.set(pos, text.into_owned());
         }
@@ -233,6 +241,7 @@ fn truncate_multiline<'a>(
     twidth: usize,
     suffix_color: bool,
     multiline: bool,
+    ambiguous_wide: bool,
 ) -> Cow<'a, str> {
     if multiline {
         let mut buf = String::new();
@@ -241,13 +250,14 @@ fn truncate_multiline<'a>(
                 buf.push('\n');
             }
 
-            let line = make_text_truncated(&line, suffix, width, twidth, suffix_color);
+            let line =
+                make_text_truncated(&line, suffix, width, twidth, suffix_color, ambiguous_wide);
             buf.push_str(&line);
         }
 
         Cow::Owned(buf)
     } else {
-        make_text_truncated(text, suffix, width, twidth, suffix_color)
+        make_text_truncated(text, suffix, width, twidth, suffix_color, ambiguous_wide)
     }
 }
 
@@ -257,6 +267,7 @@ fn make_text_truncated<'a>(
     width: usize,
     twidth: usize,
     suffix_color: bool,
+    ambiguous_wide: bool,
 ) -> Cow<'a, str> {
     if width == 0 {
         if twidth == 0 {
@@ -265,7 +276,7 @@ fn make_text_truncated<'a>(
             Cow::Borrowed(suffix)
         }
     } else {
-        truncate_text(text, width, suffix, suffix_color)
+        truncate_text(text, width, suffix, suffix_color, ambiguous_wide)
     }
 }
 
@@ -280,8 +291,12 @@ fn need_suffix_color_preservation(_suffix: &Option<TruncateSuffix<'_>>) -> bool
     }
 }
 
-fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str>, usize) {
-    let suffix_length = string_width(&suffix.text);
+fn make_suffix<'a>(
+    suffix: &'a TruncateSuffix<'_>,
+    width: usize,
+    ambiguous_wide: bool,
+) -> (Cow<'a, str>, usize) {
+    let suffix_length = string_width_ambiguous(&suffix.text, ambiguous_wide);
     if width > suffix_length {
         return (Cow::Borrowed(suffix.text.as_ref()), width - suffix_length);
     }
@@ -289,7 +304,7 @@ fn make_suffix<'a>(suffix: &'a TruncateSuffix<'_>, width: usize) -> (Cow<'a, str
     match suffix.limit {
         SuffixLimit::Ignore => (Cow::Borrowed(""), width),
         SuffixLimit::Cut => {
-            let suffix = cut_str(&suffix.text, width);
+            let suffix = cut_str(&suffix.text, width, ambiguous_wide);
             (suffix, 0)
         }
         SuffixLimit::Replace(c) => {
@@ -379,8 +394,9 @@ fn truncate_text<'a>(
     width: usize,
     suffix: &str,
     _suffix_color: bool,
+    ambiguous_wide: bool,
 ) -> Cow<'a, str> {
-    let content = cut_str(text, width);
+    let content = cut_str(text, width, ambiguous_wide);
     if suffix.is_empty() {
         return content;
     }
@@ -495,10 +511,3 @@ fn decrease_widths<F>(
         width += 1;
     }
 }
-
-fn count_borders(cfg: &SpannedConfig, start: usize, end: usize, count_columns: usize) -> usize {
-    (start..end)
-        .skip(1)
-        .filter(|&i| cfg.has_vertical(i, count_columns))
-        .count()
-}
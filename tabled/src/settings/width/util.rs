@@ -2,6 +2,7 @@ use std::borrow::Cow;
 
 use crate::{
     grid::config::SpannedConfig, grid::dimension::SpannedGridDimension, grid::records::Records,
+    grid::util::string::get_char_width_ambiguous,
 };
 
 pub(crate) fn get_table_widths<R: Records>(records: R, cfg: &SpannedConfig) -> Vec<usize> {
@@ -17,6 +18,19 @@ pub(crate) fn get_table_widths_with_total<R: Records>(
     (widths, total_width)
 }
 
+/// Returns a width of vertical borders inside a range of columns.
+pub(crate) fn count_borders(
+    cfg: &SpannedConfig,
+    start: usize,
+    end: usize,
+    count_columns: usize,
+) -> usize {
+    (start..end)
+        .skip(1)
+        .map(|i| cfg.get_vertical_width(i, count_columns))
+        .sum()
+}
+
 fn get_table_total_width(list: &[usize], cfg: &SpannedConfig) -> usize {
     let margin = cfg.get_margin();
     list.iter().sum::<usize>()
@@ -28,27 +42,34 @@ fn get_table_total_width(list: &[usize], cfg: &SpannedConfig) -> usize {
 /// The function cuts the string to a specific width.
 ///
 /// BE AWARE: width is expected to be in bytes.
-pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
+pub(crate) fn cut_str(s: &str, width: usize, ambiguous_wide: bool) -> Cow<'_, str> {
     #[cfg(feature = "color")]
     {
         const REPLACEMENT: char = '\u{FFFD}';
 
         let stripped = ansi_str::AnsiStr::ansi_strip(s);
-        let (length, count_unknowns, _) = split_at_pos(&stripped, width);
+        let (length, count_unknowns, char_length) = split_at_pos(&stripped, width, ambiguous_wide);
 
-        let mut buf = ansi_str::AnsiStr::ansi_cut(s, ..length);
-        if count_unknowns > 0 {
-            let mut b = buf.into_owned();
-            b.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
-            buf = Cow::Owned(b);
+        if count_unknowns == 0 {
+            if length == 0 {
+                return Cow::Borrowed("");
+            }
+
+            return ansi_str::AnsiStr::ansi_cut(s, ..length);
         }
 
-        buf
+        // we cut the split character too, so the replacement gets its style
+        let buf = ansi_str::AnsiStr::ansi_cut(s, ..length + char_length);
+        let replacement = std::iter::repeat(REPLACEMENT)
+            .take(count_unknowns)
+            .collect::<String>();
+
+        Cow::Owned(replace_visible_char(&buf, &replacement, false))
     }
 
     #[cfg(not(feature = "color"))]
     {
-        cut_str_basic(s, width)
+        cut_str_basic(s, width, ambiguous_wide)
     }
 }
 
@@ -56,10 +77,10 @@ pub(crate) fn cut_str(s: &str, width: usize) -> Cow<'_, str> {
 ///
 /// BE AWARE: width is expected to be in bytes.
 #[cfg(not(feature = "color"))]
-pub(crate) fn cut_str_basic(s: &str, width: usize) -> Cow<'_, str> {
+pub(crate) fn cut_str_basic(s: &str, width: usize, ambiguous_wide: bool) -> Cow<'_, str> {
     const REPLACEMENT: char = '\u{FFFD}';
 
-    let (length, count_unknowns, _) = split_at_pos(s, width);
+    let (length, count_unknowns, _) = split_at_pos(s, width, ambiguous_wide);
     let buf = &s[..length];
     if count_unknowns == 0 {
         return Cow::Borrowed(buf);
@@ -71,12 +92,92 @@ pub(crate) fn cut_str_basic(s: &str, width: usize) -> Cow<'_, str> {
     Cow::Owned(buf)
 }
 
+/// The function returns a rest of the string after a specific width.
+///
+/// It's a counterpart of [`cut_str`],
+/// so a character which is split in 2 is replaced by a `\u{FFFD}` on both sides.
+pub(crate) fn cut_str_rest(s: &str, width: usize, ambiguous_wide: bool) -> Cow<'_, str> {
+    const REPLACEMENT: char = '\u{FFFD}';
+
+    #[cfg(feature = "color")]
+    {
+        let stripped = ansi_str::AnsiStr::ansi_strip(s);
+        let (length, count_unknowns, _) = split_at_pos(&stripped, width, ambiguous_wide);
+
+        if count_unknowns == 0 {
+            if length == stripped.len() {
+                return Cow::Borrowed("");
+            }
+
+            return ansi_str::AnsiStr::ansi_cut(s, length..);
+        }
+
+        // we keep the split character, so the replacement gets its style
+        let c = stripped[length..].chars().next().unwrap_or(REPLACEMENT);
+        let count_replacements = get_char_width_ambiguous(c, ambiguous_wide) - count_unknowns;
+        let replacement = std::iter::repeat(REPLACEMENT)
+            .take(count_replacements)
+            .collect::<String>();
+
+        let buf = ansi_str::AnsiStr::ansi_cut(s, length..);
+
+        Cow::Owned(replace_visible_char(&buf, &replacement, true))
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        let (mut length, count_unknowns, char_length) = split_at_pos(s, width, ambiguous_wide);
+        let mut count_replacements = 0;
+        if count_unknowns > 0 {
+            let c = s[length..].chars().next().unwrap_or(REPLACEMENT);
+            count_replacements = get_char_width_ambiguous(c, ambiguous_wide) - count_unknowns;
+            length += char_length;
+        }
+
+        let buf = &s[length..];
+        if count_replacements == 0 {
+            return Cow::Borrowed(buf);
+        }
+
+        let mut b = String::with_capacity(buf.len() + count_replacements * REPLACEMENT.len_utf8());
+        b.extend(std::iter::repeat(REPLACEMENT).take(count_replacements));
+        b.push_str(buf);
+
+        Cow::Owned(b)
+    }
+}
+
+/// Replaces a first (or a last) visible character of a text, keeping ANSI sequences around it.
+#[cfg(feature = "color")]
+fn replace_visible_char(text: &str, replacement: &str, first: bool) -> String {
+    let mut chars = ansitok::parse_ansi(text)
+        .filter(|el| el.kind() == ansitok::ElementKind::Text)
+        .flat_map(|el| {
+            text[el.start()..el.end()]
+                .char_indices()
+                .map(move |(i, c)| (el.start() + i, c.len_utf8()))
+        });
+
+    let found = if first { chars.next() } else { chars.last() };
+    let (pos, length) = match found {
+        Some(found) => found,
+        None => return text.to_owned(),
+    };
+
+    let mut buf = String::with_capacity(text.len() + replacement.len());
+    buf.push_str(&text[..pos]);
+    buf.push_str(replacement);
+    buf.push_str(&text[pos + length..]);
+
+    buf
+}
+
 /// The function splits a string in the position and
 /// returns a exact number of bytes before the position and in case of a split in an unicode grapheme
 /// a width of a character which was tried to be splited in.
 ///
 /// BE AWARE: pos is expected to be in bytes.
-pub(crate) fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
+pub(crate) fn split_at_pos(s: &str, pos: usize, ambiguous_wide: bool) -> (usize, usize, usize) {
     let mut length = 0;
     let mut i = 0;
     for c in s.chars() {
@@ -84,7 +185,7 @@ pub(crate) fn split_at_pos(s: &str, pos: usize) -> (usize, usize, usize) {
             break;
         };
 
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
+        let c_width = get_char_width_ambiguous(c, ambiguous_wide);
 
         // We cut the chars which takes more then 1 symbol to display,
         // in order to archive the necessary width.
@@ -174,40 +275,40 @@ mod tests {
 
     #[test]
     fn strip_test() {
-        assert_eq!(cut_str("123456", 0), "");
-        assert_eq!(cut_str("123456", 3), "123");
-        assert_eq!(cut_str("123456", 10), "123456");
+        assert_eq!(cut_str("123456", 0, false), "");
+        assert_eq!(cut_str("123456", 3, false), "123");
+        assert_eq!(cut_str("123456", 10, false), "123456");
 
-        assert_eq!(cut_str("a week ago", 4), "a we");
+        assert_eq!(cut_str("a week ago", 4, false), "a we");
 
-        assert_eq!(cut_str("😳😳😳😳😳", 0), "");
-        assert_eq!(cut_str("😳😳😳😳😳", 3), "😳�");
-        assert_eq!(cut_str("😳😳😳😳😳", 4), "😳😳");
-        assert_eq!(cut_str("😳😳😳😳😳", 20), "😳😳😳😳😳");
+        assert_eq!(cut_str("😳😳😳😳😳", 0, false), "");
+        assert_eq!(cut_str("😳😳😳😳😳", 3, false), "😳�");
+        assert_eq!(cut_str("😳😳😳😳😳", 4, false), "😳😳");
+        assert_eq!(cut_str("😳😳😳😳😳", 20, false), "😳😳😳😳😳");
 
-        assert_eq!(cut_str("🏳️🏳️", 0), "");
-        assert_eq!(cut_str("🏳️🏳️", 1), "🏳");
-        assert_eq!(cut_str("🏳️🏳️", 2), "🏳\u{fe0f}🏳");
+        assert_eq!(cut_str("🏳️🏳️", 0, false), "");
+        assert_eq!(cut_str("🏳️🏳️", 1, false), "🏳");
+        assert_eq!(cut_str("🏳️🏳️", 2, false), "🏳\u{fe0f}🏳");
         assert_eq!(string_width("🏳️🏳️"), string_width("🏳\u{fe0f}🏳"));
 
-        assert_eq!(cut_str("🎓", 1), "�");
-        assert_eq!(cut_str("🎓", 2), "🎓");
+        assert_eq!(cut_str("🎓", 1, false), "�");
+        assert_eq!(cut_str("🎓", 2, false), "🎓");
 
-        assert_eq!(cut_str("🥿", 1), "�");
-        assert_eq!(cut_str("🥿", 2), "🥿");
+        assert_eq!(cut_str("🥿", 1, false), "�");
+        assert_eq!(cut_str("🥿", 2, false), "🥿");
 
-        assert_eq!(cut_str("🩰", 1), "�");
-        assert_eq!(cut_str("🩰", 2), "🩰");
+        assert_eq!(cut_str("🩰", 1, false), "�");
+        assert_eq!(cut_str("🩰", 2, false), "🩰");
 
-        assert_eq!(cut_str("👍🏿", 1), "�");
-        assert_eq!(cut_str("👍🏿", 2), "👍");
-        assert_eq!(cut_str("👍🏿", 3), "👍�");
-        assert_eq!(cut_str("👍🏿", 4), "👍🏿");
+        assert_eq!(cut_str("👍🏿", 1, false), "�");
+        assert_eq!(cut_str("👍🏿", 2, false), "👍");
+        assert_eq!(cut_str("👍🏿", 3, false), "👍�");
+        assert_eq!(cut_str("👍🏿", 4, false), "👍🏿");
 
-        assert_eq!(cut_str("🇻🇬", 1), "🇻");
-        assert_eq!(cut_str("🇻🇬", 2), "🇻🇬");
-        assert_eq!(cut_str("🇻🇬", 3), "🇻🇬");
-        assert_eq!(cut_str("🇻🇬", 4), "🇻🇬");
+        assert_eq!(cut_str("🇻🇬", 1, false), "🇻");
+        assert_eq!(cut_str("🇻🇬", 2, false), "🇻🇬");
+        assert_eq!(cut_str("🇻🇬", 3, false), "🇻🇬");
+        assert_eq!(cut_str("🇻🇬", 4, false), "🇻🇬");
     }
 
     #[cfg(feature = "color")]
@@ -215,32 +316,50 @@ mod tests {
     fn strip_color_test() {
         let numbers = "123456".red().on_bright_black().to_string();
 
-        assert_eq!(cut_str(&numbers, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
         assert_eq!(
-            cut_str(&numbers, 3),
+            cut_str(&numbers, 0, false),
+            "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(
+            cut_str(&numbers, 3, false),
             "\u{1b}[31;100m123\u{1b}[39m\u{1b}[49m"
         );
-        assert_eq!(cut_str(&numbers, 10), "\u{1b}[31;100m123456\u{1b}[0m");
+        assert_eq!(
+            cut_str(&numbers, 10, false),
+            "\u{1b}[31;100m123456\u{1b}[0m"
+        );
 
         let emojies = "😳😳😳😳😳".red().on_bright_black().to_string();
 
-        assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
         assert_eq!(
-            cut_str(&emojies, 3),
+            cut_str(&emojies, 0, false),
+            "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(
+            cut_str(&emojies, 3, false),
             "\u{1b}[31;100m😳\u{1b}[39m\u{1b}[49m�"
         );
         assert_eq!(
-            cut_str(&emojies, 4),
+            cut_str(&emojies, 4, false),
             "\u{1b}[31;100m😳😳\u{1b}[39m\u{1b}[49m"
         );
-        assert_eq!(cut_str(&emojies, 20), "\u{1b}[31;100m😳😳😳😳😳\u{1b}[0m");
+        assert_eq!(
+            cut_str(&emojies, 20, false),
+            "\u{1b}[31;100m😳😳😳😳😳\u{1b}[0m"
+        );
 
         let emojies = "🏳️🏳️".red().on_bright_black().to_string();
 
-        assert_eq!(cut_str(&emojies, 0), "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m");
-        assert_eq!(cut_str(&emojies, 1), "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m");
         assert_eq!(
-            cut_str(&emojies, 2),
+            cut_str(&emojies, 0, false),
+            "\u{1b}[31;100m\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(
+            cut_str(&emojies, 1, false),
+            "\u{1b}[31;100m🏳\u{1b}[39m\u{1b}[49m"
+        );
+        assert_eq!(
+            cut_str(&emojies, 2, false),
             "\u{1b}[31;100m🏳\u{fe0f}🏳\u{1b}[39m\u{1b}[49m"
         );
         assert_eq!(
@@ -258,7 +377,7 @@ mod tests {
             .blink()
             .to_string();
         assert_eq!(
-            cut_str(&s, 1),
+            cut_str(&s, 1, false),
             "\u{1b}[5m\u{1b}[48;2;12;200;100m\u{1b}[33mC\u{1b}[25m\u{1b}[39m\u{1b}[49m"
         )
     }
@@ -1,11 +1,23 @@
 use std::iter::FromIterator;
 
 use crate::{
-    grid::dimension::CompleteDimensionVecRecords, grid::records::Records, settings::TableOption,
+    grid::config::{ColoredConfig, Entity},
+    grid::dimension::{CompleteDimensionVecRecords, SpannedGridDimension},
+    grid::records::{ExactRecords, PeekableRecords, Records, RecordsMut},
+    grid::util::string::string_width_multiline_ambiguous,
+    settings::{CellOption, TableOption, Width},
 };
 
+use super::util::count_borders;
+
 /// A structure used to set [`Table`] width via a list of columns widths.
 ///
+/// A width includes a padding of a column,
+/// so a content which doesn't fit a width left after the padding is wrapped.
+/// A spanned cell is wrapped to a combined width of the columns it spans.
+/// If the list is shorter than a number of columns the rest of columns keep their natural width.
+/// If it's longer the extra values are ignored.
+///
 /// [`Table`]: crate::Table
 #[derive(Debug)]
 pub struct WidthList {
@@ -31,15 +43,65 @@ impl FromIterator<usize> for WidthList {
     }
 }
 
-impl<R, C> TableOption<R, CompleteDimensionVecRecords<'_>, C> for WidthList
+impl<R> TableOption<R, CompleteDimensionVecRecords<'_>, ColoredConfig> for WidthList
 where
-    R: Records,
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
 {
-    fn change(self, records: &mut R, _: &mut C, dimension: &mut CompleteDimensionVecRecords<'_>) {
-        if self.list.len() < records.count_columns() {
-            return;
+    fn change(
+        mut self,
+        records: &mut R,
+        cfg: &mut ColoredConfig,
+        dims: &mut CompleteDimensionVecRecords<'_>,
+    ) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        self.list.truncate(count_columns);
+
+        for col in 0..self.list.len() {
+            for row in 0..count_rows {
+                let pos = (row, col);
+                if !cfg.is_cell_visible(pos) {
+                    continue;
+                }
+
+                // a spanned cell takes the widths of all its columns,
+                // unless some of them are left with their natural width.
+                let span = cfg.get_column_span(pos).unwrap_or(1);
+                if col + span > self.list.len() {
+                    continue;
+                }
+
+                let width = self.list[col..col + span].iter().sum::<usize>()
+                    + count_borders(cfg, col, col + span, count_columns);
+
+                let pad = cfg.get_padding(Entity::Cell(row, col));
+                let width = width.saturating_sub(pad.left.size + pad.right.size);
+
+                let text_width = string_width_multiline_ambiguous(
+                    records.get_text(pos),
+                    cfg.is_ambiguous_width_wide(),
+                );
+                if text_width <= width {
+                    continue;
+                }
+
+                if width == 0 {
+                    records.set(pos, String::new());
+                    continue;
+                }
+
+                CellOption::change(Width::wrap(width), records, cfg, Entity::Cell(row, col));
+            }
+        }
+
+        if self.list.len() < count_columns {
+            let widths = SpannedGridDimension::width(&*records, cfg);
+            let rest = widths.into_iter().skip(self.list.len());
+            self.list.extend(rest);
         }
 
-        dimension.set_widths(self.list);
+        dims.set_widths(self.list);
     }
 }
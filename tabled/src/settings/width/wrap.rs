@@ -3,13 +3,19 @@
 //!
 //! [`Table`]: crate::Table
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Range};
 
 use crate::{
     grid::config::ColoredConfig,
     grid::dimension::CompleteDimensionVecRecords,
     grid::records::{EmptyRecords, ExactRecords, PeekableRecords, Records, RecordsMut},
-    grid::{config::Entity, config::SpannedConfig, util::string::string_width_multiline},
+    grid::{
+        config::Entity,
+        config::SpannedConfig,
+        util::string::{
+            get_char_width_ambiguous, get_text_width_ambiguous, string_width_multiline_ambiguous,
+        },
+    },
     settings::{
         measurement::Measurement,
         peaker::{Peaker, PriorityNone},
@@ -18,7 +24,7 @@ use crate::{
     },
 };
 
-use super::util::{get_table_widths, get_table_widths_with_total, split_at_pos};
+use super::util::{count_borders, get_table_widths, get_table_widths_with_total, split_at_pos};
 
 /// Wrap wraps a string to a new line in case it exceeds the provided max boundary.
 /// Otherwise keeps the content of a cell untouched.
@@ -42,6 +48,7 @@ use super::util::{get_table_widths, get_table_widths_with_total, split_at_pos};
 pub struct Wrap<W = usize, P = PriorityNone> {
     width: W,
     keep_words: bool,
+    break_on: Vec<char>,
     _priority: PhantomData<P>,
 }
 
@@ -54,6 +61,7 @@ impl<W> Wrap<W> {
         Wrap {
             width,
             keep_words: false,
+            break_on: Vec::new(),
             _priority: PhantomData,
         }
     }
@@ -76,6 +84,7 @@ impl<W, P> Wrap<W, P> {
         Wrap {
             width: self.width,
             keep_words: self.keep_words,
+            break_on: self.break_on,
             _priority: PhantomData,
         }
     }
@@ -88,12 +97,43 @@ impl<W, P> Wrap<W, P> {
         self.keep_words = true;
         self
     }
+
+    /// Set a list of characters after which a line may be broken.
+    ///
+    /// [`Wrap`] tries to break a line right after the last of these characters
+    /// which fits the width, and falls back to a split by characters
+    /// if a part between break points is too long anyway.
+    ///
+    /// Together with [`Wrap::keep_words`] a space is a break point as well.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Segment, width::Width, Modify, Style}};
+    ///
+    /// let data = ["/usr/local/share/applications"];
+    /// let table = Table::new(data)
+    ///     .with(Style::markdown())
+    ///     .with(Modify::new(Segment::all()).with(Width::wrap(15).break_on(&['/'])))
+    ///     .to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "| &str         |\n\
+    ///      |--------------|\n\
+    ///      | /usr/local/  |\n\
+    ///      | share/       |\n\
+    ///      | applications |"
+    /// );
+    /// ```
+    pub fn break_on(mut self, chars: &[char]) -> Self {
+        self.break_on = chars.to_vec();
+        self
+    }
 }
 
 impl Wrap<(), ()> {
     /// Wrap a given string
     pub fn wrap_text(text: &str, width: usize, keeping_words: bool) -> String {
-        wrap_text(text, width, keeping_words)
+        wrap_text(text, width, keeping_words, false)
     }
 }
 
@@ -122,7 +162,10 @@ where
 
         let priority = P::create();
         let keep_words = self.keep_words;
-        let widths = wrap_total_width(records, cfg, widths, total, width, keep_words, priority);
+        let break_on = &self.break_on;
+        let widths = wrap_total_width(
+            records, cfg, widths, total, width, keep_words, break_on, priority,
+        );
 
         dims.set_widths(widths);
     }
@@ -137,6 +180,8 @@ where
     fn change(self, records: &mut R, cfg: &mut ColoredConfig, entity: Entity) {
         let width = self.width.measure(&*records, cfg);
 
+        let ambiguous_wide = cfg.is_ambiguous_width_wide();
+
         let count_rows = records.count_rows();
         let count_columns = records.count_columns();
 
@@ -147,17 +192,23 @@ where
             }
 
             let text = records.get_text(pos);
-            let cell_width = string_width_multiline(text);
+            let cell_width = string_width_multiline_ambiguous(text, ambiguous_wide);
             if cell_width <= width {
                 continue;
             }
 
-            let wrapped = wrap_text(text, width, self.keep_words);
+            let wrapped = if self.break_on.is_empty() {
+                wrap_text(text, width, self.keep_words, ambiguous_wide)
+            } else {
+                wrap_text_on_chars(text, width, &self.break_on, self.keep_words, ambiguous_wide)
+            };
+
             records.set(pos, wrapped);
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn wrap_total_width<R, P>(
     records: &mut R,
     cfg: &mut ColoredConfig,
@@ -165,6 +216,7 @@ fn wrap_total_width<R, P>(
     total_width: usize,
     width: usize,
     keep_words: bool,
+    break_on: &[char],
     priority: P,
 ) -> Vec<usize>
 where
@@ -177,32 +229,60 @@ where
 
     decrease_widths(&mut widths, &min_widths, total_width, width, priority);
 
-    let points = get_decrease_cell_list(cfg, &widths, &min_widths, shape);
+    wrap_columns(records, cfg, &widths, keep_words, break_on);
+
+    widths
+}
+
+/// Wraps each cell so it fits a given list of columns widths.
+pub(crate) fn wrap_columns<R>(
+    records: &mut R,
+    cfg: &mut ColoredConfig,
+    widths: &[usize],
+    keep_words: bool,
+    break_on: &[char],
+) where
+    R: Records + ExactRecords + PeekableRecords + RecordsMut<String>,
+    for<'a> &'a R: Records,
+{
+    let shape = (records.count_rows(), records.count_columns());
+    let min_widths = get_table_widths(EmptyRecords::from(shape), cfg);
+
+    let points = get_decrease_cell_list(cfg, widths, &min_widths, shape);
 
     for ((row, col), width) in points {
         let mut wrap = Wrap::new(width);
         wrap.keep_words = keep_words;
+        wrap.break_on = break_on.to_vec();
         <Wrap as CellOption<_, _>>::change(wrap, records, cfg, (row, col).into());
     }
-
-    widths
 }
 
 #[cfg(not(feature = "color"))]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    ambiguous_wide: bool,
+) -> String {
     if width == 0 {
         return String::new();
     }
 
     if keep_words {
-        split_keeping_words(text, width, "\n")
+        split_keeping_words(text, width, "\n", ambiguous_wide)
     } else {
-        chunks(text, width).join("\n")
+        chunks(text, width, ambiguous_wide).join("\n")
     }
 }
 
 #[cfg(feature = "color")]
-pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
+pub(crate) fn wrap_text(
+    text: &str,
+    width: usize,
+    keep_words: bool,
+    ambiguous_wide: bool,
+) -> String {
     use super::util::strip_osc;
 
     if width == 0 {
@@ -213,12 +293,113 @@ pub(crate) fn wrap_text(text: &str, width: usize, keep_words: bool) -> String {
     let (prefix, suffix) = build_link_prefix_suffix(url);
 
     if keep_words {
-        split_keeping_words(&text, width, &prefix, &suffix)
+        split_keeping_words(&text, width, &prefix, &suffix, ambiguous_wide)
     } else {
-        chunks(&text, width, &prefix, &suffix).join("\n")
+        chunks(&text, width, &prefix, &suffix, ambiguous_wide).join("\n")
     }
 }
 
+/// Wraps a text breaking lines after any of the given characters where possible.
+///
+/// If `keep_words` is set a space is a break point as well,
+/// and it's dropped when a line is broken at it.
+pub(crate) fn wrap_text_on_chars(
+    text: &str,
+    width: usize,
+    break_on: &[char],
+    keep_words: bool,
+    ambiguous_wide: bool,
+) -> String {
+    if width == 0 {
+        return String::new();
+    }
+
+    #[cfg(feature = "color")]
+    {
+        use super::util::strip_osc;
+
+        let (text, url): (String, Option<String>) = strip_osc(text);
+        let (prefix, suffix) = build_link_prefix_suffix(url);
+
+        let mut lines = Vec::new();
+        for line in text.split('\n') {
+            let stripped = ansi_str::AnsiStr::ansi_strip(line);
+            for range in break_line_on_chars(&stripped, width, break_on, keep_words, ambiguous_wide)
+            {
+                let part = ansi_str::AnsiStr::ansi_cut(line, range);
+                lines.push(format!("{prefix}{part}{suffix}"));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    #[cfg(not(feature = "color"))]
+    {
+        let mut lines = Vec::new();
+        for line in text.split('\n') {
+            for range in break_line_on_chars(line, width, break_on, keep_words, ambiguous_wide) {
+                lines.push(&line[range]);
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Returns byte ranges of a line split by width, preferring to break right after a given characters.
+///
+/// If `keep_words` is set a line is also broken at a space, which is not included in any range.
+fn break_line_on_chars(
+    line: &str,
+    width: usize,
+    break_on: &[char],
+    keep_words: bool,
+    ambiguous_wide: bool,
+) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut line_width = 0;
+    // an end of a current line and a start of a next one
+    let mut last_break: Option<(usize, usize)> = None;
+
+    for (i, c) in line.char_indices() {
+        let is_space = keep_words && c == ' ';
+        let c_width = get_char_width_ambiguous(c, ambiguous_wide);
+
+        if is_space && line_width + c_width > width {
+            ranges.push(start..i);
+            start = i + c.len_utf8();
+            line_width = 0;
+            last_break = None;
+            continue;
+        }
+
+        while line_width + c_width > width && line_width > 0 {
+            let (end, next) = last_break.unwrap_or((i, i));
+            ranges.push(start..end);
+
+            line_width = get_text_width_ambiguous(&line[next..i], ambiguous_wide);
+            start = next;
+            last_break = None;
+        }
+
+        line_width += c_width;
+
+        if is_space {
+            last_break = Some((i, i + c.len_utf8()));
+        } else if break_on.contains(&c) {
+            last_break = Some((i + c.len_utf8(), i + c.len_utf8()));
+        }
+    }
+
+    if start < line.len() || ranges.is_empty() {
+        ranges.push(start..line.len());
+    }
+
+    ranges
+}
+
 #[cfg(feature = "color")]
 fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
     match url {
@@ -234,7 +415,7 @@ fn build_link_prefix_suffix(url: Option<String>) -> (String, String) {
 }
 
 #[cfg(not(feature = "color"))]
-fn chunks(s: &str, width: usize) -> Vec<String> {
+fn chunks(s: &str, width: usize, ambiguous_wide: bool) -> Vec<String> {
     if width == 0 {
         return Vec::new();
     }
@@ -245,7 +426,7 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
     let mut list = Vec::new();
     let mut i = 0;
     for c in s.chars() {
-        let c_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or_default();
+        let c_width = get_char_width_ambiguous(c, ambiguous_wide);
         if i + c_width > width {
             let count_unknowns = width - i;
             buf.extend(std::iter::repeat(REPLACEMENT).take(count_unknowns));
@@ -270,7 +451,7 @@ fn chunks(s: &str, width: usize) -> Vec<String> {
 }
 
 #[cfg(feature = "color")]
-fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
+fn chunks(s: &str, width: usize, prefix: &str, suffix: &str, ambiguous_wide: bool) -> Vec<String> {
     use std::fmt::Write;
 
     if width == 0 {
@@ -301,7 +482,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
         while !text_slice.is_empty() {
             let available_space = width - line_width;
 
-            let part_width = unicode_width::UnicodeWidthStr::width(text_slice);
+            let part_width = get_text_width_ambiguous(text_slice, ambiguous_wide);
             if part_width <= available_space {
                 line.push_str(text_slice);
                 line_width += part_width;
@@ -319,12 +500,13 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
                 break;
             }
 
-            let (lhs, rhs, (unknowns, split_char)) = split_string_at(text_slice, available_space);
+            let (lhs, rhs, (unknowns, split_char)) =
+                split_string_at(text_slice, available_space, ambiguous_wide);
 
             text_slice = &rhs[split_char..];
 
             line.push_str(lhs);
-            line_width += unicode_width::UnicodeWidthStr::width(lhs);
+            line_width += get_text_width_ambiguous(lhs, ambiguous_wide);
 
             const REPLACEMENT: char = '\u{FFFD}';
             line.extend(std::iter::repeat(REPLACEMENT).take(unknowns));
@@ -355,7 +537,7 @@ fn chunks(s: &str, width: usize, prefix: &str, suffix: &str) -> Vec<String> {
 }
 
 #[cfg(not(feature = "color"))]
-fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
+fn split_keeping_words(s: &str, width: usize, sep: &str, ambiguous_wide: bool) -> String {
     const REPLACEMENT: char = '\u{FFFD}';
 
     let mut lines = Vec::new();
@@ -378,7 +560,7 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             is_first_word = false;
         }
 
-        let word_width = unicode_width::UnicodeWidthStr::width(word);
+        let word_width = get_text_width_ambiguous(word, ambiguous_wide);
 
         let line_has_space = line_width + word_width <= width;
         if line_has_space {
@@ -406,10 +588,10 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
             while !word_part.is_empty() {
                 let available_space = width - line_width;
                 let (lhs, rhs, (unknowns, split_char)) =
-                    split_string_at(word_part, available_space);
+                    split_string_at(word_part, available_space, ambiguous_wide);
 
                 word_part = &rhs[split_char..];
-                line_width += unicode_width::UnicodeWidthStr::width(lhs) + unknowns;
+                line_width += get_text_width_ambiguous(lhs, ambiguous_wide) + unknowns;
                 is_first_word = false;
 
                 line.push_str(lhs);
@@ -434,7 +616,13 @@ fn split_keeping_words(s: &str, width: usize, sep: &str) -> String {
 }
 
 #[cfg(feature = "color")]
-fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) -> String {
+fn split_keeping_words(
+    text: &str,
+    width: usize,
+    prefix: &str,
+    suffix: &str,
+    ambiguous_wide: bool,
+) -> String {
     if text.is_empty() || width == 0 {
         return String::new();
     }
@@ -443,7 +631,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
     let mut word_width = 0;
     let mut word_chars = 0;
     let mut blocks = parsing::Blocks::new(ansi_str::get_blocks(text));
-    let mut buf = parsing::MultilineBuffer::new(width);
+    let mut buf = parsing::MultilineBuffer::new(width, ambiguous_wide);
     buf.set_prefix(prefix);
     buf.set_suffix(suffix);
 
@@ -460,7 +648,7 @@ fn split_keeping_words(text: &str, width: usize, prefix: &str, suffix: &str) ->
                 word_width = 0;
             }
             _ => {
-                word_width += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                word_width += get_char_width_ambiguous(c, ambiguous_wide);
                 word_chars += 1;
             }
         }
@@ -479,6 +667,8 @@ mod parsing {
     use ansi_str::{AnsiBlock, AnsiBlockIter, Style};
     use std::fmt::Write;
 
+    use crate::grid::util::string::get_char_width_ambiguous;
+
     pub(super) struct Blocks<'a> {
         iter: AnsiBlockIter<'a>,
         current: Option<RelativeBlock<'a>>,
@@ -528,16 +718,18 @@ mod parsing {
         width: usize,
         prefix: &'a str,
         suffix: &'a str,
+        ambiguous_wide: bool,
     }
 
     impl<'a> MultilineBuffer<'a> {
-        pub(super) fn new(width: usize) -> Self {
+        pub(super) fn new(width: usize, ambiguous_wide: bool) -> Self {
             Self {
                 buf: String::new(),
                 width_last: 0,
                 prefix: "",
                 suffix: "",
                 width,
+                ambiguous_wide,
             }
         }
 
@@ -562,7 +754,7 @@ mod parsing {
         }
 
         pub(super) fn fill(&mut self, c: char) -> usize {
-            debug_assert_eq!(unicode_width::UnicodeWidthChar::width(c), Some(1));
+            debug_assert_eq!(get_char_width_ambiguous(c, self.ambiguous_wide), 1);
 
             let rest_width = self.available_width();
             for _ in 0..rest_width {
@@ -618,7 +810,7 @@ mod parsing {
                 count_chars += 1;
                 count_bytes += c.len_utf8();
 
-                let cwidth = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                let cwidth = get_char_width_ambiguous(c, self.ambiguous_wide);
 
                 let available_space = self.width - self.width_last;
                 if available_space == 0 {
@@ -666,7 +858,7 @@ mod parsing {
                 count_chars += 1;
                 count_bytes += c.len_utf8();
 
-                let cwidth = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+                let cwidth = get_char_width_ambiguous(c, self.ambiguous_wide);
                 self.width_last += cwidth;
 
                 self.buf.push(c);
@@ -776,8 +968,8 @@ mod parsing {
     }
 }
 
-fn split_string_at(text: &str, at: usize) -> (&str, &str, (usize, usize)) {
-    let (length, count_unknowns, split_char_size) = split_at_pos(text, at);
+fn split_string_at(text: &str, at: usize, ambiguous_wide: bool) -> (&str, &str, (usize, usize)) {
+    let (length, count_unknowns, split_char_size) = split_at_pos(text, at, ambiguous_wide);
     let (lhs, rhs) = text.split_at(length);
 
     (lhs, rhs, (count_unknowns, split_char_size))
@@ -856,13 +1048,6 @@ fn get_decrease_cell_list(
     points
 }
 
-fn count_borders(cfg: &SpannedConfig, start: usize, end: usize, count_columns: usize) -> usize {
-    (start..end)
-        .skip(1)
-        .filter(|&i| cfg.has_vertical(i, count_columns))
-        .count()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -870,10 +1055,10 @@ mod tests {
     #[test]
     fn split_test() {
         #[cfg(not(feature = "color"))]
-        let split = |text, width| chunks(text, width).join("\n");
+        let split = |text, width| chunks(text, width, false).join("\n");
 
         #[cfg(feature = "color")]
-        let split = |text, width| chunks(text, width, "", "").join("\n");
+        let split = |text, width| chunks(text, width, "", "", false).join("\n");
 
         assert_eq!(split("123456", 0), "");
 
@@ -897,10 +1082,10 @@ mod tests {
     fn chunks_test() {
         #[allow(clippy::redundant_closure)]
         #[cfg(not(feature = "color"))]
-        let chunks = |text, width| chunks(text, width);
+        let chunks = |text, width| chunks(text, width, false);
 
         #[cfg(feature = "color")]
-        let chunks = |text, width| chunks(text, width, "", "");
+        let chunks = |text, width| chunks(text, width, "", "", false);
 
         assert_eq!(chunks("123456", 0), [""; 0]);
 
@@ -916,7 +1101,7 @@ mod tests {
     #[cfg(not(feature = "color"))]
     #[test]
     fn split_by_line_keeping_words_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n", false);
 
         assert_eq!(split_keeping_words("123456", 1), "1\n2\n3\n4\n5\n6");
         assert_eq!(split_keeping_words("123456", 2), "12\n34\n56");
@@ -931,7 +1116,7 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_test() {
         #[cfg(feature = "color")]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "", false);
 
         assert_eq!(split_keeping_words("123456", 1), "1\n2\n3\n4\n5\n6");
         assert_eq!(split_keeping_words("123456", 2), "12\n34\n56");
@@ -946,10 +1131,10 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_color_test() {
         #[cfg(feature = "color")]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "", false);
 
         #[cfg(not(feature = "color"))]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n", false);
 
         let text = "\u{1b}[36mJapanese “vacancy” button\u{1b}[0m";
 
@@ -963,10 +1148,10 @@ mod tests {
         use ansi_str::AnsiStr;
 
         #[cfg(feature = "color")]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "", false);
 
         #[cfg(not(feature = "color"))]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n", false);
 
         let text = "\u{1b}[37mTigre Ecuador   OMYA Andina     3824909999      Calcium carbonate       Colombia\u{1b}[0m";
 
@@ -1110,7 +1295,7 @@ mod tests {
     #[cfg(feature = "color")]
     #[test]
     fn split_by_line_keeping_words_color_3_test() {
-        let split = |text, width| split_keeping_words(text, width, "", "");
+        let split = |text, width| split_keeping_words(text, width, "", "", false);
         assert_eq!(
             split(
                 "\u{1b}[37m🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻🚵🏻\u{1b}[0m",
@@ -1139,46 +1324,49 @@ mod tests {
     #[cfg(not(feature = "color"))]
     #[test]
     fn split_keeping_words_4_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n", false);
 
-        assert_eq!(split_keeping_words("12345678", 3,), "123\n456\n78 ");
-        assert_eq!(split_keeping_words("12345678", 2,), "12\n34\n56\n78");
+        assert_eq!(split_keeping_words("12345678", 3), "123\n456\n78 ");
+        assert_eq!(split_keeping_words("12345678", 2), "12\n34\n56\n78");
     }
 
     #[cfg(feature = "color")]
     #[test]
     fn split_keeping_words_4_test() {
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "", "", false);
 
         #[cfg(not(feature = "color"))]
-        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n");
+        let split_keeping_words = |text, width| split_keeping_words(text, width, "\n", false);
 
-        assert_eq!(split_keeping_words("12345678", 3,), "123\n456\n78 ");
-        assert_eq!(split_keeping_words("12345678", 2,), "12\n34\n56\n78");
+        assert_eq!(split_keeping_words("12345678", 3), "123\n456\n78 ");
+        assert_eq!(split_keeping_words("12345678", 2), "12\n34\n56\n78");
     }
 
     #[cfg(feature = "color")]
     #[test]
     fn chunks_test_with_prefix_and_suffix() {
-        assert_eq!(chunks("123456", 0, "^", "$"), ["^$"; 0]);
+        assert_eq!(chunks("123456", 0, "^", "$", false), ["^$"; 0]);
 
         assert_eq!(
-            chunks("123456", 1, "^", "$"),
+            chunks("123456", 1, "^", "$", false),
             ["^1$", "^2$", "^3$", "^4$", "^5$", "^6$"]
         );
-        assert_eq!(chunks("123456", 2, "^", "$"), ["^12$", "^34$", "^56$"]);
-        assert_eq!(chunks("12345", 2, "^", "$"), ["^12$", "^34$", "^5$"]);
+        assert_eq!(
+            chunks("123456", 2, "^", "$", false),
+            ["^12$", "^34$", "^56$"]
+        );
+        assert_eq!(chunks("12345", 2, "^", "$", false), ["^12$", "^34$", "^5$"]);
 
         assert_eq!(
-            chunks("😳😳😳😳😳", 1, "^", "$"),
+            chunks("😳😳😳😳😳", 1, "^", "$", false),
             ["^�$", "^�$", "^�$", "^�$", "^�$"]
         );
         assert_eq!(
-            chunks("😳😳😳😳😳", 2, "^", "$"),
+            chunks("😳😳😳😳😳", 2, "^", "$", false),
             ["^😳$", "^😳$", "^😳$", "^😳$", "^😳$"]
         );
         assert_eq!(
-            chunks("😳😳😳😳😳", 3, "^", "$"),
+            chunks("😳😳😳😳😳", 3, "^", "$", false),
             ["^😳�$", "^😳�$", "^😳$"]
         );
     }
@@ -1187,20 +1375,20 @@ mod tests {
     #[test]
     fn split_by_line_keeping_words_test_with_prefix_and_suffix() {
         assert_eq!(
-            split_keeping_words("123456", 1, "^", "$"),
+            split_keeping_words("123456", 1, "^", "$", false),
             "^1$\n^2$\n^3$\n^4$\n^5$\n^6$"
         );
         assert_eq!(
-            split_keeping_words("123456", 2, "^", "$"),
+            split_keeping_words("123456", 2, "^", "$", false),
             "^12$\n^34$\n^56$"
         );
         assert_eq!(
-            split_keeping_words("12345", 2, "^", "$"),
+            split_keeping_words("12345", 2, "^", "$", false),
             "^12$\n^34$\n^5$ "
         );
 
         assert_eq!(
-            split_keeping_words("😳😳😳😳😳", 1, "^", "$"),
+            split_keeping_words("😳😳😳😳😳", 1, "^", "$", false),
             "^�$\n^�$\n^�$\n^�$\n^�$"
         );
     }
@@ -1213,7 +1401,7 @@ mod tests {
         let text = "\u{1b}[37mTigre Ecuador   OMYA Andina     3824909999      Calcium carbonate       Colombia\u{1b}[0m";
 
         assert_eq!(
-            split_keeping_words(text, 2, "^", "$")
+            split_keeping_words(text, 2, "^", "$", false)
                 .ansi_split("\n")
                 .collect::<Vec<_>>(),
             [
@@ -1261,7 +1449,7 @@ mod tests {
         );
 
         assert_eq!(
-            split_keeping_words(text, 1, "^", "$")
+            split_keeping_words(text, 1, "^", "$", false)
                 .ansi_split("\n")
                 .collect::<Vec<_>>(),
             [
@@ -1354,7 +1542,7 @@ mod tests {
     fn chunks_wrap_2() {
         let text = "\u{1b}[30mDebian\u{1b}[0m\u{1b}[31mDebian\u{1b}[0m\u{1b}[32mDebian\u{1b}[0m\u{1b}[33mDebian\u{1b}[0m\u{1b}[34mDebian\u{1b}[0m\u{1b}[35mDebian\u{1b}[0m\u{1b}[36mDebian\u{1b}[0m\u{1b}[37mDebian\u{1b}[0m\u{1b}[40mDebian\u{1b}[0m\u{1b}[41mDebian\u{1b}[0m\u{1b}[42mDebian\u{1b}[0m\u{1b}[43mDebian\u{1b}[0m\u{1b}[44mDebian\u{1b}[0m";
         assert_eq!(
-            chunks(text, 30, "", ""),
+            chunks(text, 30, "", "", false),
             [
                 "\u{1b}[30mDebian\u{1b}[39m\u{1b}[31mDebian\u{1b}[39m\u{1b}[32mDebian\u{1b}[39m\u{1b}[33mDebian\u{1b}[39m\u{1b}[34mDebian\u{1b}[39m",
                 "\u{1b}[35mDebian\u{1b}[39m\u{1b}[36mDebian\u{1b}[39m\u{1b}[37mDebian\u{1b}[39m\u{1b}[40mDebian\u{1b}[49m\u{1b}[41mDebian\u{1b}[49m",
@@ -1369,7 +1557,7 @@ mod tests {
         let text = "\u{1b}[37mCreate bytes from the \u{1b}[0m\u{1b}[7;34marg\u{1b}[0m\u{1b}[37muments.\u{1b}[0m";
 
         assert_eq!(
-            chunks(text, 22, "", ""),
+            chunks(text, 22, "", "", false),
             [
                 "\u{1b}[37mCreate bytes from the \u{1b}[39m",
                 "\u{1b}[7m\u{1b}[34marg\u{1b}[27m\u{1b}[39m\u{1b}[37muments.\u{1b}[39m"
@@ -1383,7 +1571,7 @@ mod tests {
         let text = "\u{1b}[37mCreate bytes from the \u{1b}[0m\u{1b}[7;34marg\u{1b}[0m\u{1b}[37muments.\u{1b}[0m";
 
         assert_eq!(
-            split_keeping_words(text, 22, "", ""),
+            split_keeping_words(text, 22, "", "", false),
             "\u{1b}[37mCreate bytes from the \u{1b}[39m\n\u{1b}[7m\u{1b}[34marg\u{1b}[27m\u{1b}[39m\u{1b}[37muments.\u{1b}[39m            "
         );
     }
@@ -1394,7 +1582,7 @@ mod tests {
         let text = "\u{1b}[37mReturns the floor of a number (l\u{1b}[0m\u{1b}[41;37marg\u{1b}[0m\u{1b}[37mest integer less than or equal to that number).\u{1b}[0m";
 
         assert_eq!(
-            chunks(text, 10, "", ""),
+            chunks(text, 10, "", "", false),
             [
                 "\u{1b}[37mReturns th\u{1b}[39m",
                 "\u{1b}[37me floor of\u{1b}[39m",
@@ -1414,7 +1602,7 @@ mod tests {
     fn chunks_wrap_4_keeping_words() {
         let text = "\u{1b}[37mReturns the floor of a number (l\u{1b}[0m\u{1b}[41;37marg\u{1b}[0m\u{1b}[37mest integer less than or equal to that number).\u{1b}[0m";
         assert_eq!(
-            split_keeping_words(text, 10, "", ""),
+            split_keeping_words(text, 10, "", "", false),
             concat!(
                 "\u{1b}[37mReturns \u{1b}[39m  \n",
                 "\u{1b}[37mthe floor \u{1b}[39m\n",
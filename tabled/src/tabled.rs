@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::Table;
+
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
 /// It's urgent that `header` len is equal to `fields` len.
@@ -107,7 +109,7 @@ macro_rules! default_table {
 
 default_table!(&str = borrowed);
 default_table!(str = borrowed);
-default_table!(String);
+default_table!(String = borrowed);
 
 default_table!(char);
 
@@ -148,3 +150,51 @@ where
         (0..N).map(|i| Cow::Owned(format!("{i}"))).collect()
     }
 }
+
+/// IntoTable is an extension trait which creates a [`Table`] from any iterator of [`Tabled`] items.
+///
+/// It's a shortcut for [`Table::new`], which is handy at the end of iterator chains.
+///
+/// Tuples are supported up to 6 elements, each of which must implement [`Tabled`].
+///
+/// # Example
+///
+/// ```
+/// use tabled::IntoTable;
+///
+/// let table = (1..4)
+///     .map(|i| (i, i * i))
+///     .filter(|(i, _)| i % 2 == 1)
+///     .into_table()
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-----+-----+\n\
+///      | i32 | i32 |\n\
+///      +-----+-----+\n\
+///      | 1   | 1   |\n\
+///      +-----+-----+\n\
+///      | 3   | 9   |\n\
+///      +-----+-----+"
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Table::new`]: crate::Table::new
+pub trait IntoTable {
+    /// Creates a [`Table`] from the items.
+    ///
+    /// [`Table`]: crate::Table
+    fn into_table(self) -> Table;
+}
+
+impl<I> IntoTable for I
+where
+    I: IntoIterator,
+    I::Item: Tabled,
+{
+    fn into_table(self) -> Table {
+        Table::new(self)
+    }
+}
@@ -0,0 +1,283 @@
+//! This module contains a [`Delimited`] exporter of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use crate::{
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    Table,
+};
+
+/// [`Delimited`] exports a [`Table`] data into a delimited text, like CSV or TSV.
+///
+/// Each record is terminated by a new line.
+///
+/// By default raw cell values are exported,
+/// while cells covered by a span are left blank.
+/// ANSI sequences are removed from the content before it's written.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{Table, tables::Delimited, settings::{Modify, Span}};
+///
+/// let mut table = Table::new([("Hello", "World")]);
+/// table.with(Modify::new((1, 0)).with(Span::column(2)));
+///
+/// let csv = Delimited::csv().repeat_spans(true).export(&table);
+///
+/// assert_eq!(csv, "&str,&str\nHello,Hello\n");
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Delimited {
+    delimiter: char,
+    quote: bool,
+    rendered: bool,
+    repeat_spans: bool,
+    line_separator: Option<String>,
+}
+
+impl Delimited {
+    /// Creates a CSV exporter.
+    ///
+    /// Fields which contain a delimiter, a quote or a new line are quoted.
+    pub const fn csv() -> Self {
+        Self {
+            delimiter: ',',
+            quote: true,
+            rendered: false,
+            repeat_spans: false,
+            line_separator: None,
+        }
+    }
+
+    /// Creates a TSV exporter.
+    ///
+    /// Tabs, new lines and backslashes inside fields are escaped as `\t`, `\n`, `\r` and `\\`,
+    /// while a custom delimiter is escaped by a backslash.
+    pub const fn tsv() -> Self {
+        Self {
+            delimiter: '\t',
+            quote: false,
+            rendered: false,
+            repeat_spans: false,
+            line_separator: None,
+        }
+    }
+
+    /// Sets a delimiter.
+    pub const fn delimiter(mut self, c: char) -> Self {
+        self.delimiter = c;
+        self
+    }
+
+    /// Sets whether a rendered text is exported instead of a raw value.
+    ///
+    /// A rendered text is a text as it's displayed in a table,
+    /// with leading and trailing spaces of each line trimmed, so a padding and an alignment are dropped.
+    pub const fn rendered(mut self, on: bool) -> Self {
+        self.rendered = on;
+        self
+    }
+
+    /// Sets whether cells covered by a span repeat its content instead of being blank.
+    pub const fn repeat_spans(mut self, on: bool) -> Self {
+        self.repeat_spans = on;
+        self
+    }
+
+    /// Sets a separator which is used to join lines of a multiline cell.
+    ///
+    /// By default lines are kept as they are, so new lines are quoted or escaped.
+    pub fn line_separator<S: Into<String>>(mut self, separator: S) -> Self {
+        self.line_separator = Some(separator.into());
+        self
+    }
+
+    /// Exports a table.
+    pub fn export(&self, table: &Table) -> String {
+        let mut grid = if self.rendered {
+            rendered_grid(table)
+        } else {
+            raw_grid(table)
+        };
+
+        let cfg = table.get_config();
+        let (count_rows, count_cols) = table.shape();
+        for (row, cells) in grid.iter_mut().enumerate() {
+            for (col, text) in cells.iter_mut().enumerate() {
+                if !cfg.is_cell_visible((row, col)) {
+                    text.clear();
+                }
+            }
+        }
+
+        if self.repeat_spans {
+            for row in 0..count_rows {
+                for col in 0..count_cols {
+                    if !cfg.is_cell_visible((row, col)) {
+                        continue;
+                    }
+
+                    let row_span = cfg.get_row_span((row, col)).unwrap_or(1);
+                    let col_span = cfg.get_column_span((row, col)).unwrap_or(1);
+                    let last_row = std::cmp::min(row + row_span, count_rows);
+                    let last_col = std::cmp::min(col + col_span, count_cols);
+
+                    for r in row..last_row {
+                        for c in col..last_col {
+                            if (r, c) != (row, col) {
+                                grid[r][c] = grid[row][col].clone();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut buf = String::new();
+        for row in grid {
+            for (col, text) in row.iter().enumerate() {
+                if col > 0 {
+                    buf.push(self.delimiter);
+                }
+
+                self.push_field(&mut buf, text);
+            }
+
+            buf.push('\n');
+        }
+
+        buf
+    }
+
+    fn push_field(&self, buf: &mut String, text: &str) {
+        if !self.quote {
+            match &self.line_separator {
+                Some(separator) => {
+                    for (i, line) in text.lines().enumerate() {
+                        if i > 0 {
+                            buf.push_str(separator);
+                        }
+
+                        self.push_escaped(buf, line);
+                    }
+                }
+                None => self.push_escaped(buf, text),
+            }
+
+            return;
+        }
+
+        if let Some(separator) = &self.line_separator {
+            let text = text.lines().collect::<Vec<_>>().join(separator);
+            self.push_quoted(buf, &text);
+        } else {
+            self.push_quoted(buf, text);
+        }
+    }
+
+    fn push_escaped(&self, buf: &mut String, text: &str) {
+        for c in text.chars() {
+            match c {
+                '\t' => buf.push_str("\\t"),
+                '\n' => buf.push_str("\\n"),
+                '\r' => buf.push_str("\\r"),
+                '\\' => buf.push_str("\\\\"),
+                c if c == self.delimiter => {
+                    buf.push('\\');
+                    buf.push(c);
+                }
+                c => buf.push(c),
+            }
+        }
+    }
+
+    fn push_quoted(&self, buf: &mut String, text: &str) {
+        let need_quotes = text
+            .chars()
+            .any(|c| c == self.delimiter || c == '"' || c == '\n' || c == '\r');
+        if !need_quotes {
+            buf.push_str(text);
+            return;
+        }
+
+        buf.push('"');
+        for c in text.chars() {
+            if c == '"' {
+                buf.push('"');
+            }
+
+            buf.push(c);
+        }
+        buf.push('"');
+    }
+}
+
+fn raw_grid(table: &Table) -> Vec<Vec<String>> {
+    let records = table.get_records();
+    (0..records.count_rows())
+        .map(|row| {
+            (0..records.count_columns())
+                .map(|col| strip_ansi(records.get_text((row, col))))
+                .collect()
+        })
+        .collect()
+}
+
+fn rendered_grid(table: &Table) -> Vec<Vec<String>> {
+    table
+        .to_grid()
+        .into_iter()
+        .map(|row| {
+            row.iter()
+                .map(|text| trim_cell(&strip_ansi(text)))
+                .collect()
+        })
+        .collect()
+}
+
+fn trim_cell(text: &str) -> String {
+    let lines = text.lines().map(str::trim).collect::<Vec<_>>();
+    lines.join("\n").trim_matches('\n').to_owned()
+}
+
+fn strip_ansi(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI sequence ends with a byte in a range 0x40..=0x7E
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('\u{40}'..='\u{7e}').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC sequence ends with BEL or ST (ESC \)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        let _ = chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    buf
+}
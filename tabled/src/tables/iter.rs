@@ -24,6 +24,34 @@
 //! );
 //! ```
 //!
+//! # Streaming
+//!
+//! Column widths must be known before a first row is printed.
+//! So by default [`IterTable`] buffers a number of rows (see [`IterTable::sniff`]) to measure them.
+//!
+//! If widths are known in advance they can be set via [`IterTable::width`] or [`IterTable::widths`],
+//! then rows are read and printed one by one in a single pass,
+//! which makes it possible to print data coming lazily e.g. from a database cursor.
+//!
+//! ```
+//! use tabled::tables::IterTable;
+//!
+//! let rows = (0..3).map(|i| vec![i.to_string(), format!("row {}", i)]);
+//!
+//! let table = IterTable::new(rows).widths([1, 5]).to_string();
+//!
+//! assert_eq!(
+//!     table,
+//!     "+---+-------+\n\
+//!      | 0 | row 0 |\n\
+//!      +---+-------+\n\
+//!      | 1 | row 1 |\n\
+//!      +---+-------+\n\
+//!      | 2 | row 2 |\n\
+//!      +---+-------+",
+//! );
+//! ```
+//!
 //! [`Table`]: crate::Table
 
 use std::{fmt, io};
@@ -65,6 +93,7 @@ struct Settings {
     count_columns: Option<usize>,
     count_rows: Option<usize>,
     width: Option<usize>,
+    widths: Option<Vec<usize>>,
     height: Option<usize>,
 }
 
@@ -83,6 +112,7 @@ impl<I> IterTable<I> {
                 count_rows: None,
                 height: None,
                 width: None,
+                widths: None,
             },
         }
     }
@@ -130,6 +160,22 @@ impl<I> IterTable<I> {
         self
     }
 
+    /// Set a width for each column separately.
+    ///
+    /// The number of columns is equal to the number of widths,
+    /// unless it's set via [`IterTable::columns`].
+    /// A missing width is considered to be 0.
+    ///
+    /// The records are not buffered in such case,
+    /// so the table is built in a single pass.
+    pub fn widths<W>(mut self, widths: W) -> Self
+    where
+        W: IntoIterator<Item = usize>,
+    {
+        self.table.widths = Some(widths.into_iter().collect());
+        self
+    }
+
     /// Build a string.
     ///
     /// We can't implement [`std::string::ToString`] cause it does takes `&self` reference.
@@ -172,7 +218,9 @@ fn build_grid<W: fmt::Write, I: IntoRecords>(
     opts: Settings,
 ) -> fmt::Result {
     let dont_sniff = opts.width.is_some() && opts.count_columns.is_some();
-    if dont_sniff {
+    if opts.widths.is_some() {
+        build_table_with_static_widths(f, iter, cfg, opts)
+    } else if dont_sniff {
         build_table_with_static_dims(f, iter, cfg, opts)
     } else if opts.width.is_none() {
         build_table_sniffing_with_unknown_width(f, iter, cfg, opts)
@@ -214,6 +262,45 @@ where
     }
 }
 
+fn build_table_with_static_widths<W, I>(
+    f: W,
+    iter: I,
+    cfg: CompactConfig,
+    opts: Settings,
+) -> fmt::Result
+where
+    W: fmt::Write,
+    I: IntoRecords,
+{
+    let mut widths = opts.widths.unwrap();
+    let count_columns = opts.count_columns.unwrap_or(widths.len());
+    widths.resize(count_columns, 0);
+
+    let height = opts.height.unwrap_or(1);
+    let pad = cfg.get_padding();
+    let w = widths
+        .iter()
+        .map(|w| w + pad.left.size + pad.right.size)
+        .collect();
+    let w = DimensionValue::List(w);
+    let h = DimensionValue::Exact(height + pad.top.size + pad.bottom.size);
+    let dims = StaticDimension::new(w, h);
+    let contentw = ExactValue::List(widths.into());
+    let cfg = SpannedConfig::from(cfg);
+
+    match opts.count_rows {
+        Some(limit) => {
+            let records = LimitRows::new(iter, limit);
+            let records = build_records(records, contentw, count_columns, Some(limit));
+            Grid::new(records, dims, cfg, NoColors).build(f)
+        }
+        None => {
+            let records = build_records(iter, contentw, count_columns, None);
+            Grid::new(records, dims, cfg, NoColors).build(f)
+        }
+    }
+}
+
 fn build_table_sniffing_with_unknown_width<W, I>(
     f: W,
     iter: I,
@@ -20,6 +20,8 @@
 mod compact;
 mod util;
 
+#[cfg(feature = "std")]
+mod delimited;
 #[cfg(feature = "std")]
 mod extended;
 #[cfg(feature = "std")]
@@ -41,6 +43,10 @@ pub use iter::IterTable;
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use extended::ExtendedTable;
 
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use delimited::Delimited;
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use table_pool::{PoolTable, TableValue};
@@ -8,17 +8,26 @@ use crate::{
     grid::{
         colors::NoColors,
         config::{
-            AlignmentHorizontal, ColorMap, ColoredConfig, CompactConfig, Entity, Formatting,
-            Indent, Sides, SpannedConfig,
+            AlignmentHorizontal, AlignmentVertical, Border, ColorMap, ColoredConfig, CompactConfig,
+            Entity, Formatting, Indent, Position, Sides, SpannedConfig,
+        },
+        dimension::{
+            dimension_reastimate, CompleteDimensionVecRecords, Dimension, Estimate,
+            PeekableDimension,
         },
-        dimension::{CompleteDimensionVecRecords, Dimension, Estimate, PeekableDimension},
         records::{
             vec_records::{CellInfo, VecRecords},
-            ExactRecords, Records,
+            ExactRecords, PeekableRecords, Records, RecordsMut, Resizable,
         },
+        util::string::{string_width_ambiguous, string_width_multiline_ambiguous},
         PeekableGrid,
     },
-    settings::{object::Object, CellOption, Style, TableOption},
+    settings::{
+        object::Object,
+        style::{BorderSpanCorrection, HorizontalLine},
+        CellOption, Color, Style, TableOption,
+    },
+    tables::Delimited,
     Tabled,
 };
 
@@ -52,14 +61,49 @@ use crate::{
 /// println!("{}", table);
 /// ```
 ///
+/// ## Formatting
+///
+/// A width and an alignment of a formatter are respected.
+/// The alignment is set to every cell,
+/// and if a width is bigger than the table it's extended by a margin filled with a fill char.
+///
+/// ```
+/// use tabled::Table;
+///
+/// let table = Table::new(["Hello"]);
+/// let output = format!("{:^13}", table);
+///
+/// assert_eq!(
+///     output,
+///     concat!(
+///         "  +-------+  \n",
+///         "  | &str  |  \n",
+///         "  +-------+  \n",
+///         "  | Hello |  \n",
+///         "  +-------+  ",
+///     )
+/// );
+/// ```
+///
+/// ## Determinism
+///
+/// Rendering is deterministic,
+/// the same table with the same settings is always rendered the same way,
+/// so the output can be safely used in snapshot tests.
+///
+/// Notice that [`Builder::from_maps`] keeps keys in the order they're met,
+/// so use [`Builder::from_sorted_maps`] for maps with no stable order like [`HashMap`].
+///
 /// [`Padding`]: crate::settings::Padding
 /// [`Style`]: crate::settings::Style
 /// [`Style::ascii`]: crate::settings::Style::ascii
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Table {
-    records: VecRecords<CellInfo<String>>,
+/// [`HashMap`]: std::collections::HashMap
+#[derive(Debug, PartialEq, Eq)]
+pub struct Table<S = String> {
+    records: VecRecords<CellInfo<S>>,
     config: ColoredConfig,
     dimension: CompleteDimensionVecRecords<'static>,
+    color_enabled: bool,
 }
 
 impl Table {
@@ -98,6 +142,7 @@ impl Table {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: true,
         }
     }
 
@@ -171,17 +216,71 @@ impl Table {
 
         b
     }
+}
+
+impl<'a> Table<Cow<'a, str>> {
+    /// Creates a Table instance which borrows its content from a data set given.
+    ///
+    /// Unlike [`Table::new`] fields are not copied,
+    /// so a text of fields which are borrowed by [`Tabled`], like `&str` and `String`,
+    /// is not allocated again.
+    /// A cell is copied only when it's changed by a setting.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let data = vec![(String::from("Hello"), "World")];
+    ///
+    /// let mut table = Table::new_borrowed(&data);
+    /// table.with(Style::psql());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     " String | &str  \n\
+    ///      --------+-------\n \
+    ///       Hello  | World "
+    /// );
+    /// ```
+    pub fn new_borrowed<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: Tabled + 'a,
+    {
+        let mut header = Vec::with_capacity(T::LENGTH);
+        for text in T::headers() {
+            header.push(CellInfo::new(text));
+        }
+
+        let mut records = vec![header];
+        for row in iter.into_iter() {
+            let mut list = Vec::with_capacity(T::LENGTH);
+            for text in row.fields().into_iter() {
+                list.push(CellInfo::new(text));
+            }
+
+            records.push(list);
+        }
+
+        Self {
+            records: VecRecords::new(records),
+            config: ColoredConfig::new(configure_grid()),
+            dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: true,
+        }
+    }
+}
 
+impl<S> Table<S>
+where
+    S: AsRef<str>,
+{
     /// It's a generic function which applies options to the [`Table`].
     ///
     /// It applies settings immediately.
     pub fn with<O>(&mut self, option: O) -> &mut Self
     where
-        for<'a> O: TableOption<
-            VecRecords<CellInfo<String>>,
-            CompleteDimensionVecRecords<'a>,
-            ColoredConfig,
-        >,
+        for<'a> O:
+            TableOption<VecRecords<CellInfo<S>>, CompleteDimensionVecRecords<'a>, ColoredConfig>,
     {
         let reastimation_hint = option.hint_change();
         let mut dims = self.dimension.from_origin();
@@ -204,17 +303,97 @@ impl Table {
     /// [`Location`]: crate::settings::location::Locator
     pub fn modify<T, O>(&mut self, target: T, option: O) -> &mut Self
     where
-        T: Object<VecRecords<CellInfo<String>>>,
-        O: CellOption<VecRecords<CellInfo<String>>, ColoredConfig> + Clone,
+        T: Object<VecRecords<CellInfo<S>>>,
+        O: CellOption<VecRecords<CellInfo<S>>, ColoredConfig> + Clone,
+    {
+        let reastimation_hint = option.hint_change();
+
+        let entities = target.cells(&self.records).collect::<Vec<_>>();
+        option.change_region(&mut self.records, &mut self.config, &entities);
+
+        dimension_reastimate_likely(&mut self.dimension, reastimation_hint);
+
+        self
+    }
+
+    /// Applies options to a particalar cells on the [`Table`] like [`Table::modify`] does,
+    /// but returns the target back as an error if it points to no cells.
+    ///
+    /// It's handy for targets which are resolved against the table content,
+    /// e.g. a column looked up by its name.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{object::Columns, Alignment}};
+    ///
+    /// let mut table = Table::new([("Apple", 120)]);
+    ///
+    /// assert!(table.try_modify(Columns::by_name("i32"), Alignment::right()).is_ok());
+    /// assert!(table.try_modify(Columns::by_name("price"), Alignment::right()).is_err());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+-----+\n\
+    ///      | &str  | i32 |\n\
+    ///      +-------+-----+\n\
+    ///      | Apple | 120 |\n\
+    ///      +-------+-----+"
+    /// );
+    /// ```
+    pub fn try_modify<T, O>(&mut self, target: T, option: O) -> Result<&mut Self, T>
+    where
+        T: Object<VecRecords<CellInfo<S>>>,
+        O: CellOption<VecRecords<CellInfo<S>>, ColoredConfig> + Clone,
     {
-        for entity in target.cells(&self.records) {
-            let opt = option.clone();
-            opt.change(&mut self.records, &mut self.config, entity);
+        let entities = target.cells(&self.records).collect::<Vec<_>>();
+        if entities.is_empty() {
+            return Err(target);
         }
 
         let reastimation_hint = option.hint_change();
+        option.change_region(&mut self.records, &mut self.config, &entities);
+
         dimension_reastimate_likely(&mut self.dimension, reastimation_hint);
 
+        Ok(self)
+    }
+
+    /// Sets a border of a cell at a given `(row, column)` position.
+    ///
+    /// It's the same as using a [`Border`] via [`Table::modify`] but without an object.
+    ///
+    /// Cells share their borders, so a right side of a cell is a left side of the next one,
+    /// and a bottom of a cell is a top of the one below it.
+    /// Corners are intersections of lines, which are shared by up to 4 cells.
+    /// A side which is set to `None` keeps a character set before.
+    ///
+    /// The position is not checked, so setting a border out of the table has no effect.
+    ///
+    /// ```
+    /// use tabled::{Table, grid::config::Border, settings::Style};
+    ///
+    /// let mut table = Table::new([["a", "b"], ["c", "d"]]);
+    /// table.with(Style::empty());
+    ///
+    /// let mut border = Border::empty();
+    /// border.left = Some('|');
+    /// border.bottom = Some('_');
+    /// border.left_bottom_corner = Some('+');
+    /// table.set_cell_border((2, 0), border);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "  0  1 \n",
+    ///         "  a  b \n",
+    ///         "| c  d \n",
+    ///         "+___   ",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Border`]: crate::settings::Border
+    pub fn set_cell_border(&mut self, pos: Position, border: Border<char>) -> &mut Self {
+        self.config.set_border(pos, border);
         self
     }
 
@@ -250,8 +429,50 @@ impl Table {
         let counth = self.config.count_horizontal(self.count_rows());
 
         let margin = self.config.get_margin();
+        let (_, caption_height) = captions_dimension(&self.config);
+
+        total + counth + margin.top.size + margin.bottom.size + caption_height
+    }
+
+    /// Renders a table, passing each rendered line through a given function.
+    ///
+    /// The function gets an index of a line and the line as it is printed,
+    /// so it sees all the styling including ANSI sequences.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let mut table = Table::new([("Hello", "World")]);
+    /// table.with(Style::markdown());
+    ///
+    /// let output = table.map_lines(|i, line| format!("{i}: {line}"));
+    ///
+    /// assert_eq!(
+    ///     output,
+    ///     "0: | &str  | &str  |\n\
+    ///      1: |-------|-------|\n\
+    ///      2: | Hello | World |"
+    /// );
+    /// ```
+    pub fn map_lines<F>(&self, mut f: F) -> String
+    where
+        F: FnMut(usize, &str) -> String,
+    {
+        let table = self.to_string();
+        if table.is_empty() {
+            return table;
+        }
+
+        let mut buf = String::with_capacity(table.len());
+        for (i, line) in table.split('\n').enumerate() {
+            if i > 0 {
+                buf.push('\n');
+            }
+
+            buf.push_str(&f(i, line));
+        }
 
-        total + counth + margin.top.size + margin.bottom.size
+        buf
     }
 
     /// Returns total widths of a table, including margin and vertical lines.
@@ -265,8 +486,152 @@ impl Table {
         let countv = self.config.count_vertical(self.count_columns());
 
         let margin = self.config.get_margin();
+        let (caption_width, _) = captions_dimension(&self.config);
+
+        std::cmp::max(
+            total + countv + margin.left.size + margin.right.size,
+            caption_width,
+        )
+    }
+
+    /// Returns a table shape together with its total width and height,
+    /// as (count rows, count columns, total width, total height).
+    ///
+    /// The dimensions are calculated in a single pass without rendering the table,
+    /// and they match the output of the rendered table.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Margin, Style}};
+    ///
+    /// let mut table = Table::new([("Hello", "World\n!")]);
+    /// table.with(Style::modern()).with(Margin::new(1, 1, 0, 0));
+    ///
+    /// assert_eq!(table.measure(), (2, 2, 19, 6));
+    /// ```
+    pub fn measure(&self) -> (usize, usize, usize, usize) {
+        let (count_rows, count_cols) = self.shape();
+        if self.is_empty() {
+            return (count_rows, count_cols, 0, 0);
+        }
+
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        let margin = self.config.get_margin();
+
+        let width = (0..count_cols)
+            .map(|col| dims.get_width(col))
+            .sum::<usize>()
+            + self.config.count_vertical(count_cols)
+            + margin.left.size
+            + margin.right.size;
+
+        let height = (0..count_rows)
+            .map(|row| dims.get_height(row))
+            .sum::<usize>()
+            + self.config.count_horizontal(count_rows)
+            + margin.top.size
+            + margin.bottom.size;
+
+        let (caption_width, caption_height) = captions_dimension(&self.config);
+        let width = std::cmp::max(width, caption_width);
+        let height = height + caption_height;
+
+        (count_rows, count_cols, width, height)
+    }
+
+    /// Returns widths of columns, including padding.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Padding};
+    ///
+    /// let mut table = Table::new([("Hello", "World\n!")]);
+    /// table.with(Padding::new(2, 2, 0, 0));
+    ///
+    /// assert_eq!(table.column_widths(), [9, 9]);
+    /// assert_eq!(table.content_widths(), [5, 5]);
+    /// ```
+    pub fn column_widths(&self) -> Vec<usize> {
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        (0..self.count_columns())
+            .map(|col| dims.get_width(col))
+            .collect()
+    }
+
+    /// Returns widths of columns content, excluding padding and borders.
+    ///
+    /// A width of a multiline cell is a width of its widest line.
+    /// Cells which span several columns are not taken into account.
+    pub fn content_widths(&self) -> Vec<usize> {
+        let (count_rows, count_cols) = self.shape();
+
+        (0..count_cols)
+            .map(|col| {
+                (0..count_rows)
+                    .filter(|&row| self.config.is_cell_visible((row, col)))
+                    .filter(|&row| self.config.get_column_span((row, col)).is_none())
+                    .map(|row| get_cell_width(&self.records, &self.config, (row, col)))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// Returns a width and a height of a cell content including its padding.
+    ///
+    /// The content is measured as it is, so changes made by settings like wrapping are counted.
+    /// The size doesn't include a stretch made by other cells of the same column or row,
+    /// use [`Table::column_widths`] for it.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Modify, Width, object::Cell}};
+    ///
+    /// let mut table = Table::new([["Hello World"]]);
+    /// table.with(Modify::new(Cell::new(1, 0)).with(Width::wrap(7)));
+    ///
+    /// assert_eq!(table.cell_dimension((1, 0)), (9, 2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a position is out of bounds of the table.
+    pub fn cell_dimension(&self, pos: Position) -> (usize, usize) {
+        let padding = self.config.get_padding(pos.into());
+
+        let width = get_cell_width(&self.records, &self.config, pos);
+        let width = width + padding.left.size + padding.right.size;
+        let height = self.records.count_lines(pos) + padding.top.size + padding.bottom.size;
+
+        (width, height)
+    }
+
+    /// Turns colors of the table on or off.
+    ///
+    /// When colors are off the table is rendered without any color settings,
+    /// though they're kept in the config, so they can be turned on later.
+    /// It's handy to have the same table colored in a terminal and plain in snapshot tests.
+    ///
+    /// Colors which are a part of a cell content are not affected.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Color, Style}};
+    ///
+    /// let mut table = Table::new([["Hello"]]);
+    /// table.with(Style::psql()).with(Color::FG_RED);
+    /// table.set_color_enabled(false);
+    ///
+    /// assert_eq!(table.to_string(), " 0     \n-------\n Hello ");
+    /// ```
+    pub fn set_color_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.color_enabled = enabled;
+        self
+    }
 
-        total + countv + margin.left.size + margin.right.size
+    /// Returns whether colors of the table are on.
+    pub fn is_color_enabled(&self) -> bool {
+        self.color_enabled
     }
 
     /// Returns a table config.
@@ -280,14 +645,709 @@ impl Table {
     }
 
     /// Returns a used records.
-    pub fn get_records(&self) -> &VecRecords<CellInfo<String>> {
+    pub fn get_records(&self) -> &VecRecords<CellInfo<S>> {
         &self.records
     }
 
     /// Returns a used records.
-    pub fn get_records_mut(&mut self) -> &mut VecRecords<CellInfo<String>> {
+    pub fn get_records_mut(&mut self) -> &mut VecRecords<CellInfo<S>> {
         &mut self.records
     }
+
+    /// Returns an iterator over a cells content with their positions.
+    ///
+    /// It reflects the current state of the records,
+    /// meaning all applied settings which changed the content are taken into account.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Hello", 1)]);
+    /// let cells: Vec<_> = table.cells().collect();
+    ///
+    /// assert_eq!(
+    ///     cells,
+    ///     [((0, 0), "&str"), ((0, 1), "i32"), ((1, 0), "Hello"), ((1, 1), "1")]
+    /// );
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &str)> {
+        self.records.iter().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| ((row, col), cell.as_ref()))
+        })
+    }
+}
+
+impl Table {
+    /// Sets a content of a cell.
+    ///
+    /// If the position is out of the table bounds the text is returned back as an error.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let mut table = Table::new([("Hello", 1)]);
+    /// table.set((1, 1), "2023").unwrap();
+    ///
+    /// assert!(table.set((2, 0), "World").is_err());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+-------+------+\n\
+    ///      | &str  | i32  |\n\
+    ///      +-------+------+\n\
+    ///      | Hello | 2023 |\n\
+    ///      +-------+------+"
+    /// );
+    /// ```
+    pub fn set<S>(&mut self, pos: Position, text: S) -> Result<&mut Self, S>
+    where
+        S: Into<String>,
+    {
+        let (count_rows, count_cols) = self.shape();
+        if pos.0 >= count_rows || pos.1 >= count_cols {
+            return Err(text);
+        }
+
+        self.records.set(pos, text.into());
+        dimension_reastimate_likely(&mut self.dimension, Some(Entity::Cell(pos.0, pos.1)));
+
+        Ok(self)
+    }
+
+    /// Removes a header, the first row of the table, together with a line which separates it.
+    ///
+    /// Settings of the following rows (spans, alignment, padding, colors, borders and lines)
+    /// are shifted together with them, so the first data row becomes the top one.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let mut table = Table::new([("Hello", 1), ("World", 2)]);
+    /// table.with(Style::psql()).remove_header();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         " Hello | 1 \n",
+    ///         " World | 2 ",
+    ///     )
+    /// );
+    /// ```
+    pub fn remove_header(&mut self) -> &mut Self {
+        let count_rows = self.count_rows();
+        if count_rows == 0 {
+            return self;
+        }
+
+        self.config.remove_row(0);
+        self.records.remove_row(0);
+        dimension_reastimate_likely(&mut self.dimension, Some(Entity::Global));
+
+        self
+    }
+
+    /// Keeps only the first `n` rows of the table,
+    /// appending a row of `…` if any rows were dropped.
+    ///
+    /// A header is counted as a row.
+    /// Spans and colors of the dropped rows are dropped as well.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let mut table = Table::new([1, 2, 3, 4, 5]);
+    /// table.with(Style::markdown()).head(3);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "| i32 |\n\
+    ///      |-----|\n\
+    ///      | 1   |\n\
+    ///      | 2   |\n\
+    ///      | …   |"
+    /// );
+    /// ```
+    pub fn head(&mut self, n: usize) -> &mut Self {
+        self.truncate_rows(n, false)
+    }
+
+    /// Keeps only the first `n` rows of the table, like [`Table::head`] does,
+    /// while the `…` row also shows a total amount of data rows.
+    ///
+    /// The first row is considered to be a header, so it's not counted.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let mut table = Table::new([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// table.with(Style::markdown()).head_with_total(2);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "| i32        | char |\n\
+    ///      |------------|------|\n\
+    ///      | 1          | a    |\n\
+    ///      | … (3 rows) | …    |"
+    /// );
+    /// ```
+    pub fn head_with_total(&mut self, n: usize) -> &mut Self {
+        self.truncate_rows(n, true)
+    }
+
+    fn truncate_rows(&mut self, n: usize, show_total: bool) -> &mut Self {
+        let (count_rows, count_cols) = self.shape();
+        if count_rows <= n {
+            return self;
+        }
+
+        let records = std::mem::replace(&mut self.records, VecRecords::new(Vec::new()));
+        let mut data: Vec<Vec<CellInfo<String>>> = records.into();
+        data.truncate(n);
+
+        let mut ellipsis = vec![CellInfo::new(String::from("…")); count_cols];
+        if show_total && count_cols > 0 {
+            ellipsis[0] = CellInfo::new(format!("… ({} rows)", count_rows - 1));
+        }
+
+        data.push(ellipsis);
+
+        self.records = VecRecords::new(data);
+        self.config.truncate_rows(n);
+        dimension_reastimate_likely(&mut self.dimension, Some(Entity::Global));
+
+        self
+    }
+
+    /// Splits the table into a list of tables by columns,
+    /// so each of them fits into a given width if it's possible.
+    ///
+    /// If `repeat_first` is set the first column is considered to be a key,
+    /// so it's repeated in each of the tables.
+    /// Columns pinned by [`Columns::pin`] are considered to be keys as well,
+    /// they are moved in front of the rest of the columns.
+    ///
+    /// Each table gets at least one column besides the key one,
+    /// therefore a table may be wider than the given width if a single column doesn't fit.
+    ///
+    /// Settings set to particular columns are moved together with the columns,
+    /// while column spans are cut to the columns of a table they end up in.
+    ///
+    /// [`Columns::pin`]: crate::settings::object::Columns::pin
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Style};
+    ///
+    /// let data = [("Hello", "World", "!!!")];
+    /// let mut table = Table::new(data);
+    /// table.with(Style::psql());
+    ///
+    /// let tables = table.split_columns(15, true);
+    ///
+    /// assert_eq!(tables.len(), 2);
+    /// assert_eq!(
+    ///     tables[0].to_string(),
+    ///     " &str  | &str  \n\
+    ///      -------+-------\n \
+    ///       Hello | World "
+    /// );
+    /// assert_eq!(
+    ///     tables[1].to_string(),
+    ///     " &str  | &str \n\
+    ///      -------+------\n \
+    ///       Hello | !!!  "
+    /// );
+    /// ```
+    pub fn split_columns(&self, max_width: usize, repeat_first: bool) -> Vec<Table> {
+        let count_cols = self.count_columns();
+        let mut keys = Vec::new();
+        if repeat_first {
+            keys.push(0);
+        }
+        for &col in self.config.get_pinned_columns() {
+            if col < count_cols && !keys.contains(&col) {
+                keys.push(col);
+            }
+        }
+
+        if count_cols <= keys.len() {
+            return vec![self.clone()];
+        }
+
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        let margin = self.config.get_margin();
+        let margin = margin.left.size + margin.right.size;
+        let keys_width = margin + keys.iter().map(|&col| dims.get_width(col)).sum::<usize>();
+
+        let mut chunks = Vec::new();
+        let mut chunk = keys.to_vec();
+        let mut chunk_width = keys_width;
+        for col in (0..count_cols).filter(|col| !keys.contains(col)) {
+            let width = dims.get_width(col);
+            let total = chunk_width + width + self.config.count_vertical(chunk.len() + 1);
+
+            if total > max_width && chunk.len() > keys.len() {
+                chunks.push(chunk);
+                chunk = keys.to_vec();
+                chunk_width = keys_width;
+            }
+
+            chunk.push(col);
+            chunk_width += width;
+        }
+
+        if chunk.len() > keys.len() {
+            chunks.push(chunk);
+        }
+
+        chunks
+            .into_iter()
+            .map(|columns| self.copy_columns(&columns))
+            .collect()
+    }
+
+    fn copy_columns(&self, columns: &[usize]) -> Table {
+        let records = self
+            .records
+            .iter()
+            .map(|row| columns.iter().map(|&col| row[col].clone()).collect())
+            .collect();
+
+        let mut config = self.config.clone();
+        config.select_columns(columns);
+
+        Table {
+            records: VecRecords::new(records),
+            config,
+            dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: self.color_enabled,
+        }
+    }
+
+    /// Returns a table which consists only of a header, the first row of the table.
+    ///
+    /// It's handy to preview a schema of a large data set.
+    /// A style and other settings are kept.
+    ///
+    /// If `data_widths` is set, columns widths are derived from the whole table,
+    /// otherwise they're derived from the header alone and data rows are not looked at.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = [["Hello", "World"], ["123", "456"]];
+    /// let table = Table::new(data);
+    ///
+    /// assert_eq!(
+    ///     table.header_only(false).to_string(),
+    ///     "+---+---+\n\
+    ///      | 0 | 1 |\n\
+    ///      +---+---+"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     table.header_only(true).to_string(),
+    ///     "+-------+-------+\n\
+    ///      | 0     | 1     |\n\
+    ///      +-------+-------+"
+    /// );
+    /// ```
+    pub fn header_only(&self, data_widths: bool) -> Table {
+        let records = self.records.iter().take(1).cloned().collect::<Vec<_>>();
+        let records = VecRecords::new(records);
+
+        let mut dimension = CompleteDimensionVecRecords::default();
+        if data_widths {
+            dimension.set_widths(self.column_widths());
+        }
+
+        if let Some(&height) = self.dimension.get_heights().and_then(|list| list.first()) {
+            dimension.set_heights(vec![height]);
+        }
+
+        Self {
+            records,
+            config: self.config.clone(),
+            dimension,
+            color_enabled: self.color_enabled,
+        }
+    }
+
+    /// Returns cells as they're rendered, without borders and margin.
+    ///
+    /// Each cell includes its padding, alignment and changes made by settings,
+    /// like wrapping or truncation. Lines of a cell are joined by `\n`.
+    ///
+    /// A spanned cell holds a whole content of a span, while the cells it covers are empty.
+    ///
+    /// Colors set by settings are not applied, while colors which are a part of a content are kept.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::Padding};
+    ///
+    /// let mut table = Table::new([["Hello", "World"]]);
+    /// table.with(Padding::new(1, 2, 0, 0));
+    ///
+    /// assert_eq!(
+    ///     table.to_grid(),
+    ///     vec![
+    ///         vec![String::from(" 0      "), String::from(" 1      ")],
+    ///         vec![String::from(" Hello  "), String::from(" World  ")],
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_grid(&self) -> Vec<Vec<String>> {
+        let (count_rows, count_cols) = self.shape();
+        if count_rows == 0 || count_cols == 0 {
+            return Vec::new();
+        }
+
+        let mut dims = CompleteDimensionVecRecords::from_origin(&self.dimension);
+        dims.estimate(&self.records, self.config.as_ref());
+
+        let cfg = &self.config;
+        let mut grid = vec![vec![String::new(); count_cols]; count_rows];
+        for (row, cells) in grid.iter_mut().enumerate() {
+            for (col, cell) in cells.iter_mut().enumerate() {
+                if !cfg.is_cell_visible((row, col)) {
+                    continue;
+                }
+
+                let col_span = cfg.get_column_span((row, col)).unwrap_or(1);
+                let row_span = cfg.get_row_span((row, col)).unwrap_or(1);
+                let last_col = std::cmp::min(col + col_span, count_cols);
+                let last_row = std::cmp::min(row + row_span, count_rows);
+
+                let width = (col..last_col)
+                    .map(|col| dims.get_width(col))
+                    .sum::<usize>()
+                    + (col + 1..last_col)
+                        .map(|col| cfg.get_vertical_width(col, count_cols))
+                        .sum::<usize>();
+                let height = (row..last_row)
+                    .map(|row| dims.get_height(row))
+                    .sum::<usize>()
+                    + (row + 1..last_row)
+                        .filter(|&row| cfg.has_horizontal(row, count_rows))
+                        .count();
+
+                *cell = format_cell(&self.records, cfg, (row, col), width, height);
+            }
+        }
+
+        grid
+    }
+
+    /// Compares the table with another one,
+    /// returning a copy of the table where differences are colored.
+    ///
+    /// Cells are matched by their position.
+    /// A cell which is different from the one in `other` is colored yellow,
+    /// a cell which is not present in `other` is colored green.
+    /// Cells which are present only in `other` are added with its content and colored red.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let old = Table::new([("Hello", "World")]);
+    /// let new = Table::new([("Hello", "Rust")]);
+    ///
+    /// let diff = new.diff(&old);
+    ///
+    /// assert_eq!(
+    ///     diff.to_string(),
+    ///     "+-------+------+\n\
+    ///      | &str  | &str |\n\
+    ///      +-------+------+\n\
+    ///      | Hello | \u{1b}[33mRust\u{1b}[39m |\n\
+    ///      +-------+------+"
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Table) -> Table {
+        let (count_rows, count_cols) = self.shape();
+        let (other_rows, other_cols) = other.shape();
+
+        let rows = std::cmp::max(count_rows, other_rows);
+        let cols = std::cmp::max(count_cols, other_cols);
+        let has_cell = |table: &Table, (row, col): Position| {
+            let (count_rows, count_cols) = table.shape();
+            row < count_rows && col < count_cols
+        };
+
+        let mut config = self.config.clone();
+        let mut records = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let mut cells = Vec::with_capacity(cols);
+            for col in 0..cols {
+                let pos = (row, col);
+                let (cell, color) = match (has_cell(self, pos), has_cell(other, pos)) {
+                    (true, true) => {
+                        let cell = &self.records[row][col];
+                        let is_changed = cell.as_ref() != other.records[row][col].as_ref();
+                        (cell.clone(), is_changed.then(|| Color::FG_YELLOW))
+                    }
+                    (true, false) => (self.records[row][col].clone(), Some(Color::FG_GREEN)),
+                    (false, true) => (other.records[row][col].clone(), Some(Color::FG_RED)),
+                    (false, false) => (CellInfo::new(String::new()), None),
+                };
+
+                if let Some(color) = color {
+                    let _ = config.set_color(Entity::Cell(row, col), color.into());
+                }
+
+                cells.push(cell);
+            }
+
+            records.push(cells);
+        }
+
+        Table {
+            records: VecRecords::new(records),
+            config,
+            dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: self.color_enabled,
+        }
+    }
+
+    /// Exports raw data of the table as CSV.
+    ///
+    /// Cells covered by a span are left blank,
+    /// see [`Delimited`] to change it or to export a rendered text.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Hello, World", "\"quoted\"")]);
+    ///
+    /// assert_eq!(table.to_csv(), "&str,&str\n\"Hello, World\",\"\"\"quoted\"\"\"\n");
+    /// ```
+    ///
+    /// [`Delimited`]: crate::tables::Delimited
+    pub fn to_csv(&self) -> String {
+        Delimited::csv().export(self)
+    }
+
+    /// Exports raw data of the table as TSV.
+    ///
+    /// Cells covered by a span are left blank,
+    /// see [`Delimited`] to change it or to export a rendered text.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Hello", "World\n!")]);
+    ///
+    /// assert_eq!(table.to_tsv(), "&str\t&str\nHello\tWorld\\n!\n");
+    /// ```
+    ///
+    /// [`Delimited`]: crate::tables::Delimited
+    pub fn to_tsv(&self) -> String {
+        Delimited::tsv().export(self)
+    }
+
+    /// Renders the table as a reStructuredText grid table.
+    ///
+    /// The first row is considered to be a header, so it's underlined by `=`.
+    /// Multiline cells and spans are supported by grid tables as they are.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new([("Hello", "World\n!")]);
+    ///
+    /// assert_eq!(
+    ///     table.to_rst(),
+    ///     "+-------+-------+\n\
+    ///      | &str  | &str  |\n\
+    ///      +=======+=======+\n\
+    ///      | Hello | World |\n\
+    ///      |       | !     |\n\
+    ///      +-------+-------+"
+    /// );
+    /// ```
+    pub fn to_rst(&self) -> String {
+        let mut table = self.clone();
+        if self.count_rows() > 1 {
+            let header = HorizontalLine::full('=', '+', '+', '+');
+            let _ = table.with(Style::ascii().horizontals([(1, header)]));
+        } else {
+            let _ = table.with(Style::ascii());
+        }
+
+        let _ = table.with(BorderSpanCorrection);
+
+        table.to_string()
+    }
+
+    /// Renders the table as an Org-mode table.
+    ///
+    /// The first row is considered to be a header, so it's followed by a separator line.
+    ///
+    /// If any column is not aligned to the left,
+    /// a row of alignment cookies (`<l>`, `<c>`, `<r>`) is put on top of the table.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Alignment, Modify, object::Columns}};
+    ///
+    /// let mut table = Table::new([("Apple", 120), ("Banana", 7)]);
+    /// table.with(Modify::new(Columns::last()).with(Alignment::right()));
+    ///
+    /// assert_eq!(
+    ///     table.to_org(),
+    ///     "| <l>    | <r> |\n\
+    ///      | &str   | i32 |\n\
+    ///      |--------+-----|\n\
+    ///      | Apple  | 120 |\n\
+    ///      | Banana |   7 |"
+    /// );
+    /// ```
+    pub fn to_org(&self) -> String {
+        let (count_rows, count_cols) = self.shape();
+
+        let alignments = (0..count_cols)
+            .map(|col| *self.config.get_alignment_horizontal(Entity::Column(col)))
+            .collect::<Vec<_>>();
+        let has_cookies = alignments
+            .iter()
+            .any(|alignment| *alignment != AlignmentHorizontal::Left);
+
+        let mut records: Vec<Vec<_>> = self.records.clone().into();
+        let mut config = self.config.clone();
+        if has_cookies {
+            let cookies = alignments
+                .iter()
+                .map(|alignment| match alignment {
+                    AlignmentHorizontal::Left => "<l>",
+                    AlignmentHorizontal::Center => "<c>",
+                    AlignmentHorizontal::Right => "<r>",
+                })
+                .map(|cookie| CellInfo::new(cookie.to_string()))
+                .collect();
+
+            records.insert(0, cookies);
+            config.insert_row(0);
+        }
+
+        let header_row = usize::from(has_cookies);
+
+        let mut table = Table {
+            records: VecRecords::new(records),
+            config,
+            dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: self.color_enabled,
+        };
+
+        if count_rows > 1 {
+            let header = HorizontalLine::full('-', '|', '|', '+');
+            let _ = table.with(Style::markdown().horizontals([(header_row + 1, header)]));
+        } else {
+            let _ = table.with(Style::markdown().remove_horizontals());
+        }
+
+        table.to_string()
+    }
+
+    /// Renders the table as a LaTeX `tabular` environment.
+    ///
+    /// Column specifications are derived from a horizontal alignment of columns (`l`, `c`, `r`),
+    /// vertical borders become `|` and horizontal ones `\hline`.
+    /// Column spans are turned into `\multicolumn`.
+    ///
+    /// LaTeX special characters are escaped and lines of a multiline cell are joined by a space.
+    ///
+    /// ```
+    /// use tabled::{Table, settings::{Alignment, Modify, object::Columns}};
+    ///
+    /// let mut table = Table::new([("Apple", "10%")]);
+    /// table.with(Modify::new(Columns::last()).with(Alignment::right()));
+    ///
+    /// assert_eq!(
+    ///     table.to_latex(),
+    ///     concat!(
+    ///         "\\begin{tabular}{|l|r|}\n",
+    ///         "\\hline\n",
+    ///         "\\&str & \\&str \\\\\n",
+    ///         "\\hline\n",
+    ///         "Apple & 10\\% \\\\\n",
+    ///         "\\hline\n",
+    ///         "\\end{tabular}",
+    ///     )
+    /// );
+    /// ```
+    #[cfg(feature = "latex")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "latex")))]
+    pub fn to_latex(&self) -> String {
+        let (count_rows, count_cols) = self.shape();
+        let cfg = &self.config;
+
+        let border = |col: usize| {
+            if cfg.has_vertical(col, count_cols) {
+                "|"
+            } else {
+                ""
+            }
+        };
+        let hline = |row: usize, buf: &mut String| {
+            if cfg.has_horizontal(row, count_rows) {
+                buf.push_str("\\hline\n");
+            }
+        };
+
+        let mut buf = String::from("\\begin{tabular}{");
+        for col in 0..count_cols {
+            buf.push_str(border(col));
+            buf.push(latex_alignment(
+                cfg.get_alignment_horizontal(Entity::Column(col)),
+            ));
+        }
+        buf.push_str(border(count_cols));
+        buf.push_str("}\n");
+
+        for row in 0..count_rows {
+            hline(row, &mut buf);
+
+            let mut cells = Vec::with_capacity(count_cols);
+            let mut col = 0;
+            while col < count_cols {
+                let pos = (row, col);
+                let text = if cfg.is_cell_visible(pos) {
+                    let lines = self.records.get_text(pos).lines().collect::<Vec<_>>();
+                    latex_escape(&lines.join(" "))
+                } else {
+                    String::new()
+                };
+
+                let span = cfg.get_column_span(pos).unwrap_or(1);
+                let span = std::cmp::min(span, count_cols - col);
+                if span > 1 {
+                    let alignment = latex_alignment(cfg.get_alignment_horizontal(pos.into()));
+                    let left = if col == 0 { border(0) } else { "" };
+                    let right = border(col + span);
+
+                    cells.push(format!(
+                        "\\multicolumn{{{}}}{{{}{}{}}}{{{}}}",
+                        span, left, alignment, right, text
+                    ));
+                } else {
+                    cells.push(text);
+                }
+
+                col += span;
+            }
+
+            buf.push_str(&cells.join(" & "));
+            buf.push_str(" \\\\\n");
+        }
+
+        hline(count_rows, &mut buf);
+        buf.push_str("\\end{tabular}");
+
+        buf
+    }
 }
 
 impl Default for Table {
@@ -296,30 +1356,67 @@ impl Default for Table {
             records: VecRecords::default(),
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: true,
+        }
+    }
+}
+
+impl<S> Clone for Table<S>
+where
+    S: Clone + AsRef<str>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            records: self.records.clone(),
+            config: self.config.clone(),
+            dimension: self.dimension.clone(),
+            color_enabled: self.color_enabled,
         }
     }
 }
 
-impl fmt::Display for Table {
+impl<S> fmt::Display for Table<S>
+where
+    S: AsRef<str>,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.is_empty() {
             return Ok(());
         }
 
-        let config = use_format_configuration(f, self);
-        let colors = self.config.get_colors();
+        let mut config = use_format_configuration(f, self);
+        let mut colors = self.config.get_colors();
+        let no_colors = ColorMap::default();
+        if !self.color_enabled {
+            config.to_mut().clear_colors();
+            colors = &no_colors;
+        }
+
+        let caption_top = self.config.get_caption_top();
+        let caption_bottom = self.config.get_caption_bottom();
+        if caption_top.is_none() && caption_bottom.is_none() {
+            return print_table(f, self, &config, colors);
+        }
 
-        if !self.dimension.is_empty() {
-            let mut dims = self.dimension.clone();
-            dims.estimate(&self.records, config.as_ref());
+        let mut table = String::new();
+        print_table(&mut table, self, &config, colors)?;
 
-            print_grid(f, &self.records, &config, &dims, colors)
-        } else {
-            let mut dims = PeekableDimension::default();
-            dims.estimate(&self.records, &config);
+        let ambiguous_wide = config.is_ambiguous_width_wide();
+        let width = string_width_multiline_ambiguous(&table, ambiguous_wide);
 
-            print_grid(f, &self.records, &config, &dims, colors)
+        if let Some(caption) = caption_top {
+            caption.print(f, width, ambiguous_wide)?;
+            f.write_str("\n")?;
         }
+
+        f.write_str(&table)?;
+
+        if let Some(caption) = caption_bottom {
+            f.write_str("\n")?;
+            caption.print(f, width, ambiguous_wide)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -342,6 +1439,7 @@ impl From<Builder> for Table {
             records,
             config: ColoredConfig::new(configure_grid()),
             dimension: CompleteDimensionVecRecords::default(),
+            color_enabled: true,
         }
     }
 }
@@ -380,6 +1478,158 @@ impl<R, D> TableOption<R, D, ColoredConfig> for &SpannedConfig {
     }
 }
 
+#[cfg(feature = "latex")]
+fn latex_alignment(alignment: &AlignmentHorizontal) -> char {
+    match alignment {
+        AlignmentHorizontal::Left => 'l',
+        AlignmentHorizontal::Center => 'c',
+        AlignmentHorizontal::Right => 'r',
+    }
+}
+
+#[cfg(feature = "latex")]
+fn latex_escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '#' | '_' | '$' | '{' | '}' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '\\' => buf.push_str("\\textbackslash{}"),
+            '~' => buf.push_str("\\textasciitilde{}"),
+            '^' => buf.push_str("\\textasciicircum{}"),
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+/// Returns a width of a cell content.
+///
+/// A cached width of records is measured with ambiguous characters being narrow,
+/// so the content is measured again if they're set to be wide.
+fn get_cell_width<S>(records: &VecRecords<CellInfo<S>>, cfg: &SpannedConfig, pos: Position) -> usize
+where
+    S: AsRef<str>,
+{
+    if cfg.is_ambiguous_width_wide() {
+        string_width_multiline_ambiguous(records.get_text(pos), true)
+    } else {
+        records.get_width(pos)
+    }
+}
+
+/// Returns a cell content laid out into a given width and height,
+/// the same way a grid renders it, though colors of the config are not applied.
+fn format_cell<R>(
+    records: &R,
+    cfg: &SpannedConfig,
+    pos: Position,
+    width: usize,
+    height: usize,
+) -> String
+where
+    R: Records + PeekableRecords,
+{
+    let entity = pos.into();
+    let pad = cfg.get_padding(entity);
+    let formatting = *cfg.get_formatting(entity);
+    let ambiguous_wide = cfg.is_ambiguous_width_wide();
+
+    let trim = |line: &str| -> String {
+        if !formatting.horizontal_trim {
+            return line.to_owned();
+        }
+
+        #[cfg(feature = "color")]
+        {
+            ansi_str::AnsiStr::ansi_trim(line).into_owned()
+        }
+
+        #[cfg(not(feature = "color"))]
+        {
+            line.trim().to_owned()
+        }
+    };
+
+    let all_lines = (0..records.count_lines(pos))
+        .map(|i| trim(records.get_line(pos, i)))
+        .collect::<Vec<_>>();
+    let cell_width = all_lines
+        .iter()
+        .map(|line| string_width_ambiguous(line, ambiguous_wide))
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = all_lines.as_slice();
+    if formatting.vertical_trim {
+        let is_empty = |line: &&String| line.trim().is_empty();
+        let start = lines.iter().take_while(is_empty).count();
+        let end = lines[start..].iter().rev().take_while(is_empty).count();
+        lines = &lines[start..lines.len() - end];
+    }
+
+    let cell_height = std::cmp::min(lines.len(), height);
+    let available = height.saturating_sub(pad.top.size);
+    let top = pad.top.size
+        + match cfg.get_alignment_vertical(entity) {
+            AlignmentVertical::Top => 0,
+            AlignmentVertical::Bottom => available.saturating_sub(cell_height),
+            AlignmentVertical::Center => available.saturating_sub(cell_height) / 2,
+        };
+
+    let justification = cfg.get_justification(entity);
+    let alignment = *cfg.get_alignment_horizontal(entity);
+    let available = width.saturating_sub(pad.left.size + pad.right.size);
+
+    let mut buf = String::new();
+    for i in 0..height {
+        if i > 0 {
+            buf.push('\n');
+        }
+
+        if i < top {
+            buf.extend(std::iter::repeat(pad.top.fill).take(width));
+            continue;
+        }
+
+        let line = match lines.get(i - top) {
+            Some(line) if i - top < cell_height => line,
+            _ => {
+                buf.extend(std::iter::repeat(pad.bottom.fill).take(width));
+                continue;
+            }
+        };
+
+        let line_width = string_width_ambiguous(line, ambiguous_wide);
+        let text_width = if formatting.allow_lines_alignment {
+            line_width
+        } else {
+            cell_width
+        };
+
+        let indent = available.saturating_sub(text_width);
+        let (left, right) = match alignment {
+            AlignmentHorizontal::Left => (0, indent),
+            AlignmentHorizontal::Right => (indent, 0),
+            AlignmentHorizontal::Center => (indent / 2, indent - indent / 2),
+        };
+
+        buf.extend(std::iter::repeat(pad.left.fill).take(pad.left.size));
+        buf.extend(std::iter::repeat(justification).take(left));
+        buf.push_str(line);
+        buf.extend(std::iter::repeat(justification).take(right));
+        if !formatting.allow_lines_alignment {
+            buf.extend(std::iter::repeat(' ').take(text_width - line_width));
+        }
+        buf.extend(std::iter::repeat(pad.right.fill).take(pad.right.size));
+    }
+
+    buf
+}
+
 fn convert_fmt_alignment(alignment: fmt::Alignment) -> AlignmentHorizontal {
     match alignment {
         fmt::Alignment::Left => AlignmentHorizontal::Left,
@@ -418,10 +1668,13 @@ fn configure_grid() -> SpannedConfig {
     cfg
 }
 
-fn use_format_configuration<'a>(
+fn use_format_configuration<'a, S>(
     f: &mut fmt::Formatter<'_>,
-    table: &'a Table,
-) -> Cow<'a, SpannedConfig> {
+    table: &'a Table<S>,
+) -> Cow<'a, SpannedConfig>
+where
+    S: AsRef<str>,
+{
     if f.align().is_some() || f.width().is_some() {
         let mut cfg = table.config.as_ref().clone();
 
@@ -441,7 +1694,10 @@ fn set_align_table(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig) {
     }
 }
 
-fn set_width_table(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig, table: &Table) {
+fn set_width_table<S>(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig, table: &Table<S>)
+where
+    S: AsRef<str>,
+{
     if let Some(width) = f.width() {
         let total_width = table.total_width();
         if total_width >= width {
@@ -476,9 +1732,46 @@ fn set_width_table(f: &fmt::Formatter<'_>, cfg: &mut SpannedConfig, table: &Tabl
     }
 }
 
-fn print_grid<F: fmt::Write, D: Dimension>(
+fn captions_dimension(cfg: &ColoredConfig) -> (usize, usize) {
+    let ambiguous_wide = cfg.is_ambiguous_width_wide();
+    let captions = cfg.get_caption_top().into_iter();
+    let captions = captions.chain(cfg.get_caption_bottom());
+
+    captions.fold((0, 0), |(width, height), caption| {
+        let caption_width = caption.width(ambiguous_wide);
+        (
+            std::cmp::max(width, caption_width),
+            height + caption.height(),
+        )
+    })
+}
+
+fn print_table<F, S>(
+    f: &mut F,
+    table: &Table<S>,
+    cfg: &SpannedConfig,
+    colors: &ColorMap,
+) -> fmt::Result
+where
+    F: fmt::Write,
+    S: AsRef<str>,
+{
+    if !table.dimension.is_empty() {
+        let mut dims = table.dimension.clone();
+        dims.estimate(&table.records, cfg);
+
+        print_grid(f, &table.records, cfg, &dims, colors)
+    } else {
+        let mut dims = PeekableDimension::default();
+        dims.estimate(&table.records, cfg);
+
+        print_grid(f, &table.records, cfg, &dims, colors)
+    }
+}
+
+fn print_grid<F: fmt::Write, D: Dimension, S: AsRef<str>>(
     f: &mut F,
-    records: &VecRecords<CellInfo<String>>,
+    records: &VecRecords<CellInfo<S>>,
     cfg: &SpannedConfig,
     dims: D,
     colors: &ColorMap,
@@ -490,67 +1783,6 @@ fn print_grid<F: fmt::Write, D: Dimension>(
     }
 }
 
-fn dimension_reastimate(
-    dims: &mut CompleteDimensionVecRecords<'_>,
-    widths: Option<Vec<usize>>,
-    heights: Option<Vec<usize>>,
-    hint: Option<Entity>,
-) {
-    let hint = match hint {
-        Some(hint) => hint,
-        None => return,
-    };
-
-    match hint {
-        Entity::Global | Entity::Cell(_, _) => {
-            dims_set_widths(dims, widths);
-            dims_set_heights(dims, heights);
-        }
-        Entity::Column(_) => {
-            dims_set_widths(dims, widths);
-        }
-        Entity::Row(_) => {
-            dims_set_heights(dims, heights);
-        }
-    }
-}
-
-fn dims_set_widths(dims: &mut CompleteDimensionVecRecords<'_>, list: Option<Vec<usize>>) {
-    match list {
-        Some(list) => match dims.get_widths() {
-            Some(widths) => {
-                if widths == list {
-                    dims.clear_width();
-                } else {
-                    dims.set_widths(list);
-                }
-            }
-            None => dims.set_widths(list),
-        },
-        None => {
-            dims.clear_width();
-        }
-    }
-}
-
-fn dims_set_heights(dims: &mut CompleteDimensionVecRecords<'_>, list: Option<Vec<usize>>) {
-    match list {
-        Some(list) => match dims.get_heights() {
-            Some(heights) => {
-                if heights == list {
-                    dims.clear_height();
-                } else {
-                    dims.set_heights(list);
-                }
-            }
-            None => dims.set_heights(list),
-        },
-        None => {
-            dims.clear_height();
-        }
-    }
-}
-
 fn dimension_reastimate_likely(dims: &mut CompleteDimensionVecRecords<'_>, hint: Option<Entity>) {
     let hint = match hint {
         Some(hint) => hint,
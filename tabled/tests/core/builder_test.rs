@@ -1,8 +1,11 @@
 #![cfg(feature = "std")]
 
-use std::iter::FromIterator;
+use std::{
+    collections::{BTreeMap, HashMap},
+    iter::FromIterator,
+};
 
-use tabled::builder::Builder;
+use tabled::{builder::Builder, settings::Style, Table};
 
 use testing_table::test_table;
 
@@ -742,6 +745,397 @@ test_table!(
     "+---+------+---------+-----------+"
 );
 
+test_table!(
+    from_sorted_maps,
+    Builder::from_sorted_maps([
+        BTreeMap::from([("id", "1"), ("name", "Alice")]),
+        BTreeMap::from([("id", "2"), ("email", "bob@mail.com")]),
+        BTreeMap::from([("name", "Carol"), ("age", "30")]),
+    ])
+    .build(),
+    "+-----+--------------+----+-------+"
+    "| age | email        | id | name  |"
+    "+-----+--------------+----+-------+"
+    "|     |              | 1  | Alice |"
+    "+-----+--------------+----+-------+"
+    "|     | bob@mail.com | 2  |       |"
+    "+-----+--------------+----+-------+"
+    "| 30  |              |    | Carol |"
+    "+-----+--------------+----+-------+"
+);
+
+test_table!(
+    from_maps,
+    Builder::from_maps([
+        vec![("id", "1"), ("name", "Alice")],
+        vec![("email", "bob@mail.com"), ("id", "2")],
+        vec![("name", "Carol"), ("age", "30")],
+    ])
+    .build(),
+    "+----+-------+--------------+-----+"
+    "| id | name  | email        | age |"
+    "+----+-------+--------------+-----+"
+    "| 1  | Alice |              |     |"
+    "+----+-------+--------------+-----+"
+    "| 2  |       | bob@mail.com |     |"
+    "+----+-------+--------------+-----+"
+    "|    | Carol |              | 30  |"
+    "+----+-------+--------------+-----+"
+);
+
+test_table!(
+    from_maps_hash_map,
+    Builder::from_maps([
+        HashMap::from([("id", "1")]),
+        HashMap::from([("name", "Bob")]),
+        HashMap::from([("id", "3")]),
+    ])
+    .build(),
+    "+----+------+"
+    "| id | name |"
+    "+----+------+"
+    "| 1  |      |"
+    "+----+------+"
+    "|    | Bob  |"
+    "+----+------+"
+    "| 3  |      |"
+    "+----+------+"
+);
+
+test_table!(
+    try_set_header,
+    {
+        let mut b = Builder::default();
+        b.push_record(["1", "2", "3"]);
+        b.push_record(["a", "b", "c"]);
+        b.try_set_header(["x", "y", "z"]).unwrap();
+        b.build()
+    },
+    "+---+---+---+"
+    "| x | y | z |"
+    "+---+---+---+"
+    "| 1 | 2 | 3 |"
+    "+---+---+---+"
+    "| a | b | c |"
+    "+---+---+---+"
+);
+
+test_table!(
+    try_set_header_replace,
+    {
+        let mut b = Builder::default();
+        b.set_header(["x", "y", "z"]);
+        b.push_record(["1", "2", "3"]);
+        b.try_set_header(["i", "j", "k"]).unwrap();
+        b.build()
+    },
+    "+---+---+---+"
+    "| i | j | k |"
+    "+---+---+---+"
+    "| 1 | 2 | 3 |"
+    "+---+---+---+"
+);
+
+#[test]
+fn try_set_header_mismatch() {
+    let mut b = Builder::default();
+    b.push_record(["1", "2", "3"]);
+
+    let err = b.try_set_header(["x", "y"]).unwrap_err();
+    assert_eq!(err, ["x", "y"]);
+
+    let err = b.try_set_header(["x", "y", "z", "w"]).unwrap_err();
+    assert_eq!(err, ["x", "y", "z", "w"]);
+
+    assert_eq!(
+        b.build().to_string(),
+        "+---+---+---+\n\
+         | 1 | 2 | 3 |\n\
+         +---+---+---+"
+    );
+}
+
+#[test]
+fn try_set_header_empty() {
+    let mut b = Builder::default();
+    assert!(b.try_set_header(["x", "y"]).is_ok());
+
+    b.remove_header();
+    b.push_record(["1"]);
+
+    assert_eq!(
+        b.build().to_string(),
+        "+---+\n\
+         | 1 |\n\
+         +---+"
+    );
+}
+
+#[test]
+fn from_sorted_maps_hash_map_is_deterministic() {
+    let build = || {
+        let maps = (0..5).map(|i| {
+            (0..10)
+                .map(|j| (format!("key{j}"), format!("{i}-{j}")))
+                .collect::<HashMap<_, _>>()
+        });
+
+        Builder::from_sorted_maps(maps).build().to_string()
+    };
+
+    let expected = build();
+    for _ in 0..50 {
+        assert_eq!(build(), expected);
+    }
+}
+
+test_table!(
+    push_cell_table,
+    {
+        let mut nested = Builder::from_iter([["a", "b"], ["c", "d"]]).build();
+        nested.with(Style::rounded());
+
+        let mut b = Builder::default();
+        b.set_header(["id", "nested", "note"]);
+        b.push_record(["1"]);
+        b.push_cell_table(&nested);
+        b.push_record(["2", "", "wide content"]);
+        b.build()
+    },
+    "+----+-----------+--------------+"
+    "| id | nested    | note         |"
+    "+----+-----------+--------------+"
+    "| 1  | ╭───┬───╮ |              |"
+    "|    | │ a │ b │ |              |"
+    "|    | ├───┼───┤ |              |"
+    "|    | │ c │ d │ |              |"
+    "|    | ╰───┴───╯ |              |"
+    "+----+-----------+--------------+"
+    "| 2  |           | wide content |"
+    "+----+-----------+--------------+"
+);
+
+test_table!(
+    push_cell_table_empty_builder,
+    {
+        let mut b = Builder::default();
+        b.push_cell_table(&Table::new([[1, 2]]));
+        b.build().with(Style::modern())
+    },
+    "┌───────────┐"
+    "│ +---+---+ │"
+    "│ | 0 | 1 | │"
+    "│ +---+---+ │"
+    "│ | 1 | 2 | │"
+    "│ +---+---+ │"
+    "└───────────┘"
+);
+
+#[test]
+fn push_cell_table_width() {
+    let nested = Builder::from_iter([["1", "2"], ["3", "4"]]).build();
+
+    let mut b = Builder::default();
+    b.push_cell_table(&nested);
+    let table = b.build();
+
+    assert_eq!(table.content_widths(), vec![nested.total_width()]);
+}
+
+test_table!(
+    extend_column_interleaved,
+    {
+        let mut b = Builder::default();
+        b.set_header(["a", "b", "c"]);
+        b.extend_column(0, ["1", "2"]).unwrap();
+        b.extend_column(1, ["x"]).unwrap();
+        b.extend_column(0, ["3"]).unwrap();
+        b.extend_column(1, ["y", "z", "w"]).unwrap();
+        b.build()
+    },
+    "+---+---+---+"
+    "| a | b | c |"
+    "+---+---+---+"
+    "| 1 | x |   |"
+    "+---+---+---+"
+    "| 2 | y |   |"
+    "+---+---+---+"
+    "| 3 | z |   |"
+    "+---+---+---+"
+    "|   | w |   |"
+    "+---+---+---+"
+);
+
+test_table!(
+    extend_column_after_push_record,
+    {
+        let mut b = Builder::default();
+        b.push_record(["1", "2"]);
+        b.push_record(["3"]);
+        b.extend_column(1, ["4", "6"]).unwrap();
+        b.build()
+    },
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 | 4 |"
+    "+---+---+"
+    "|   | 6 |"
+    "+---+---+"
+);
+
+test_table!(
+    extend_column_keeps_empty_values,
+    {
+        let mut b = Builder::default();
+        b.set_header(["a", "b"]);
+        b.extend_column(0, ["1", ""]).unwrap();
+        b.extend_column(0, ["3"]).unwrap();
+        b.extend_column(1, ["", "x"]).unwrap();
+        b.extend_column(1, ["y"]).unwrap();
+        b.build()
+    },
+    "+---+---+"
+    "| a | b |"
+    "+---+---+"
+    "| 1 |   |"
+    "+---+---+"
+    "|   | x |"
+    "+---+---+"
+    "| 3 | y |"
+    "+---+---+"
+);
+
+test_table!(
+    extend_column_after_clean_rows,
+    {
+        let mut b = Builder::default();
+        b.push_record(["1", "2"]);
+        b.push_record(["", ""]);
+        b.clean_rows();
+        b.extend_column(0, ["3"]).unwrap();
+        b.build()
+    },
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 |   |"
+    "+---+---+"
+);
+
+#[test]
+fn extend_column_out_of_range() {
+    let mut b = Builder::default();
+    b.push_record(["1", "2"]);
+
+    assert_eq!(
+        b.extend_column(2, ["3", "4"]).map(|_| ()),
+        Err(vec![String::from("3"), String::from("4")])
+    );
+    assert_eq!(b.count_rows(), 1);
+    assert_eq!(b.count_columns(), 2);
+}
+
+#[test]
+fn clear_keeps_capacity() {
+    let mut b = Builder::with_capacity(8);
+    b.set_header(["a", "b"]);
+    for i in 0..8 {
+        b.push_record([i.to_string(), i.to_string()]);
+    }
+
+    let capacity = b.capacity();
+    b.clear();
+
+    assert_eq!(b.capacity(), capacity);
+    assert_eq!(b.count_rows(), 0);
+    assert_eq!(b.count_columns(), 0);
+    assert!(!b.has_header());
+}
+
+test_table!(
+    clear_and_reuse,
+    {
+        let mut b = Builder::default();
+        b.set_header(["a", "b", "c"]);
+        b.push_record(["1", "2", "3"]);
+        b.clear();
+        b.push_record(["4", "5"]);
+        b.push_record(["6"]);
+        b.build()
+    },
+    "+---+---+"
+    "| 4 | 5 |"
+    "+---+---+"
+    "| 6 |   |"
+    "+---+---+"
+);
+
+test_table!(
+    clean_empty_row_and_column,
+    clean({
+        let mut b = Builder::default();
+        b.set_header(["a", "b", "c"]);
+        b.push_record(["1", "", "2"]);
+        b.push_record(["", "", ""]);
+        b.push_record(["3", "", "4"]);
+        b
+    }),
+    "+---+---+"
+    "| a | c |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 | 4 |"
+    "+---+---+"
+);
+
+test_table!(
+    clean_rows_only,
+    {
+        let mut b = Builder::default();
+        b.push_record(["1", "", "2"]);
+        b.push_record(["", "", ""]);
+        b.clean_rows();
+        b.build()
+    },
+    "+---+--+---+"
+    "| 1 |  | 2 |"
+    "+---+--+---+"
+);
+
+test_table!(
+    clean_columns_only,
+    {
+        let mut b = Builder::default();
+        b.push_record(["1", "", "2"]);
+        b.push_record(["", "", ""]);
+        b.clean_columns();
+        b.build()
+    },
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "|   |   |"
+    "+---+---+"
+);
+
+test_table!(
+    clean_inconsistent_rows,
+    clean({
+        let mut b = Builder::default();
+        b.push_record(["1", "", "2"]);
+        b.push_record([""]);
+        b.push_record(["3"]);
+        b
+    }),
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+    "| 3 |   |"
+    "+---+---+"
+);
+
 fn clean(mut b: Builder) -> String {
     b.clean();
     b.build().to_string()
@@ -0,0 +1,112 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    builder::Builder,
+    settings::{object::Cell, Modify, Span, Width},
+    tables::Delimited,
+};
+
+use crate::matrix::Matrix;
+
+#[test]
+fn csv_round_trip() {
+    let data = [
+        ["name", "note"],
+        ["Alice", "likes, commas"],
+        ["Bob", "says \"hi\""],
+        ["Eve", "multi\nline"],
+    ];
+
+    let mut builder = Builder::default();
+    for row in data {
+        builder.push_record(row);
+    }
+
+    let csv = builder.build().to_csv();
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(csv.as_bytes());
+    let mut builder = Builder::default();
+    for record in reader.records() {
+        builder.push_record(record.unwrap().iter().map(String::from));
+    }
+
+    let got: Vec<Vec<String>> = builder.into();
+    assert_eq!(got, data.map(|row| row.map(String::from).to_vec()).to_vec());
+}
+
+#[test]
+fn csv_rendered() {
+    let mut table = Matrix::table(1, 1);
+    table
+        .with(Modify::new(Cell::new(0, 1)).with(Width::truncate(3)))
+        .with(Modify::new(Cell::new(1, 1)).with("  multi \n line  "));
+
+    assert_eq!(table.to_csv(), "N,col\n0,\"  multi \n line  \"\n");
+    assert_eq!(
+        Delimited::csv().rendered(true).export(&table),
+        "N,col\n0,\"multi\nline\"\n"
+    );
+}
+
+#[test]
+fn csv_spans() {
+    let mut table = Matrix::table(2, 2);
+    table
+        .with(Modify::new(Cell::new(1, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell::new(0, 0)).with(Span::row(2)));
+
+    assert_eq!(table.to_csv(), "N,column 0,column 1\n,0-0,\n1,1-0,1-1\n");
+    assert_eq!(
+        Delimited::csv().repeat_spans(true).export(&table),
+        "N,column 0,column 1\nN,0-0,0-0\n1,1-0,1-1\n"
+    );
+}
+
+#[test]
+fn tsv_escapes() {
+    let table = Builder::from(vec![vec![String::from("a\tb"), String::from("c\\d")]]).build();
+
+    assert_eq!(table.to_tsv(), "a\\tb\tc\\\\d\n");
+    assert_eq!(
+        Delimited::tsv().delimiter(';').export(&table),
+        "a\\tb;c\\\\d\n"
+    );
+}
+
+#[test]
+fn tsv_escapes_delimiter() {
+    let table = Builder::from(vec![vec![String::from("a;b"), String::from("c\nd")]]).build();
+
+    assert_eq!(
+        Delimited::tsv().delimiter(';').export(&table),
+        "a\\;b;c\\nd\n"
+    );
+    assert_eq!(
+        Delimited::tsv()
+            .delimiter(';')
+            .line_separator(" ")
+            .export(&table),
+        "a\\;b;c d\n"
+    );
+    assert_eq!(
+        Delimited::csv().line_separator(" ").export(&table),
+        "a;b,c d\n"
+    );
+}
+
+#[test]
+fn delimited_strips_ansi() {
+    let table = Builder::from(vec![vec![
+        String::from("\u{1b}[31mred\u{1b}[39m"),
+        String::from("\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\"),
+    ]])
+    .build();
+
+    assert_eq!(table.to_csv(), "red,link\n");
+    assert_eq!(table.to_tsv(), "red\tlink\n");
+
+    #[cfg(feature = "color")]
+    assert_eq!(Delimited::csv().rendered(true).export(&table), "red,link\n");
+}
@@ -75,6 +75,28 @@ test_table!(
     "+----+----+----+"
 );
 
+test_table!(
+    iter_table_widths,
+    IterTable::new((0..3).map(|i| vec![i.to_string(), format!("row {i}"), String::from("text")])).widths([1, 5, 2]),
+    "+---+-------+----+"
+    "| 0 | row 0 | te |"
+    "+---+-------+----+"
+    "| 1 | row 1 | te |"
+    "+---+-------+----+"
+    "| 2 | row 2 | te |"
+    "+---+-------+----+"
+);
+
+test_table!(
+    iter_table_widths_cols,
+    IterTable::new(Matrix::with_no_frame(2, 3).to_vec()).widths([3]).columns(2),
+    "+-----+--+"
+    "| 0-0 |  |"
+    "+-----+--+"
+    "| 1-0 |  |"
+    "+-----+--+"
+);
+
 test_table!(
     iter_table_height_does_not_work,
     IterTable::new(Matrix::with_no_frame(3, 3).to_vec()).height(5),
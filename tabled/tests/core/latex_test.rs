@@ -0,0 +1,70 @@
+#![cfg(feature = "latex")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{object::Cell, Alignment, Modify, Span, Style},
+};
+
+#[test]
+fn latex_column_span_test() {
+    let mut builder = Builder::default();
+    builder.push_record(["Total", ""]);
+    builder.push_record(["1_000", "$5 & {more}"]);
+
+    let mut table = builder.build();
+    table.with(
+        Modify::new(Cell::new(0, 0))
+            .with(Span::column(2))
+            .with(Alignment::center()),
+    );
+
+    assert_eq!(
+        table.to_latex(),
+        concat!(
+            "\\begin{tabular}{|l|l|}\n",
+            "\\hline\n",
+            "\\multicolumn{2}{|c|}{Total} \\\\\n",
+            "\\hline\n",
+            "1\\_000 & \\$5 \\& \\{more\\} \\\\\n",
+            "\\hline\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_borders_test() {
+    let mut table = Builder::from_iter([["a", "b"], ["c", "d"]]).build();
+    table
+        .with(Style::markdown())
+        .with(Modify::new(Cell::new(1, 1)).with(Alignment::right()));
+
+    assert_eq!(
+        table.to_latex(),
+        concat!(
+            "\\begin{tabular}{|l|l|}\n",
+            "a & b \\\\\n",
+            "\\hline\n",
+            "c & d \\\\\n",
+            "\\end{tabular}",
+        )
+    );
+}
+
+#[test]
+fn latex_escape_test() {
+    let table = Builder::from_iter([["50% #1 ~ ^ \\"]]).build();
+
+    assert_eq!(
+        table.to_latex(),
+        concat!(
+            "\\begin{tabular}{|l|}\n",
+            "\\hline\n",
+            "50\\% \\#1 \\textasciitilde{} \\textasciicircum{} \\textbackslash{} \\\\\n",
+            "\\hline\n",
+            "\\end{tabular}",
+        )
+    );
+}
@@ -1,7 +1,9 @@
 mod builder_test;
 mod compact_table;
+mod delimited_test;
 mod extended_table_test;
 mod index_test;
 mod iter_table;
+mod latex_test;
 mod pool_table;
 mod table_test;
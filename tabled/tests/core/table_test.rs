@@ -4,12 +4,18 @@ use std::iter::FromIterator;
 
 use tabled::{
     builder::Builder,
-    settings::{formatting::Charset, Height, Modify, Padding, Settings, Style, Width},
-    Table,
+    grid::{config::Border, util::string::string_width},
+    settings::{
+        formatting::{Charset, TrimStrategy},
+        object::{Cell, Columns, Rows},
+        style::{BorderSpanCorrection, HorizontalLine},
+        Alignment, Height, Margin, Modify, Padding, Panel, Settings, Span, Style, Width,
+    },
+    IntoTable, Table,
 };
 
 use crate::matrix::Matrix;
-use testing_table::test_table;
+use testing_table::{static_table, test_table};
 
 mod default_types {
     use super::*;
@@ -389,6 +395,18 @@ mod derived {
         "+----+----+"
     );
 
+    test_table!(
+        table_into_table_structures,
+        (0..2).map(|i| TestType { f1: i, f2: "str" }).into_table(),
+        "+----+-----+"
+        "| f1 | f2  |"
+        "+----+-----+"
+        "| 0  | str |"
+        "+----+-----+"
+        "| 1  | str |"
+        "+----+-----+"
+    );
+
     test_table!(
         table_tuple_with_structure_vec,
         Table::new([(0, TestType { f1: 0, f2: "0str" }), (1, TestType { f1: 1, f2: "1str" })]),
@@ -860,3 +878,768 @@ test_table!(
     "|      1      |      1-0      |   1-1    |   1-2    |"
     "|      2      |      2-0      |   2-1    |   2-2    |"
 );
+
+#[test]
+fn table_cells_test() {
+    let mut table = Matrix::table(2, 2);
+    table.modify((1, 0), "Hello World");
+
+    let cells: Vec<_> = table.cells().collect();
+
+    assert_eq!(
+        cells,
+        [
+            ((0, 0), "N"),
+            ((0, 1), "column 0"),
+            ((0, 2), "column 1"),
+            ((1, 0), "Hello World"),
+            ((1, 1), "0-0"),
+            ((1, 2), "0-1"),
+            ((2, 0), "1"),
+            ((2, 1), "1-0"),
+            ((2, 2), "1-1"),
+        ]
+    );
+}
+
+#[test]
+fn table_cells_empty_test() {
+    let table = Table::default();
+    assert_eq!(table.cells().count(), 0);
+}
+
+#[test]
+fn table_set_test() {
+    let mut table = Matrix::table(2, 2);
+    table.with(Style::psql());
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " N | column 0 | column 1 "
+            "---+----------+----------"
+            " 0 |   0-0    |   0-1    "
+            " 1 |   1-0    |   1-1    "
+        )
+    );
+
+    table.set((1, 1), "a longer string").unwrap();
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " N |    column 0     | column 1 "
+            "---+-----------------+----------"
+            " 0 | a longer string |   0-1    "
+            " 1 |       1-0       |   1-1    "
+        )
+    );
+}
+
+#[test]
+fn table_set_out_of_bounds_test() {
+    let mut table = Matrix::table(2, 2);
+
+    assert_eq!(table.set((3, 0), "text").err(), Some("text"));
+    assert_eq!(table.set((0, 3), "text").err(), Some("text"));
+    assert!(table.set((2, 2), "text").is_ok());
+}
+
+#[test]
+fn table_split_columns_test() {
+    let mut table = Matrix::table(2, 6);
+    table.with(Style::psql());
+
+    let tables = table.split_columns(40, true);
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(
+        tables[0].to_string(),
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            " 1 |   1-0    |   1-1    |   1-2    "
+        )
+    );
+    assert_eq!(
+        tables[1].to_string(),
+        static_table!(
+            " N | column 3 | column 4 | column 5 "
+            "---+----------+----------+----------"
+            " 0 |   0-3    |   0-4    |   0-5    "
+            " 1 |   1-3    |   1-4    |   1-5    "
+        )
+    );
+}
+
+#[test]
+fn table_split_columns_narrow_test() {
+    let table = Matrix::table(1, 3);
+
+    let tables = table.split_columns(0, true);
+
+    assert_eq!(tables.len(), 3);
+    assert!(tables.iter().all(|table| table.count_columns() == 2));
+}
+
+#[test]
+fn table_split_columns_no_repeat_test() {
+    let mut table = Matrix::table(2, 5);
+    table.with(Style::psql());
+
+    let tables = table.split_columns(40, false);
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(
+        tables[0].to_string(),
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            " 1 |   1-0    |   1-1    |   1-2    "
+        )
+    );
+    assert_eq!(
+        tables[1].to_string(),
+        static_table!(
+            " column 3 | column 4 "
+            "----------+----------"
+            "   0-3    |   0-4    "
+            "   1-3    |   1-4    "
+        )
+    );
+}
+
+#[test]
+fn table_split_columns_moves_settings_test() {
+    let mut table = Matrix::table(2, 5);
+    table
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(4)).with(Alignment::right()));
+
+    let tables = table.split_columns(40, false);
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(
+        tables[0].to_string(),
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            " 1 |   1-0    |   1-1    |   1-2    "
+        )
+    );
+    assert_eq!(
+        tables[1].to_string(),
+        static_table!(
+            " column 3 | column 4 "
+            "----------+----------"
+            "      0-3 |   0-4    "
+            "      1-3 |   1-4    "
+        )
+    );
+}
+
+#[test]
+fn table_split_columns_repeat_first_moves_settings_test() {
+    let mut table = Matrix::table(2, 6);
+    table
+        .with(Style::psql())
+        .with(Modify::new(Columns::first()).with(Alignment::right()))
+        .with(Modify::new(Columns::single(5)).with(Alignment::right()));
+
+    let tables = table.split_columns(40, true);
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(
+        tables[0].to_string(),
+        static_table!(
+            " N | column 0 | column 1 | column 2 "
+            "---+----------+----------+----------"
+            " 0 |   0-0    |   0-1    |   0-2    "
+            " 1 |   1-0    |   1-1    |   1-2    "
+        )
+    );
+    assert_eq!(
+        tables[1].to_string(),
+        static_table!(
+            " N | column 3 | column 4 | column 5 "
+            "---+----------+----------+----------"
+            " 0 |   0-3    |      0-4 |   0-5    "
+            " 1 |   1-3    |      1-4 |   1-5    "
+        )
+    );
+}
+
+#[test]
+fn table_split_columns_pinned_test() {
+    let mut table = Matrix::table(2, 5);
+    table.with(Style::psql()).with(Columns::pin(3));
+
+    let tables = table.split_columns(40, true);
+
+    assert_eq!(tables.len(), 2);
+    assert_eq!(
+        tables[0].to_string(),
+        static_table!(
+            " N | column 2 | column 0 | column 1 "
+            "---+----------+----------+----------"
+            " 0 |   0-2    |   0-0    |   0-1    "
+            " 1 |   1-2    |   1-0    |   1-1    "
+        )
+    );
+    assert_eq!(
+        tables[1].to_string(),
+        static_table!(
+            " N | column 2 | column 3 | column 4 "
+            "---+----------+----------+----------"
+            " 0 |   0-2    |   0-3    |   0-4    "
+            " 1 |   1-2    |   1-3    |   1-4    "
+        )
+    );
+}
+
+#[test]
+fn table_to_rst_test() {
+    let mut table = Matrix::table(2, 2);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Cell::new(1, 1)).with("multi\nline"));
+
+    assert_eq!(
+        table.to_rst(),
+        static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+===+==========+==========+"
+            "| 0 |  multi   |   0-1    |"
+            "|   |  line    |          |"
+            "+---+----------+----------+"
+            "| 1 |   1-0    |   1-1    |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn table_to_rst_span_test() {
+    let mut table = Matrix::table(2, 2);
+    table.with(Modify::new(Cell::new(1, 1)).with(Span::column(2)));
+
+    assert_eq!(
+        table.to_rst(),
+        static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+===+==========+==========+"
+            "| 0 |         0-0         |"
+            "+---+----------+----------+"
+            "| 1 |   1-0    |   1-1    |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+#[test]
+fn table_to_org_test() {
+    let table = Matrix::table(2, 2);
+
+    assert_eq!(
+        table.to_org(),
+        static_table!(
+            "| <c> |   <c>    |   <c>    |"
+            "|  N  | column 0 | column 1 |"
+            "|-----+----------+----------|"
+            "|  0  |   0-0    |   0-1    |"
+            "|  1  |   1-0    |   1-1    |"
+        )
+    );
+}
+
+#[test]
+fn table_to_org_padding_margin_test() {
+    let mut table = Matrix::table(1, 2);
+    table
+        .with(Padding::new(1, 1, 0, 1))
+        .with(Margin::new(1, 0, 0, 1))
+        .with(Modify::new(Cell::new(1, 0)).with(Alignment::right()));
+
+    assert_eq!(
+        table.to_org(),
+        static_table!(
+            " | <c> |   <c>    |   <c>    |"
+            " |     |          |          |"
+            " |  N  | column 0 | column 1 |"
+            " |     |          |          |"
+            " |-----+----------+----------|"
+            " |   0 |   0-0    |   0-1    |"
+            " |     |          |          |"
+            "                              "
+        )
+    );
+}
+
+#[test]
+fn table_to_org_left_aligned_test() {
+    let mut table = Matrix::table(1, 2);
+    table.with(Modify::new(Columns::new(..)).with(Alignment::left()));
+
+    assert_eq!(
+        table.to_org(),
+        static_table!(
+            "| N | column 0 | column 1 |"
+            "|---+----------+----------|"
+            "| 0 | 0-0      | 0-1      |"
+        )
+    );
+}
+
+test_table!(
+    table_head_test,
+    Matrix::table(9, 2).head(3),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+    "| … |    …     |    …     |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_head_not_truncated_test,
+    Matrix::table(2, 2).head(3),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_head_with_total_test,
+    Matrix::table(9, 2).with(Style::psql()).head_with_total(2),
+    "     N      | column 0 | column 1 "
+    "------------+----------+----------"
+    "     0      |   0-0    |   0-1    "
+    " … (9 rows) |    …     |    …     "
+);
+
+test_table!(
+    table_head_drops_settings_test,
+    Matrix::table(9, 2)
+        .with(Modify::new(Rows::new(3..)).with(Alignment::left()).with(Padding::new(3, 3, 1, 1)))
+        .modify((3, 1), tabled::settings::Border::filled('#'))
+        .head(3),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+    "| … |    …     |    …     |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_head_drops_spans_test,
+    Matrix::table(9, 2)
+        .with(Modify::new(Cell::new(1, 1)).with(Span::row(5)))
+        .with(Modify::new(Cell::new(4, 0)).with(Span::column(2)))
+        .head(3),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+          +----------+"
+    "| 1 |          |   1-1    |"
+    "+---+----------+----------+"
+    "| … |    …     |    …     |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    table_set_cell_border_l_shape_test,
+    {
+        let mut table = Matrix::table(2, 2);
+        table.with(Style::empty());
+
+        for row in 0..3 {
+            let mut border = Border::empty();
+            border.left = Some('#');
+            table.set_cell_border((row, 0), border);
+        }
+
+        for col in 0..3 {
+            let mut border = Border::empty();
+            border.bottom = Some('#');
+            table.set_cell_border((2, col), border);
+        }
+
+        let mut corner = Border::empty();
+        corner.left_bottom_corner = Some('#');
+        table.set_cell_border((2, 0), corner);
+
+        table
+    },
+    "# N  column 0  column 1 "
+    "# 0    0-0       0-1    "
+    "# 1    1-0       1-1    "
+    "########################"
+);
+
+#[test]
+fn table_map_lines_test() {
+    let mut table = Matrix::table(2, 2);
+    table.with(Style::psql());
+
+    let output = table.map_lines(|i, line| format!("{i} {line}"));
+
+    assert_eq!(
+        output,
+        static_table!(
+            "0  N | column 0 | column 1 "
+            "1 ---+----------+----------"
+            "2  0 |   0-0    |   0-1    "
+            "3  1 |   1-0    |   1-1    "
+        )
+    );
+}
+
+#[test]
+fn table_map_lines_empty_test() {
+    let table = Table::default();
+    assert_eq!(table.map_lines(|i, line| format!("{i} {line}")), "");
+}
+
+#[test]
+fn table_content_widths_test() {
+    let mut table = Matrix::table(2, 2);
+    table.with(Padding::new(2, 2, 0, 0));
+
+    assert_eq!(table.content_widths(), [1, 8, 8]);
+    assert_eq!(table.column_widths(), [5, 12, 12]);
+}
+
+#[test]
+fn table_content_widths_multiline_test() {
+    let mut table = Table::new([["Hello\nWorld!!!", "1"], ["a", "b\nc"]]);
+    table.with(Padding::new(2, 2, 0, 0));
+
+    assert_eq!(table.content_widths(), [8, 1]);
+    assert_eq!(table.column_widths(), [12, 5]);
+}
+
+#[test]
+fn table_content_widths_empty_test() {
+    let table = Table::default();
+
+    assert!(table.content_widths().is_empty());
+    assert!(table.column_widths().is_empty());
+}
+
+#[test]
+fn table_render_is_deterministic_test() {
+    let build = || {
+        let mut table = Matrix::table(6, 6);
+        table
+            .modify((0, 0), Span::column(3))
+            .modify((1, 1), Span::column(2))
+            .modify((1, 4), Span::row(3))
+            .modify((2, 0), Span::row(2))
+            .modify((3, 2), Span::column(2))
+            .modify((5, 1), Span::column(4))
+            .with(Style::modern())
+            .with(BorderSpanCorrection);
+
+        table.to_string()
+    };
+
+    let expected = build();
+    for _ in 0..50 {
+        assert_eq!(build(), expected);
+    }
+}
+
+test_table!(
+    table_crlf_test,
+    Table::new([["a\r\nb", "c\rd"]]),
+    "+---+---+"
+    "| 0 | 1 |"
+    "+---+---+"
+    "| a | c |"
+    "| b | d |"
+    "+---+---+"
+);
+
+#[test]
+fn table_crlf_height_test() {
+    let table = Table::new([["a\r\nb"]]);
+    let output = table.to_string();
+
+    assert!(!output.contains('\r'));
+    assert_eq!(table.total_height(), 6);
+    assert_eq!(output, Table::new([["a\nb"]]).to_string());
+}
+
+test_table!(
+    into_table_iterator_test,
+    (0..3)
+        .map(|i| (i, i.to_string() + "!"))
+        .filter(|(i, _)| *i != 1)
+        .into_table(),
+    "+-----+--------+"
+    "| i32 | String |"
+    "+-----+--------+"
+    "| 0   | 0!     |"
+    "+-----+--------+"
+    "| 2   | 2!     |"
+    "+-----+--------+"
+);
+
+test_table!(
+    into_table_tuple_arity_test,
+    [(1, 'a', "b", 2.5, true, 3u8)].into_table(),
+    "+-----+------+------+-----+------+----+"
+    "| i32 | char | &str | f64 | bool | u8 |"
+    "+-----+------+------+-----+------+----+"
+    "| 1   | a    | b    | 2.5 | true | 3  |"
+    "+-----+------+------+-----+------+----+"
+);
+
+test_table!(
+    table_header_only_test,
+    Table::new((0..100).map(|i| (i, format!("value number {}", i))))
+        .with(Style::modern())
+        .header_only(false),
+    "┌─────┬────────┐"
+    "│ i32 │ String │"
+    "└─────┴────────┘"
+);
+
+test_table!(
+    table_header_only_data_widths_test,
+    Table::new((0..100).map(|i| (i, format!("value number {}", i))))
+        .with(Style::modern())
+        .header_only(true),
+    "┌─────┬─────────────────┐"
+    "│ i32 │ String          │"
+    "└─────┴─────────────────┘"
+);
+
+test_table!(
+    table_header_only_empty_test,
+    Table::default().header_only(true),
+    ""
+);
+
+#[test]
+fn table_to_grid_test() {
+    let mut table = Table::new([["Hello World", "1"]]);
+    table
+        .with(Style::modern())
+        .modify((1, 0), Width::wrap(5))
+        .modify((1, 1), Padding::new(2, 0, 1, 0));
+
+    assert_eq!(
+        table.to_grid(),
+        [
+            [" 0     ", " 1 "],
+            [" Hello \n  Worl \n d     ", "   \n  1\n   "],
+        ]
+    );
+}
+
+#[test]
+fn table_to_grid_span_test() {
+    let mut table = Matrix::table(2, 2);
+    table
+        .modify((0, 0), Span::column(2))
+        .modify((1, 2), Span::row(2));
+
+    assert_eq!(
+        table.to_grid(),
+        [
+            ["    N    ", "", " column 1 "],
+            [" 0 ", " 0-0 ", "   0-1    \n          \n          "],
+            [" 1 ", " 1-0 ", ""],
+        ]
+    );
+}
+
+#[test]
+fn table_to_grid_alignment_test() {
+    let mut table = Table::new([["Hello\nWorld", "  text  ", "1"]]);
+    table
+        .modify((1, 0), Alignment::right())
+        .modify((1, 1), Alignment::center_vertical())
+        .modify((1, 1), TrimStrategy::Horizontal)
+        .modify((1, 2), Alignment::bottom())
+        .modify((1, 2), Padding::new(1, 1, 0, 0).fill('>', '<', ' ', ' '));
+
+    assert_eq!(
+        table.to_grid(),
+        [
+            [" 0     ", " 1        ", " 2 "],
+            [" Hello \n World ", " text     \n          ", "   \n>1<"],
+        ]
+    );
+
+    let lines = table.to_string();
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines[3], "| Hello | text     |   |");
+    assert_eq!(lines[4], "| World |          |>1<|");
+}
+
+#[test]
+fn table_to_grid_empty_test() {
+    assert!(Table::default().to_grid().is_empty());
+}
+
+#[test]
+fn table_cell_dimension_test() {
+    let mut table = Matrix::table(2, 2);
+    table
+        .with(Modify::new(Cell::new(1, 1)).with(Width::wrap(2)))
+        .with(Modify::new(Cell::new(2, 2)).with(Padding::new(2, 3, 1, 1)));
+
+    assert_eq!(table.cell_dimension((1, 1)), (4, 2));
+    assert_eq!(table.cell_dimension((2, 2)), (8, 3));
+    assert_eq!(table.cell_dimension((0, 0)), (3, 1));
+}
+
+#[test]
+fn table_cell_dimension_multiline_test() {
+    let mut table = Table::new([["Hello World\nHi"]]);
+    table.with(Modify::new(Cell::new(1, 0)).with(Width::wrap(5)));
+
+    assert_eq!(table.cell_dimension((1, 0)), (7, 4));
+    assert_eq!(table.column_widths(), vec![7]);
+}
+
+#[test]
+fn table_measure_test() {
+    let mut modern = Matrix::table(2, 2);
+    modern.with(Style::modern());
+
+    let mut blank = Matrix::table(2, 2);
+    blank.with(Style::blank());
+
+    let mut margin = Matrix::table(2, 2);
+    margin
+        .with(Margin::new(1, 2, 3, 4))
+        .with(Padding::new(2, 2, 1, 1));
+
+    let mut panel = Matrix::table(2, 3);
+    panel
+        .with(Panel::header("A long header line which spans"))
+        .modify(Cell::new(2, 1), Span::column(2));
+
+    let multiline = Table::new([["Hello World\nHi", "multi\nline\ntext"]]);
+
+    let tables = [Matrix::table(3, 3), modern, blank, margin, panel, multiline];
+
+    for table in &tables {
+        let output = table.to_string();
+        let width = output.split('\n').map(string_width).max().unwrap_or(0);
+        let height = output.split('\n').count();
+
+        let (count_rows, count_cols) = table.shape();
+        assert_eq!(table.measure(), (count_rows, count_cols, width, height));
+    }
+}
+
+#[test]
+fn table_measure_empty_test() {
+    assert_eq!(Table::default().measure(), (0, 0, 0, 0));
+    assert_eq!(Table::default().to_string(), "");
+}
+
+test_table!(
+    table_remove_header_test,
+    Matrix::new(3, 2).with(Style::psql()).remove_header(),
+    " 0 | 0-0 | 0-1 "
+    " 1 | 1-0 | 1-1 "
+    " 2 | 2-0 | 2-1 "
+);
+
+test_table!(
+    table_remove_header_recomputes_widths_test,
+    Table::new([("a", 1), ("bb", 2)]).remove_header(),
+    "+----+---+"
+    "| a  | 1 |"
+    "+----+---+"
+    "| bb | 2 |"
+    "+----+---+"
+);
+
+test_table!(
+    table_remove_header_shifts_lines_test,
+    Matrix::new(3, 2)
+        .with(Style::modern().remove_horizontal().horizontals([
+            (1, HorizontalLine::full('=', '╞', '╡', '╪')),
+            (3, HorizontalLine::full('-', '├', '┤', '┼')),
+        ]))
+        .remove_header(),
+    "┌───┬─────┬─────┐"
+    "│ 0 │ 0-0 │ 0-1 │"
+    "│ 1 │ 1-0 │ 1-1 │"
+    "├---┼-----┼-----┤"
+    "│ 2 │ 2-0 │ 2-1 │"
+    "└───┴─────┴─────┘"
+);
+
+test_table!(
+    table_remove_header_empty_test,
+    Table::default().remove_header(),
+    ""
+);
+
+#[test]
+fn table_new_borrowed_test() {
+    let data = vec![
+        (String::from("Hello World"), "1"),
+        (String::from("Hi"), "2"),
+    ];
+
+    let mut owned = Table::new(&data);
+    let mut borrowed = Table::new_borrowed(&data);
+
+    assert_eq!(borrowed.to_string(), owned.to_string());
+    assert_eq!(
+        borrowed.get_records()[1][0].as_ref().as_ptr(),
+        data[0].0.as_ptr()
+    );
+
+    owned
+        .with(Style::psql())
+        .with(Modify::new(Columns::last()).with(Alignment::right()))
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(5)));
+    borrowed
+        .with(Style::psql())
+        .with(Modify::new(Columns::last()).with(Alignment::right()))
+        .with(Modify::new(Rows::new(1..)).with(Width::wrap(5)));
+
+    assert_eq!(borrowed.to_string(), owned.to_string());
+    assert_eq!(
+        borrowed.to_string(),
+        static_table!(
+            " String | &str "
+            "--------+------"
+            " Hello  |    1 "
+            "  Worl  |      "
+            " d      |      "
+            " Hi     |    2 "
+        )
+    );
+    assert_eq!(
+        borrowed.get_records()[2][0].as_ref().as_ptr(),
+        data[1].0.as_ptr()
+    );
+}
@@ -1062,6 +1062,40 @@ fn test_order_skip_usage() {
     assert_eq!(Example::default().fields(), vec!["", ""]);
 }
 
+#[test]
+fn test_map_order_is_stable_across_builds() {
+    use std::collections::HashMap;
+
+    #[derive(Tabled)]
+    struct Example {
+        id: u8,
+        #[tabled(map)]
+        labels: HashMap<String, usize>,
+    }
+
+    let keys = [
+        "zone", "app", "tier", "owner", "env", "region", "build", "cost",
+    ];
+
+    for _ in 0..16 {
+        // each map gets its own random state, so an iteration order changes between them.
+        let labels = keys
+            .iter()
+            .map(|key| (key.to_string(), key.len()))
+            .collect();
+        let value = Example { id: 1, labels };
+
+        assert_eq!(Example::headers(), vec!["id", "labels"]);
+        assert_eq!(
+            value.fields(),
+            vec![
+                "1",
+                "app: 3\nbuild: 5\ncost: 4\nenv: 3\nowner: 5\nregion: 6\ntier: 4\nzone: 4"
+            ]
+        );
+    }
+}
+
 #[test]
 fn test_skip_enum_0() {
     #[allow(dead_code)]
@@ -1,9 +1,13 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    location::ByColumnName,
-    object::{Columns, Rows, Segment},
-    Alignment, Modify, Padding, Style,
+use tabled::{
+    settings::{
+        formatting::AlignmentStrategy,
+        location::ByColumnName,
+        object::{Columns, Rows, Segment},
+        Alignment, Modify, Padding, Style,
+    },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -35,6 +39,81 @@ test_table!(
     "+---+----------+----------+----------+"
 );
 
+test_table!(
+    data_rows_alignment,
+    Matrix::new(3, 3)
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Rows::data()).with(Alignment::right())),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |      0-0 |      0-1 |      0-2 |"
+    "+---+----------+----------+----------+"
+    "| 1 |      1-0 |      1-1 |      1-2 |"
+    "+---+----------+----------+----------+"
+    "| 2 |      2-0 |      2-1 |      2-2 |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    column_by_name_alignment,
+    Matrix::new(3, 3)
+        .with(Modify::new(Columns::by_name("column 1")).with(Alignment::right())),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |      0-1 |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |      1-1 |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |      2-1 |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    column_by_name_not_existing_alignment,
+    Matrix::new(3, 3)
+        .with(Modify::new(Columns::by_name("column 9")).with(Alignment::right())),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+#[test]
+fn column_by_name_try_modify_not_existing_alignment() {
+    let mut table = Matrix::new(3, 3).to_table();
+    let expected = table.to_string();
+
+    let result = table.try_modify(Columns::by_name("column 9"), Alignment::right());
+
+    assert_eq!(result.err(), Some(Columns::by_name("column 9")));
+    assert_eq!(table.to_string(), expected);
+}
+
+test_table!(
+    auto_alignment,
+    Matrix::new(3, 1)
+        .insert((1, 1), "12")
+        .insert((2, 1), "abc")
+        .insert((3, 1), "-3.5")
+        .with(Modify::new(Columns::single(1)).with(Alignment::auto())),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |       12 |"
+    "+---+----------+"
+    "| 1 | abc      |"
+    "+---+----------+"
+    "| 2 |     -3.5 |"
+    "+---+----------+"
+);
+
 test_table!(
     full_alignment_multiline,
     Matrix::new(3, 3).insert((3, 2), "https://\nwww\n.\nredhat\n.com\n/en")
@@ -136,3 +215,39 @@ test_table!(
         " 1 |   1-0    |   1-1    |   1-2    "
         " 2 |   2-0    |   2-1    |   2-2    "
 );
+
+test_table!(
+    center_wide_chars,
+    Table::new([["你好"], ["你好吗"], ["a你"], ["123456"]])
+        .with(Style::ascii())
+        .with(Alignment::center())
+        .with(Padding::zero()),
+    "+------+"
+    "|  0   |"
+    "+------+"
+    "| 你好 |"
+    "+------+"
+    "|你好吗|"
+    "+------+"
+    "| a你  |"
+    "+------+"
+    "|123456|"
+    "+------+"
+);
+
+test_table!(
+    center_wide_chars_multiline,
+    Table::new([["你好\nab"], ["12345"]])
+        .with(Style::ascii())
+        .with(Alignment::center())
+        .with(AlignmentStrategy::PerLine)
+        .with(Padding::zero()),
+    "+-----+"
+    "|  0  |"
+    "+-----+"
+    "|你好 |"
+    "| ab  |"
+    "+-----+"
+    "|12345|"
+    "+-----+"
+);
@@ -0,0 +1,120 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::AnsiStr;
+
+#[test]
+fn ansi_str_width_test() {
+    assert_eq!(AnsiStr::new("").width(), 0);
+    assert_eq!(AnsiStr::new("Hello World").width(), 11);
+    assert_eq!(AnsiStr::new("Hello\nWorld!!").width(), 7);
+    assert_eq!(AnsiStr::new("こんにちは").width(), 10);
+    assert_eq!(AnsiStr::new("🎉🎉").width(), 4);
+}
+
+#[test]
+fn ansi_str_truncate_test() {
+    let text = AnsiStr::new("Hello World");
+
+    assert_eq!(text.truncate_to(0), "");
+    assert_eq!(text.truncate_to(5), "Hello");
+    assert_eq!(text.truncate_to(11), "Hello World");
+    assert_eq!(text.truncate_to(100), "Hello World");
+}
+
+#[test]
+fn ansi_str_truncate_wide_chars_test() {
+    let text = AnsiStr::new("こんにちは");
+
+    assert_eq!(text.truncate_to(4), "こん");
+    assert_eq!(text.truncate_to(5), "こん\u{FFFD}");
+    assert_eq!(AnsiStr::new(text.truncate_to(5).as_ref()).width(), 5);
+}
+
+#[test]
+fn ansi_str_split_test() {
+    let text = AnsiStr::new("Hello World");
+
+    assert_eq!(text.split_at_width(0), ("".into(), "Hello World".into()));
+    assert_eq!(text.split_at_width(6), ("Hello ".into(), "World".into()));
+    assert_eq!(text.split_at_width(11), ("Hello World".into(), "".into()));
+    assert_eq!(text.split_at_width(20), ("Hello World".into(), "".into()));
+}
+
+#[test]
+fn ansi_str_split_wide_chars_test() {
+    let text = AnsiStr::new("こんにちは");
+
+    assert_eq!(text.split_at_width(4), ("こん".into(), "にちは".into()));
+    assert_eq!(
+        text.split_at_width(5),
+        ("こん\u{FFFD}".into(), "\u{FFFD}ちは".into())
+    );
+}
+
+#[cfg(feature = "color")]
+mod color {
+    use super::*;
+
+    #[test]
+    fn ansi_str_width_test() {
+        assert_eq!(AnsiStr::new("\u{1b}[31mHello\u{1b}[39m").width(), 5);
+        assert_eq!(
+            AnsiStr::new("\u{1b}[1m\u{1b}[31mHello\u{1b}[39m World\u{1b}[22m").width(),
+            11
+        );
+        assert_eq!(AnsiStr::new("\u{1b}[32mこん\u{1b}[39m\nにちは").width(), 6);
+    }
+
+    #[test]
+    fn ansi_str_truncate_test() {
+        let text = AnsiStr::new("\u{1b}[31mHello World\u{1b}[39m");
+
+        assert_eq!(text.truncate_to(0), "");
+        assert_eq!(text.truncate_to(5), "\u{1b}[31mHello\u{1b}[39m");
+        assert_eq!(text.truncate_to(11), "\u{1b}[31mHello World\u{1b}[39m");
+    }
+
+    #[test]
+    fn ansi_str_truncate_nested_test() {
+        let text = AnsiStr::new("\u{1b}[1m\u{1b}[31mHello\u{1b}[39m World\u{1b}[22m");
+
+        for width in 0..=11 {
+            let cut = text.truncate_to(width);
+            assert_eq!(AnsiStr::new(&cut).width(), width);
+        }
+
+        let cut = text.truncate_to(8);
+        assert!(cut.starts_with("\u{1b}[1m\u{1b}[31mHello\u{1b}[39m"));
+        assert!(cut.contains(" Wo"));
+        assert!(!cut.contains("World"));
+    }
+
+    #[test]
+    fn ansi_str_split_nested_test() {
+        let text = AnsiStr::new("\u{1b}[1m\u{1b}[31mHello\u{1b}[39m World\u{1b}[22m");
+
+        for width in 0..=11 {
+            let (left, right) = text.split_at_width(width);
+            assert_eq!(AnsiStr::new(&left).width(), width);
+            assert_eq!(AnsiStr::new(&right).width(), 11 - width);
+        }
+
+        let (left, right) = text.split_at_width(3);
+        assert!(left.starts_with("\u{1b}[1m\u{1b}[31mHel"));
+        assert!(right.starts_with('\u{1b}'));
+        assert!(right.contains("lo"));
+        assert!(right.contains(" World"));
+    }
+
+    #[test]
+    fn ansi_str_split_wide_chars_test() {
+        let text = AnsiStr::new("\u{1b}[34mこんにちは\u{1b}[39m");
+
+        let (left, right) = text.split_at_width(5);
+        assert_eq!(AnsiStr::new(&left).width(), 5);
+        assert_eq!(AnsiStr::new(&right).width(), 5);
+        assert!(left.contains("こん\u{FFFD}"));
+        assert!(right.contains("\u{FFFD}"));
+        assert!(right.contains("ちは"));
+    }
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{object::Columns, Alignment, Caption, Modify, Style};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    caption_top_center,
+    Matrix::new(2, 3).with(Caption::new("Figure 1").top().center()),
+    "               Figure 1               "
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    caption_top_left,
+    Matrix::new(2, 3).with(Caption::new("Figure 1")),
+    "Figure 1                              "
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    caption_bottom_right,
+    Matrix::new(2, 3)
+        .with(Style::modern())
+        .with(Caption::new("Figure 1").bottom().right()),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+    "                              Figure 1"
+);
+
+test_table!(
+    caption_bottom_center,
+    Matrix::new(2, 2)
+        .with(Style::rounded())
+        .with(Caption::new("Table 1: Results").bottom().center()),
+    "╭───┬──────────┬──────────╮"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "│ 1 │   1-0    │   1-1    │"
+    "╰───┴──────────┴──────────╯"
+    "     Table 1: Results      "
+);
+
+test_table!(
+    caption_multiline,
+    Matrix::new(1, 3)
+        .with(Style::psql())
+        .with(Caption::new("Figure 1\nA list of cells").center()),
+    "              Figure 1              "
+    "          A list of cells           "
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 |   0-0    |   0-1    |   0-2    "
+);
+
+test_table!(
+    caption_overflow,
+    Matrix::new(1, 1).with(Caption::new("A caption which is wider than a table")),
+    "A caption which is wider than a table"
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
+
+test_table!(
+    caption_widen,
+    Matrix::new(1, 1)
+        .with(Caption::new("A caption which is wider than a table").widen(true)),
+    "A caption which is wider than a table"
+    "+--------------+--------------------+"
+    "|      N       |      column 0      |"
+    "+--------------+--------------------+"
+    "|      0       |        0-0         |"
+    "+--------------+--------------------+"
+);
+
+test_table!(
+    caption_keeps_cells,
+    Matrix::new(2, 2)
+        .with(Caption::new("Figure 1").center())
+        .with(Style::markdown())
+        .with(Modify::new(Columns::new(1..)).with(Alignment::right())),
+    "         Figure 1          "
+    "| N | column 0 | column 1 |"
+    "|---|----------|----------|"
+    "| 0 |      0-0 |      0-1 |"
+    "| 1 |      1-0 |      1-1 |"
+);
+
+test_table!(
+    caption_top_and_bottom,
+    Matrix::new(1, 1)
+        .with(Style::psql())
+        .with(Caption::new("top").center())
+        .with(Caption::new("bottom").bottom().right()),
+    "     top      "
+    " N | column 0 "
+    "---+----------"
+    " 0 |   0-0    "
+    "        bottom"
+);
+
+#[test]
+fn caption_measure_test() {
+    let mut table = Matrix::table(1, 1);
+    table.with(Caption::new("A caption\nwhich is wider than a table"));
+
+    assert_eq!(table.measure(), (2, 2, 27, 7));
+    assert_eq!(table.total_width(), 27);
+    assert_eq!(table.total_height(), 7);
+    assert_eq!(
+        table.to_grid(),
+        [[" N ", " column 0 "], [" 0 ", "   0-0    "]].map(|row| row.map(String::from).to_vec())
+    );
+}
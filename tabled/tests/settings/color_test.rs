@@ -1,9 +1,18 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{Color, Modify};
+use tabled::{
+    settings::{
+        highlight::HighlightText,
+        object::{Columns, Rows},
+        style::BorderColor,
+        themes::Colorization,
+        Alignment, Color, Highlight, Margin, Modify, Span,
+    },
+    Table,
+};
 
 use crate::matrix::Matrix;
-use testing_table::test_table;
+use testing_table::{static_table, test_table};
 
 test_table!(
     color_global,
@@ -32,3 +41,177 @@ test_table!(
     "| 2 |   2-0    |   2-1    |   2-2    |"
     "+---+----------+----------+----------+"
 );
+
+#[test]
+fn color_disabled() {
+    let mut table = Matrix::table(2, 2);
+    table
+        .with(Colorization::rows([Color::BG_BLUE, Color::FG_RED]))
+        .with(Highlight::color(
+            Rows::first(),
+            BorderColor::filled(Color::FG_GREEN),
+        ))
+        .with(Margin::new(1, 1, 0, 0).colorize(
+            Color::BG_RED,
+            Color::BG_RED,
+            Color::BG_RED,
+            Color::BG_RED,
+        ));
+
+    let colored = table.to_string();
+    assert!(colored.contains('\u{1b}'));
+
+    table.set_color_enabled(false);
+
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            " +---+----------+----------+ "
+            " | N | column 0 | column 1 | "
+            " +---+----------+----------+ "
+            " | 0 |   0-0    |   0-1    | "
+            " +---+----------+----------+ "
+            " | 1 |   1-0    |   1-1    | "
+            " +---+----------+----------+ "
+        )
+    );
+
+    table.set_color_enabled(true);
+
+    assert_eq!(table.to_string(), colored);
+}
+
+#[test]
+fn highlight_text_content() {
+    let mut table = Table::new([("db", "ERR: disk full, ERR: retry"), ("web", "ok")]);
+    table.with(Modify::new(Columns::single(1)).with(HighlightText::new("ERR", Color::FG_RED)));
+
+    let cells: Vec<_> = table.cells().map(|(_, text)| text).collect();
+    assert_eq!(
+        cells,
+        [
+            "&str",
+            "&str",
+            "db",
+            "\u{1b}[31mERR\u{1b}[39m: disk full, \u{1b}[31mERR\u{1b}[39m: retry",
+            "web",
+            "ok",
+        ]
+    );
+}
+
+#[test]
+fn highlight_text_skips_ansi_sequences() {
+    let mut table = Table::new([["\u{1b}[31mred\u{1b}[39m 31m"], ["\u{1b}[1mE\u{1b}[22mRR"]]);
+    table.with(HighlightText::new("31m", Color::FG_BLUE));
+    table.with(HighlightText::new("ERR", Color::FG_GREEN));
+
+    let cells: Vec<_> = table.cells().map(|(_, text)| text).collect();
+    assert_eq!(
+        cells,
+        [
+            "0",
+            "\u{1b}[31mred\u{1b}[39m \u{1b}[34m31m\u{1b}[39m",
+            "\u{1b}[1m\u{1b}[32mE\u{1b}[22mRR\u{1b}[39m",
+        ]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn highlight_text_regex() {
+    let mut table = Table::new([("db", "ERR: disk full, WARN: retry"), ("web", "ok")]);
+    table.with(Modify::new(Columns::single(1)).with(HighlightText::regex(
+        regex::Regex::new("ERR|WARN").unwrap(),
+        Color::FG_RED,
+    )));
+
+    let cells: Vec<_> = table.cells().map(|(_, text)| text).collect();
+    assert_eq!(
+        cells,
+        [
+            "&str",
+            "&str",
+            "db",
+            "\u{1b}[31mERR\u{1b}[39m: disk full, \u{1b}[31mWARN\u{1b}[39m: retry",
+            "web",
+            "ok",
+        ]
+    );
+}
+
+#[cfg(feature = "color")]
+test_table!(
+    highlight_text,
+    Table::new([("db", "ERR: disk full"), ("web", "ok")])
+        .with(Modify::new(Columns::single(1)).with(HighlightText::new("ERR", Color::FG_RED))),
+    "+------+----------------+"
+    "| &str | &str           |"
+    "+------+----------------+"
+    "| db   | \u{1b}[31mERR\u{1b}[39m: disk full |"
+    "+------+----------------+"
+    "| web  | ok             |"
+    "+------+----------------+"
+);
+
+test_table!(
+    diff_changed_cell,
+    {
+        let mut old = Matrix::table(2, 2);
+        let mut new = Matrix::table(2, 2);
+        old.with(Modify::new((2, 1)).with("1-0"));
+        new.with(Modify::new((2, 1)).with("x"));
+        new.diff(&old)
+    },
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |    \u{1b}[33mx\u{1b}[39m     |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    diff_added_and_missing_cells,
+    Table::new([("a", "b"), ("c", "d")]).diff(&Table::new([("a", "b", "c")])),
+    "+------+------+------+"
+    "| &str | &str | \u{1b}[31m&str\u{1b}[39m |"
+    "+------+------+------+"
+    "| a    | b    | \u{1b}[31mc\u{1b}[39m    |"
+    "+------+------+------+"
+    "| \u{1b}[32mc\u{1b}[39m    | \u{1b}[32md\u{1b}[39m    |      |"
+    "+------+------+------+"
+);
+
+test_table!(
+    remove_header_shifts_row_settings,
+    Matrix::table(3, 2)
+        .with(Modify::new((1, 0)).with(Span::column(2)))
+        .with(Modify::new(Rows::single(1)).with(Alignment::right()))
+        .with(Modify::new(Rows::single(3)).with(Color::FG_RED))
+        .remove_header(),
+    "+---+-----+-----+"
+    "|       0 | 0-1 |"
+    "+---+-----+-----+"
+    "| 1 | 1-0 | 1-1 |"
+    "+---+-----+-----+"
+    "| \u{1b}[31m2\u{1b}[39m | \u{1b}[31m2-0\u{1b}[39m | \u{1b}[31m2-1\u{1b}[39m |"
+    "+---+-----+-----+"
+);
+
+test_table!(
+    head_drops_colors,
+    Matrix::table(3, 1)
+        .with(Modify::new(Rows::new(2..)).with(Color::FG_RED))
+        .head(3),
+    "+---+----------+"
+    "| N | column 0 |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+    "| \u{1b}[31m1\u{1b}[39m |   \u{1b}[31m1-0\u{1b}[39m    |"
+    "+---+----------+"
+    "| … |    …     |"
+    "+---+----------+"
+);
@@ -1,10 +1,11 @@
 #![cfg(feature = "std")]
 
 use tabled::settings::{
-    object::{Cell, Object},
-    themes::Colorization,
-    Color,
+    object::{Cell, Columns, Object, Rows, Segment},
+    themes::{Colorization, MapColors, Rules, StyleRowsBy},
+    Color, Modify,
 };
+use tabled::Table;
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -51,6 +52,155 @@ test_table!(
     "+---+----------+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41mN\u{1b}[49m\u{1b}[41m \u{1b}[49m| column 0 | column 1 | column 2 |\n+---+----------+----------+----------+\n| 0 |\u{1b}[106m \u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m0-0\u{1b}[49m\u{1b}[106m   \u{1b}[49m\u{1b}[106m \u{1b}[49m|   0-1    |   0-2    |\n+---+----------+----------+----------+\n| 1 |   1-0    |\u{1b}[1m \u{1b}[22m\u{1b}[1m  \u{1b}[22m\u{1b}[1m1-1\u{1b}[22m\u{1b}[1m   \u{1b}[22m\u{1b}[1m \u{1b}[22m|   1-2    |\n+---+----------+----------+----------+"
 );
 
+test_table!(
+    columns_cell_option,
+    Matrix::new(2, 4).with(Modify::new(Segment::all()).with(Colorization::columns([
+        Color::BG_BRIGHT_BLACK,
+        Color::BG_WHITE
+    ]))),
+    "+---+----------+----------+----------+----------+\n|\u{1b}[100m \u{1b}[49m\u{1b}[100mN\u{1b}[49m\u{1b}[100m \u{1b}[49m|\u{1b}[47m \u{1b}[49m\u{1b}[47mcolumn 0\u{1b}[49m\u{1b}[47m \u{1b}[49m|\u{1b}[100m \u{1b}[49m\u{1b}[100mcolumn 1\u{1b}[49m\u{1b}[100m \u{1b}[49m|\u{1b}[47m \u{1b}[49m\u{1b}[47mcolumn 2\u{1b}[49m\u{1b}[47m \u{1b}[49m|\u{1b}[100m \u{1b}[49m\u{1b}[100mcolumn 3\u{1b}[49m\u{1b}[100m \u{1b}[49m|\n+---+----------+----------+----------+----------+\n|\u{1b}[100m \u{1b}[49m\u{1b}[100m0\u{1b}[49m\u{1b}[100m \u{1b}[49m|\u{1b}[47m \u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m0-0\u{1b}[49m\u{1b}[47m   \u{1b}[49m\u{1b}[47m \u{1b}[49m|\u{1b}[100m \u{1b}[49m\u{1b}[100m  \u{1b}[49m\u{1b}[100m0-1\u{1b}[49m\u{1b}[100m   \u{1b}[49m\u{1b}[100m \u{1b}[49m|\u{1b}[47m \u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m0-2\u{1b}[49m\u{1b}[47m   \u{1b}[49m\u{1b}[47m \u{1b}[49m|\u{1b}[100m \u{1b}[49m\u{1b}[100m  \u{1b}[49m\u{1b}[100m0-3\u{1b}[49m\u{1b}[100m   \u{1b}[49m\u{1b}[100m \u{1b}[49m|\n+---+----------+----------+----------+----------+\n|\u{1b}[100m \u{1b}[49m\u{1b}[100m1\u{1b}[49m\u{1b}[100m \u{1b}[49m|\u{1b}[47m \u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m1-0\u{1b}[49m\u{1b}[47m   \u{1b}[49m\u{1b}[47m \u{1b}[49m|\u{1b}[100m \u{1b}[49m\u{1b}[100m  \u{1b}[49m\u{1b}[100m1-1\u{1b}[49m\u{1b}[100m   \u{1b}[49m\u{1b}[100m \u{1b}[49m|\u{1b}[47m \u{1b}[49m\u{1b}[47m  \u{1b}[49m\u{1b}[47m1-2\u{1b}[49m\u{1b}[47m   \u{1b}[49m\u{1b}[47m \u{1b}[49m|\u{1b}[100m \u{1b}[49m\u{1b}[100m  \u{1b}[49m\u{1b}[100m1-3\u{1b}[49m\u{1b}[100m   \u{1b}[49m\u{1b}[100m \u{1b}[49m|\n+---+----------+----------+----------+----------+"
+);
+
+#[test]
+fn chess_cell_option() {
+    let expected = Matrix::new(3, 3)
+        .with(Colorization::chess(color1(), color2()))
+        .to_string();
+    let table = Matrix::new(3, 3)
+        .with(Modify::new(Segment::all()).with(Colorization::chess(color1(), color2())))
+        .to_string();
+
+    assert_eq!(table, expected);
+}
+
+test_table!(
+    map_colors,
+    Matrix::new(3, 2)
+        .insert((1, 2), "OK")
+        .insert((2, 2), " FAIL ")
+        .insert((3, 2), "OKAY")
+        .with(Modify::new(Columns::single(2)).with(MapColors::new([("OK", color1()), ("FAIL", color2())]))),
+    "+---+----------+----------+\n| N | column 0 | column 1 |\n+---+----------+----------+\n| 0 |   0-0    |\u{1b}[41m \u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41mOK\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+\n| 1 |   1-0    |\u{1b}[106m \u{1b}[49m\u{1b}[106m \u{1b}[49m\u{1b}[106m FAIL \u{1b}[49m\u{1b}[106m \u{1b}[49m\u{1b}[106m \u{1b}[49m|\n+---+----------+----------+\n| 2 |   2-0    |   OKAY   |\n+---+----------+----------+"
+);
+
+test_table!(
+    map_colors_skips_header,
+    Matrix::new(1, 2)
+        .insert((0, 2), "OK")
+        .insert((1, 2), "OK")
+        .with(MapColors::new([("OK", color1())])),
+    "+---+----------+----+"
+    "| N | column 0 | OK |"
+    "+---+----------+----+"
+    "| 0 |   0-0    |\u{1b}[41m \u{1b}[49m\u{1b}[41mOK\u{1b}[49m\u{1b}[41m \u{1b}[49m|"
+    "+---+----------+----+"
+);
+
+test_table!(
+    map_colors_with_header,
+    Matrix::new(1, 2)
+        .insert((0, 2), "OK")
+        .insert((1, 2), "OK")
+        .with(MapColors::new([("OK", color1())]).header(true)),
+    "+---+----------+----+"
+    "| N | column 0 |\u{1b}[41m \u{1b}[49m\u{1b}[41mOK\u{1b}[49m\u{1b}[41m \u{1b}[49m|"
+    "+---+----------+----+"
+    "| 0 |   0-0    |\u{1b}[41m \u{1b}[49m\u{1b}[41mOK\u{1b}[49m\u{1b}[41m \u{1b}[49m|"
+    "+---+----------+----+"
+);
+
+test_table!(
+    style_rows_by,
+    Matrix::new(3, 2)
+        .insert((1, 2), "UP")
+        .insert((2, 2), "DOWN")
+        .insert((3, 2), "UP")
+        .with(Modify::new(Cell::new(2, 1)).with(color2()))
+        .with(StyleRowsBy::new(|row: &[&str]| (row[2] == "DOWN").then(color1))),
+    "+---+----------+----------+\n| N | column 0 | column 1 |\n+---+----------+----------+\n| 0 |   0-0    |    UP    |\n+---+----------+----------+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m1\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m1-0\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41mDOWN\u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+---+----------+----------+\n| 2 |   2-0    |    UP    |\n+---+----------+----------+"
+);
+
+test_table!(
+    style_rows_by_skips_header,
+    Matrix::new(1, 2).with(StyleRowsBy::new(|_: &[&str]| Some(color1()))),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "|\u{1b}[41m \u{1b}[49m\u{1b}[41m0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-0\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m  \u{1b}[49m\u{1b}[41m0-1\u{1b}[49m\u{1b}[41m   \u{1b}[49m\u{1b}[41m \u{1b}[49m|"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    style_rows_by_with_header,
+    Matrix::new(1, 2).with(StyleRowsBy::new(|row: &[&str]| (row[0] == "N").then(color1)).header(true)),
+    "+---+----------+----------+"
+    "|\u{1b}[41m \u{1b}[49m\u{1b}[41mN\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41mcolumn 0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41mcolumn 1\u{1b}[49m\u{1b}[41m \u{1b}[49m|"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    style_rows_by_none,
+    Matrix::new(2, 2).with(StyleRowsBy::new(|_: &[&str]| None)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    exact_header_background,
+    Matrix::new(1, 2).with(Colorization::exact([Color::BG_BLUE], Rows::first())),
+    "+---+----------+----------+\n|\u{1b}[44m \u{1b}[49m\u{1b}[44mN\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 0\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 1\u{1b}[49m\u{1b}[44m \u{1b}[49m|\n+---+----------+----------+\n| 0 |   0-0    |   0-1    |\n+---+----------+----------+"
+);
+
+test_table!(
+    rows_header_background,
+    Matrix::new(1, 2).with(Colorization::rows([Color::BG_BLUE, Color::default()])),
+    "+---+----------+----------+\n|\u{1b}[44m \u{1b}[49m\u{1b}[44mN\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 0\u{1b}[49m\u{1b}[44m \u{1b}[49m|\u{1b}[44m \u{1b}[49m\u{1b}[44mcolumn 1\u{1b}[49m\u{1b}[44m \u{1b}[49m|\n+---+----------+----------+\n| 0 |   0-0    |   0-1    |\n+---+----------+----------+"
+);
+
+test_table!(
+    rules_first_match_wins,
+    Table::new([["0-0", "0-1"]]).with(
+        Rules::new()
+            .when(|(row, _), _| row == 1)
+            .then(color1())
+            .when(|(_, col), _| col == 1)
+            .then(color2())
+    ),
+    "+-----+-----+\n| 0   |\u{1b}[106m \u{1b}[49m\u{1b}[106m1\u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m \u{1b}[49m|\n+-----+-----+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0-0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m \u{1b}[49m\u{1b}[41m0-1\u{1b}[49m\u{1b}[41m \u{1b}[49m|\n+-----+-----+"
+);
+
+test_table!(
+    rules_all_matches_are_combined,
+    Table::new([["0-0", "0-1"]]).with(
+        Rules::new()
+            .when(|(row, _), _| row == 1)
+            .then(color1())
+            .when(|(_, col), _| col == 1)
+            .then(color2())
+            .all_matches()
+    ),
+    "+-----+-----+\n| 0   |\u{1b}[106m \u{1b}[49m\u{1b}[106m1\u{1b}[49m\u{1b}[106m  \u{1b}[49m\u{1b}[106m \u{1b}[49m|\n+-----+-----+\n|\u{1b}[41m \u{1b}[49m\u{1b}[41m0-0\u{1b}[49m\u{1b}[41m \u{1b}[49m|\u{1b}[41m\u{1b}[106m \u{1b}[49m\u{1b}[41m\u{1b}[106m0-1\u{1b}[49m\u{1b}[41m\u{1b}[106m \u{1b}[49m|\n+-----+-----+"
+);
+
+test_table!(
+    rules_by_content,
+    Matrix::new(2, 2).with(Rules::new().when(|_, text| text == "none").then(color1())),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
 fn color1() -> Color {
     Color::BG_RED
 }
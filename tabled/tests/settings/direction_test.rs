@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{
+    object::Columns, Alignment, Border, Direction, Margin, Padding, Panel, Style,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    direction_rtl,
+    Matrix::new(3, 3).with(Direction::Rtl),
+    "+----------+----------+----------+---+"
+    "| column 2 | column 1 | column 0 | N |"
+    "+----------+----------+----------+---+"
+    "|   0-2    |   0-1    |   0-0    | 0 |"
+    "+----------+----------+----------+---+"
+    "|   1-2    |   1-1    |   1-0    | 1 |"
+    "+----------+----------+----------+---+"
+    "|   2-2    |   2-1    |   2-0    | 2 |"
+    "+----------+----------+----------+---+"
+);
+
+test_table!(
+    direction_ltr,
+    Matrix::new(3, 3).with(Direction::Ltr),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    direction_rtl_twice,
+    Matrix::new(3, 3).with(Direction::Rtl).with(Direction::Rtl),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    direction_rtl_borders,
+    Matrix::new(2, 2)
+        .with(Style::modern().left('[').corner_top_left('<'))
+        .with(Margin::new(3, 0, 0, 0))
+        .with(Direction::Rtl),
+    "┌──────────┬──────────┬───>   "
+    "│ column 1 │ column 0 │ N ]   "
+    "├──────────┼──────────┼───]   "
+    "│   0-1    │   0-0    │ 0 ]   "
+    "├──────────┼──────────┼───]   "
+    "│   1-1    │   1-0    │ 1 ]   "
+    "└──────────┴──────────┴───]   "
+);
+
+test_table!(
+    direction_rtl_span,
+    Matrix::new(2, 2).with(Panel::vertical(0, "Panel")).with(Direction::Rtl),
+    "+----------+----------+---+-------+"
+    "| column 1 | column 0 | N | Panel |"
+    "+----------+----------+---+       +"
+    "|   0-1    |   0-0    | 0 |       |"
+    "+----------+----------+---+       +"
+    "|   1-1    |   1-0    | 1 |       |"
+    "+----------+----------+---+-------+"
+);
+
+test_table!(
+    direction_rtl_moves_column_settings,
+    Matrix::table(2, 2)
+        .modify(Columns::single(1), Alignment::left())
+        .modify(Columns::single(1), Padding::new(3, 0, 0, 0))
+        .modify((1, 0), Border::filled('#'))
+        .with(Direction::Rtl),
+    "+----------+-----------+---+"
+    "| column 1 |column 0   | N |"
+    "+----------+-----------#####"
+    "|   0-1    |     0-0   # 0 #"
+    "+----------+-----------#####"
+    "|   1-1    |     1-0   | 1 |"
+    "+----------+-----------+---+"
+);
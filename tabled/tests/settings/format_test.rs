@@ -1,8 +1,15 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    object::{Cell, Columns, Object, Rows, Segment},
-    Alignment, Format, Modify, Padding, Style,
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        format::{Hyperlink, NumberFormat, ZeroPad},
+        object::{Cell, Columns, Object, Rows, Segment},
+        Alignment, Format, Modify, Padding, Style,
+    },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -11,6 +18,29 @@ use testing_table::test_table;
 #[cfg(feature = "color")]
 use owo_colors::OwoColorize;
 
+test_table!(
+    formatting_uppercase_header_test,
+    Builder::from_iter([["straße", "nr"], ["Markt", "3"]])
+        .build()
+        .with(Style::modern())
+        .with(Modify::new(Rows::first()).with(Format::content(|s| s.to_uppercase()))),
+    "┌─────────┬────┐"
+    "│ STRASSE │ NR │"
+    "├─────────┼────┤"
+    "│ Markt   │ 3  │"
+    "└─────────┴────┘"
+);
+
+test_table!(
+    formatting_trim_test,
+    Table::new([("  padded  ", "\tx ")])
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Format::content(|s| s.trim().to_owned()))),
+    " &str   | &str "
+    "--------+------"
+    " padded | x    "
+);
+
 test_table!(
     formatting_full_test,
     Matrix::new(3, 3).with(Modify::new(Segment::all()).with(Format::content(|s| format!("[{s}]")))),
@@ -227,6 +257,60 @@ test_table!(
     "+-------------+-------------+-------------+-------------+"
 );
 
+test_table!(
+    number_format_revenue_column_test,
+    Table::new([("north", "1234567.891"), ("south", "-9876.5"), ("east", "42"), ("west", "n/a")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(NumberFormat::new().precision(2)))
+        .with(Modify::new(Columns::single(1)).with(Alignment::right())),
+    " &str  |         &str "
+    "-------+--------------"
+    " north | 1,234,567.89 "
+    " south |    -9,876.50 "
+    " east  |        42.00 "
+    " west  |          n/a "
+);
+
+test_table!(
+    number_format_custom_chars_test,
+    Table::new([("a", "1234.5"), ("b", "999.999"), ("c", "1000000")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(NumberFormat::new().grouping('.').decimal(',').precision(2))),
+    " &str | &str         "
+    "------+--------------"
+    " a    | 1.234,50     "
+    " b    | 1.000,00     "
+    " c    | 1.000.000,00 "
+);
+
+test_table!(
+    number_format_thousands_decimals_test,
+    Table::new([("a", "1234567.5"), ("b", "-0.001"), ("c", "-2.5E3"), ("d", "1e-3"), ("e", "-")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(1)).with(NumberFormat::new().thousands(',').decimals(2))),
+    " &str | &str         "
+    "------+--------------"
+    " a    | 1,234,567.50 "
+    " b    | 0.00         "
+    " c    | -2,500.00    "
+    " d    | 0.00         "
+    " e    | -            "
+);
+
+test_table!(
+    zero_pad_id_column_test,
+    Table::new([("7", "db"), ("42", "cache"), ("-3", "queue"), ("12345", "api"), ("n/a", "web")])
+        .with(Style::psql())
+        .with(Modify::new(Columns::single(0)).with(ZeroPad::to(4))),
+    " &str  | &str  "
+    "-------+-------"
+    " 0007  | db    "
+    " 0042  | cache "
+    " -003  | queue "
+    " 12345 | api   "
+    " n/a   | web   "
+);
+
 #[cfg(feature = "color")]
 test_table!(
     color_test,
@@ -267,3 +351,61 @@ test_table!(
     "   |          |          | \u{1b}[32m.com\u{1b}[39m     "
     "   |          |          | \u{1b}[32m/en\u{1b}[39m      "
 );
+
+#[test]
+fn hyperlink_test() {
+    let mut table = Matrix::table(2, 2);
+    let expected_width = table.total_width();
+
+    table.with(Modify::new(Cell::new(1, 1)).with(Hyperlink::new("https://example.com")));
+
+    assert_eq!(table.total_width(), expected_width);
+    assert_eq!(
+        table.to_string(),
+        "+---+----------+----------+\n\
+         | N | column 0 | column 1 |\n\
+         +---+----------+----------+\n\
+         | 0 |   \u{1b}]8;;https://example.com\u{1b}\\0-0\u{1b}]8;;\u{1b}\\    |   0-1    |\n\
+         +---+----------+----------+\n\
+         | 1 |   1-0    |   1-1    |\n\
+         +---+----------+----------+"
+    );
+}
+
+#[test]
+fn hyperlink_multiline_test() {
+    let mut table = Table::new([["some\ntext"]]);
+    table.with(Modify::new(Cell::new(1, 0)).with(Hyperlink::new("https://example.com")));
+
+    assert_eq!(
+        table.to_string(),
+        "+------+\n\
+         | 0    |\n\
+         +------+\n\
+         | \u{1b}]8;;https://example.com\u{1b}\\some\u{1b}]8;;\u{1b}\\ |\n\
+         | \u{1b}]8;;https://example.com\u{1b}\\text\u{1b}]8;;\u{1b}\\ |\n\
+         +------+"
+    );
+}
+
+#[test]
+fn hyperlink_wrap_test() {
+    let mut table = Table::new([["src/lib.rs"], ["README.md"]]);
+    let expected_width = table.total_width();
+
+    table
+        .with(Modify::new(Rows::new(1..)).with(Hyperlink::wrap(|text| format!("file:///{text}"))))
+        .with(Modify::new(Rows::new(1..)).with(Alignment::right()));
+
+    assert_eq!(table.total_width(), expected_width);
+    assert_eq!(
+        table.to_string(),
+        "+------------+\n\
+         | 0          |\n\
+         +------------+\n\
+         | \u{1b}]8;;file:///src/lib.rs\u{1b}\\src/lib.rs\u{1b}]8;;\u{1b}\\ |\n\
+         +------------+\n\
+         |  \u{1b}]8;;file:///README.md\u{1b}\\README.md\u{1b}]8;;\u{1b}\\ |\n\
+         +------------+"
+    );
+}
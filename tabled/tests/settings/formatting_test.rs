@@ -1,6 +1,13 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{formatting::Justification, object::Columns, Color, Modify};
+use tabled::{
+    settings::{
+        formatting::{DecimalAlignment, EmptyCell, Fill, Justification, TrimLines},
+        object::{Cell, Columns, Object, Rows},
+        Alignment, Color, Margin, Modify, Style,
+    },
+    Table,
+};
 
 use crate::matrix::Matrix;
 use testing_table::test_table;
@@ -66,3 +73,148 @@ test_table!(
     "| 2 | \u{1b}[44m##\u{1b}[49m2-0\u{1b}[44m###\u{1b}[49m | \u{1b}[41m@@\u{1b}[49m2-1\u{1b}[41m@@@\u{1b}[49m | \u{1b}[47m$$\u{1b}[49m2-2\u{1b}[47m$$$\u{1b}[49m |"
     "+---+----------+----------+----------+"
 );
+
+test_table!(
+    fill_center_aligned_cell,
+    Table::new([("Total", "1000000"), ("x", "1")])
+        .with(Modify::new(Cell::new(2, 0)).with(Fill::char('.')).with(Alignment::center())),
+    "+-------+---------+"
+    "| &str  | &str    |"
+    "+-------+---------+"
+    "| Total | 1000000 |"
+    "+-------+---------+"
+    "|...x...| 1       |"
+    "+-------+---------+"
+);
+
+test_table!(
+    fill_column,
+    Matrix::new(3, 3).with(Modify::new(Columns::single(1)).with(Fill::char('.'))),
+    "+---+----------+----------+----------+"
+    "| N |.column 0.| column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |...0-0....|   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |...1-0....|   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |...2-0....|   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    decimal_alignment,
+    Table::new([
+        ("Rent", "1250.00"),
+        ("Coffee", "3.5"),
+        ("Phone", "49"),
+        ("Tax", "-0.125"),
+        ("Refund", "n/a"),
+    ])
+    .with(Modify::new(Columns::single(1).not(Rows::first())).with(Alignment::decimal())),
+    "+--------+----------+"
+    "| &str   | &str     |"
+    "+--------+----------+"
+    "| Rent   | 1250.00  |"
+    "+--------+----------+"
+    "| Coffee |    3.5   |"
+    "+--------+----------+"
+    "| Phone  |   49     |"
+    "+--------+----------+"
+    "| Tax    |   -0.125 |"
+    "+--------+----------+"
+    "| Refund |      n/a |"
+    "+--------+----------+"
+);
+
+test_table!(
+    decimal_alignment_global,
+    Table::new([(1, 2.5, "x"), (10, 0.25, "y"), (100, 12.0, "z")]).with(DecimalAlignment::new()),
+    "+-----+-------+------+"
+    "| i32 |   f64 | &str |"
+    "+-----+-------+------+"
+    "|   1 |  2.5  |    x |"
+    "+-----+-------+------+"
+    "|  10 |  0.25 |    y |"
+    "+-----+-------+------+"
+    "| 100 | 12    |    z |"
+    "+-----+-------+------+"
+);
+
+test_table!(
+    trim_lines_blank,
+    Matrix::new(3, 3).with(Style::blank()).with(TrimLines::new()),
+    " N   column 0   column 1   column 2"
+    " 0     0-0        0-1        0-2"
+    " 1     1-0        1-1        1-2"
+    " 2     2-0        2-1        2-2"
+);
+
+test_table!(
+    trim_lines_bordered,
+    Matrix::new(2, 2).with(Style::modern()).with(TrimLines::new()),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+#[test]
+fn trim_lines_margin() {
+    let table = Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Margin::new(1, 3, 1, 1))
+        .with(TrimLines::new())
+        .to_string();
+
+    assert_eq!(
+        table,
+        concat!(
+            "\n",
+            "  N | column 0 | column 1\n",
+            " ---+----------+----------\n",
+            "  0 |   0-0    |   0-1\n",
+            "  1 |   1-0    |   1-1\n",
+        )
+    );
+}
+
+test_table!(
+    trim_lines_off,
+    Matrix::new(2, 2).with(Style::blank()).with(TrimLines::new()).with(TrimLines::off()),
+    " N   column 0   column 1 "
+    " 0     0-0        0-1    "
+    " 1     1-0        1-1    "
+);
+
+test_table!(
+    empty_cell_placeholder,
+    Table::new([["1", "", "3"], ["", "  ", "6"], ["7", "8", "\n"]])
+        .with(EmptyCell::new("—"))
+        .with(Alignment::center()),
+    "+---+---+---+"
+    "| 0 | 1 | 2 |"
+    "+---+---+---+"
+    "| 1 | — | 3 |"
+    "+---+---+---+"
+    "| — | — | 6 |"
+    "+---+---+---+"
+    "| 7 | 8 | — |"
+    "+---+---+---+"
+);
+
+test_table!(
+    empty_cell_placeholder_widens_column,
+    Table::new([["1", ""], ["", "4"]])
+        .with(Modify::new(Columns::single(1)).with(EmptyCell::new("N/A")))
+        .with(Alignment::right()),
+    "+---+-----+"
+    "| 0 |   1 |"
+    "+---+-----+"
+    "| 1 | N/A |"
+    "+---+-----+"
+    "|   |   4 |"
+    "+---+-----+"
+);
@@ -11,6 +11,24 @@ use testing_table::test_table;
 #[cfg(feature = "color")]
 use owo_colors::OwoColorize;
 
+test_table!(
+    cell_height_limit_indicator,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .with(Modify::new((1, 1)).with(Format::content(|_| "1\n2\n3\n4\n5".to_string())))
+        .with(Modify::new((2, 2)).with(Format::content(|_| "a\nb".to_string())))
+        .with(Modify::new(Segment::all()).with(Height::limit(2).indicator('▾'))),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │    1     │   0-1    │"
+    "│   │    2▾    │          │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │    a     │"
+    "│   │          │    b     │"
+    "└───┴──────────┴──────────┘"
+);
+
 test_table!(
     cell_height_increase,
     Matrix::new(3, 3)
@@ -261,3 +279,30 @@ test_table!(
     "| +------+ |"
     "+----------+"
 );
+
+test_table!(
+    height_list_shorter_than_rows,
+    Matrix::new(3, 3).insert((3, 1), "multi\nline").with(Height::list([0, 3])),
+    "+---+----------+----------+----------+"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "|   |          |          |          |"
+    "|   |          |          |          |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |  multi   |   2-1    |   2-2    |"
+    "|   |  line    |          |          |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    height_list_longer_than_rows,
+    Matrix::new(1, 1).with(Height::list([2, 1, 5, 5])),
+    "+---+----------+"
+    "| N | column 0 |"
+    "|   |          |"
+    "+---+----------+"
+    "| 0 |   0-0    |"
+    "+---+----------+"
+);
@@ -5,7 +5,8 @@ use tabled::{
     settings::{
         highlight::Highlight,
         object::{Cell, Columns, Frame, Object, Rows, Segment},
-        style::{Border, Style},
+        style::{Border, BorderColor, Style},
+        Color,
     },
 };
 
@@ -417,3 +418,11 @@ fn highlingt_complex_figures() {
         ),
     );
 }
+
+test_table!(
+    highlight_color_header_lines,
+    Matrix::new(1, 2)
+        .with(Highlight::color(Rows::first(), BorderColor::new().set_top(Color::FG_BLUE).set_bottom(Color::FG_BLUE)))
+        .with(Highlight::color(Rows::new(1..), BorderColor::new().set_bottom(Color::FG_BRIGHT_BLACK))),
+    "+\u{1b}[34m---+----------+----------\u{1b}[39m+\n| N | column 0 | column 1 |\n+\u{1b}[34m---+----------+----------\u{1b}[39m+\n| 0 |   0-0    |   0-1    |\n+\u{1b}[90m---+----------+----------\u{1b}[39m+"
+);
@@ -0,0 +1,91 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{
+    object::{Columns, Rows},
+    Alignment, Border, Index, Padding, Span, Style,
+};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    index_test,
+    Matrix::new(3, 2).with(Index::new()),
+    "+---+---+----------+----------+"
+    "| # | N | column 0 | column 1 |"
+    "+---+---+----------+----------+"
+    "| 1 | 0 |   0-0    |   0-1    |"
+    "+---+---+----------+----------+"
+    "| 2 | 1 |   1-0    |   1-1    |"
+    "+---+---+----------+----------+"
+    "| 3 | 2 |   2-0    |   2-1    |"
+    "+---+---+----------+----------+"
+);
+
+test_table!(
+    index_start_and_header_test,
+    Matrix::new(3, 2)
+        .with(Style::psql())
+        .with(Index::new().start(0).header("row")),
+    " row | N | column 0 | column 1 "
+    "-----+---+----------+----------"
+    "  0  | 0 |   0-0    |   0-1    "
+    "  1  | 1 |   1-0    |   1-1    "
+    "  2  | 2 |   2-0    |   2-1    "
+);
+
+test_table!(
+    index_no_header_test,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Index::new().no_header()),
+    " 1 | N | column 0 | column 1 "
+    "---+---+----------+----------"
+    " 2 | 0 |   0-0    |   0-1    "
+    " 3 | 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    index_shifts_alignment_test,
+    Matrix::table(2, 2)
+        .with(Style::psql())
+        .modify(Columns::single(1), Alignment::left())
+        .modify(Rows::first(), Padding::new(2, 2, 0, 0))
+        .with(Index::new()),
+    "  #  |  N  |  column 0  |  column 1  "
+    "-----+-----+------------+------------"
+    "  1  |  0  | 0-0        |    0-1     "
+    "  2  |  1  | 1-0        |    1-1     "
+);
+
+test_table!(
+    index_shifts_spans_test,
+    Matrix::table(2, 2)
+        .modify((1, 1), Span::column(2))
+        .modify((0, 0), Span::row(2))
+        .with(Index::new()),
+    "+---+---+----------+----------+"
+    "| # | N | column 0 | column 1 |"
+    "+---+   +----------+----------+"
+    "| 1 |   |         0-0         |"
+    "+---+---+----------+----------+"
+    "| 2 | 1 |   1-0    |   1-1    |"
+    "+---+---+----------+----------+"
+);
+
+test_table!(
+    index_shifts_borders_test,
+    Matrix::table(2, 2)
+        .with(Style::ascii())
+        .modify((1, 0), Border::filled('*'))
+        .with(Index::new()),
+    "+---+---+----------+----------+"
+    "| # | N | column 0 | column 1 |"
+    "+---*****----------+----------+"
+    "| 1 * 0 *   0-0    |   0-1    |"
+    "+---*****----------+----------+"
+    "| 2 | 1 |   1-0    |   1-1    |"
+    "+---+---+----------+----------+"
+);
+
+test_table!(index_empty_test, Matrix::empty().with(Index::new()), "");
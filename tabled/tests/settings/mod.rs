@@ -1,8 +1,11 @@
 mod alignment_test;
+mod ansi_string_test;
+mod caption_test;
 mod color_test;
 mod colorization;
 mod column_names_test;
 mod concat_test;
+mod direction_test;
 mod disable_test;
 mod duplicate_test;
 mod extract_test;
@@ -10,6 +13,7 @@ mod format_test;
 mod formatting_test;
 mod height_test;
 mod highlingt_test;
+mod index_test;
 mod margin_test;
 mod merge_test;
 mod padding_test;
@@ -18,6 +22,9 @@ mod render_settings;
 mod rotate_test;
 mod shadow_test;
 mod span_test;
+mod sparkline_test;
 mod split_test;
 mod style_test;
+mod theme_test;
+mod tree_test;
 mod width_test;
@@ -1,7 +1,7 @@
 #![cfg(feature = "std")]
 
 use tabled::settings::{
-    object::{Rows, Segment},
+    object::{Cell, Rows, Segment},
     Alignment, Modify, Padding, Style,
 };
 
@@ -30,6 +30,32 @@ test_table!(
     "   |          |          |          "
 );
 
+test_table!(
+    padding_single_cell,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Cell::new(2, 1)).with(Padding::new(5, 1, 0, 0))),
+    " N | column 0 | column 1 | column 2 "
+    "---+----------+----------+----------"
+    " 0 | 0-0      | 0-1      | 0-2      "
+    " 1 |     1-0  | 1-1      | 1-2      "
+    " 2 | 2-0      | 2-1      | 2-2      "
+);
+
+test_table!(
+    padding_single_cell_widens_column,
+    Matrix::new(3, 3)
+        .with(Style::psql())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Cell::new(2, 1)).with(Padding::new(9, 1, 0, 0))),
+    " N | column 0    | column 1 | column 2 "
+    "---+-------------+----------+----------"
+    " 0 | 0-0         | 0-1      | 0-2      "
+    " 1 |         1-0 | 1-1      | 1-2      "
+    " 2 | 2-0         | 2-1      | 2-2      "
+);
+
 test_table!(
     padding_with_set_characters,
     Matrix::new(3, 3)
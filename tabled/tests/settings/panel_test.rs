@@ -1,9 +1,16 @@
 #![cfg(feature = "std")]
 
-use tabled::settings::{
-    object::{Cell, Object, Rows, Segment},
-    style::BorderSpanCorrection,
-    Alignment, Border, Highlight, Modify, Panel, Span, Style, Width,
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    settings::{
+        object::{self, Cell, Object, Rows, Segment},
+        panel::{Aggregation, Footer, RepeatHeader},
+        style::{BorderSpanCorrection, RawStyle},
+        Alignment, Border, Color, Highlight, Modify, Padding, Panel, Span, Style, Width,
+    },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -183,6 +190,34 @@ test_table!(
     "└─────┴─────┘"
 );
 
+test_table!(
+    panel_style_span_intersection_join,
+    Matrix::iter([(0, 1)])
+        .with(Panel::horizontal(0,"Numbers"))
+        .with(Style::modern().intersection_top('─').span_intersection('┬')),
+    "┌───────────┐"
+    "│  Numbers  │"
+    "├─────┬─────┤"
+    "│ i32 │ i32 │"
+    "├─────┼─────┤"
+    "│  0  │  1  │"
+    "└─────┴─────┘"
+);
+
+test_table!(
+    panel_style_span_intersection_line,
+    Matrix::iter([(0, 1)])
+        .with(Panel::horizontal(0,"Numbers"))
+        .with(Style::modern().intersection_top('─').span_intersection('─')),
+    "┌───────────┐"
+    "│  Numbers  │"
+    "├───────────┤"
+    "│ i32 │ i32 │"
+    "├─────┼─────┤"
+    "│  0  │  1  │"
+    "└─────┴─────┘"
+);
+
 test_table!(
     panel_in_single_column,
     #[allow(unknown_lints)]
@@ -337,3 +372,225 @@ test_table!(
     "     | 1 |   1-0    |  1-1  |   1-2    |     "
     "     | 2 |   2-0    |  2-1  |   2-2    |     "
 );
+
+test_table!(
+    repeat_header,
+    Matrix::new(11, 2)
+        .with(Style::psql())
+        .with(RepeatHeader::new(5)),
+    " N  | column 0 | column 1 "
+    "----+----------+----------"
+    " 0  |   0-0    |   0-1    "
+    " 1  |   1-0    |   1-1    "
+    " 2  |   2-0    |   2-1    "
+    " 3  |   3-0    |   3-1    "
+    " 4  |   4-0    |   4-1    "
+    " N  | column 0 | column 1 "
+    " 5  |   5-0    |   5-1    "
+    " 6  |   6-0    |   6-1    "
+    " 7  |   7-0    |   7-1    "
+    " 8  |   8-0    |   8-1    "
+    " 9  |   9-0    |   9-1    "
+    " N  | column 0 | column 1 "
+    " 10 |   10-0   |   10-1   "
+);
+
+test_table!(
+    repeat_header_zero,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(RepeatHeader::new(0)),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    repeat_header_span_and_color,
+    Matrix::new(4, 2)
+        .with(Style::psql())
+        .with(Panel::header("Numbers"))
+        .modify(Rows::first(), Color::FG_RED)
+        .with(RepeatHeader::new(2)),
+    "         \u{1b}[31mNumbers\u{1b}[39m         "
+    "---+----------+----------"
+    " N | column 0 | column 1 "
+    " 0 |   0-0    |   0-1    "
+    "         \u{1b}[31mNumbers\u{1b}[39m         "
+    " 1 |   1-0    |   1-1    "
+    " 2 |   2-0    |   2-1    "
+    "         \u{1b}[31mNumbers\u{1b}[39m         "
+    " 3 |   3-0    |   3-1    "
+);
+
+test_table!(
+    panel_object_color,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Panel::header("Numbers"))
+        .modify(object::Panel::horizontal(0), Color::FG_RED),
+    "         \u{1b}[31mNumbers\u{1b}[39m         "
+    "---+----------+----------"
+    " N | column 0 | column 1 "
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+);
+
+test_table!(
+    panel_object_padding,
+    Matrix::new(2, 2)
+        .with(Style::ascii())
+        .with(Panel::header("Numbers"))
+        .modify(object::Panel::horizontal(0), Padding::new(2, 2, 1, 0)),
+    "+---+----------+----------+"
+    "|                         |"
+    "|         Numbers         |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    panel_object_vertical_border,
+    Matrix::new(2, 2)
+        .with(Style::ascii())
+        .with(Panel::vertical(0, "V"))
+        .modify(object::Panel::vertical(0), Border::new().set_right('#')),
+    "+---+---+----------+----------+"
+    "| V # N | column 0 | column 1 |"
+    "+   +---+----------+----------+"
+    "|   | 0 |   0-0    |   0-1    |"
+    "+   +---+----------+----------+"
+    "|   | 1 |   1-0    |   1-1    |"
+    "+---+---+----------+----------+"
+);
+
+test_table!(
+    panel_object_out_of_bounds,
+    Matrix::new(2, 2)
+        .with(Style::ascii())
+        .modify(object::Panel::horizontal(10), Color::FG_RED),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    footer_summary,
+    Table::new([("Item", "1"), ("Item", "20"), ("Item", "300")])
+        .with(Style::psql())
+        .with(Footer::summary()),
+    " &str | &str "
+    "------+------"
+    " Item | 1    "
+    " Item | 20   "
+    " Item | 300  "
+    "      | 321  "
+);
+
+test_table!(
+    footer_summary_label,
+    Table::new([
+        ("Apple", "1.5", "n/a"),
+        ("Orange", "x", "2"),
+        ("Banana", "2.25", "4")
+    ])
+    .with(Footer::summary().label("Total")),
+    "+--------+------+------+"
+    "| &str   | &str | &str |"
+    "+--------+------+------+"
+    "| Apple  | 1.5  | n/a  |"
+    "+--------+------+------+"
+    "| Orange | x    | 2    |"
+    "+--------+------+------+"
+    "| Banana | 2.25 | 4    |"
+    "+--------+------+------+"
+    "| Total  | 3.75 | 6    |"
+    "+--------+------+------+"
+);
+
+test_table!(
+    footer_summary_aggregations,
+    Builder::from_iter([
+        ["10", "1", "7", "-1.5"],
+        ["20", "2", "9", "3"],
+        ["30", "3", "8", "2"]
+    ])
+    .build()
+    .with(
+        Footer::summary()
+            .offset(0)
+            .aggregation(Aggregation::Avg)
+            .column(1, Aggregation::Count)
+            .column(2, Aggregation::Min)
+            .column(3, Aggregation::Max)
+    ),
+    "+-------+---+---+------+"
+    "| 10    | 1 | 7 | -1.5 |"
+    "+-------+---+---+------+"
+    "| 20    | 2 | 9 | 3    |"
+    "+-------+---+---+------+"
+    "| 30    | 3 | 8 | 2    |"
+    "+-------+---+---+------+"
+    "| 20.00 | 3 | 7 | 3.0  |"
+    "+-------+---+---+------+"
+);
+
+test_table!(
+    panel_wrap_keeps_table_width,
+    Matrix::new(2, 2).with(Panel::horizontal(0, "Linux Distributions and their releases").wrap()),
+    "+---+----------+----------+"
+    "| Linux Distributions and |"
+    "| their releases          |"
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    panel_wrap_counts_vertical_str_width,
+    Matrix::new(2, 2)
+        .with({
+            let mut style = RawStyle::from(Style::ascii());
+            style.set_vertical_str("| ");
+            style
+        })
+        .with(Panel::horizontal(0, "Linux Distributions and a list of releases").wrap()),
+    "+---+-----------+-----------+"
+    "| Linux Distributions and a |"
+    "| list of releases          |"
+    "+---+-----------+-----------+"
+    "| N |  column 0 |  column 1 |"
+    "+---+-----------+-----------+"
+    "| 0 |    0-0    |    0-1    |"
+    "+---+-----------+-----------+"
+    "| 1 |    1-0    |    1-1    |"
+    "+---+-----------+-----------+"
+);
+
+test_table!(
+    panel_wrap_breaks_long_word,
+    Builder::from_iter([["1", "2"]])
+        .build()
+        .with(Panel::horizontal(0, "Distributions").wrap()),
+    "+---+---+"
+    "| Distr |"
+    "| ibuti |"
+    "| ons   |"
+    "+---+---+"
+    "| 1 | 2 |"
+    "+---+---+"
+);
@@ -1,7 +1,7 @@
 #![cfg(feature = "std")]
 
 use tabled::settings::{
-    formatting::{AlignmentStrategy, TabSize, TrimStrategy},
+    formatting::{AlignmentStrategy, TabExpand, TabSize, TrimStrategy},
     object::Segment,
     Alignment, Modify, Span, Style,
 };
@@ -116,6 +116,21 @@ test_table!(
     "                      |          | /en          |          "
 );
 
+test_table!(
+    tab_expand_test,
+    Matrix::iter(tab_data1()).with(Style::psql()).with(TabExpand::size(4)),
+    "       N        | column 0 | column 1  | column 2 "
+    "----------------+----------+-----------+----------"
+    "       0        |   0-0    |    0-1    |   0-2    "
+    " 123 123 asdasd |   1-0    |    1-1    |   1-2    "
+    "       2        |   2-0    | htt ps:// |   2-2    "
+    "                |          | www       |          "
+    "                |          | .         |          "
+    "                |          | red hat   |          "
+    "                |          | .c  om    |          "
+    "                |          | /en       |          "
+);
+
 test_table!(
     tab_size_test_1,
     Matrix::iter(tab_data1()).with(Style::psql()).with(Modify::new(Segment::all()).with(Alignment::right())).with(TabSize::new(2)),
@@ -89,6 +89,57 @@ test_table!(
     "   ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
 );
 
+test_table!(
+    test_shadow_gradient_bottom_right,
+    Matrix::iter([(123, 456, 789), (234, 567, 891)]).with(Style::psql()).with(Shadow::new(3).gradient(true)),
+    " i32 | i32 | i32    "
+    "-----+-----+-----▓▒░"
+    " 123 | 456 | 789 ▓▒░"
+    " 234 | 567 | 891 ▓▒░"
+    " ▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓"
+    " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
+    " ░░░░░░░░░░░░░░░░░░░"
+);
+
+test_table!(
+    test_shadow_gradient_top_left,
+    Matrix::iter([(123, 456, 789), (234, 567, 891)]).with(Style::psql()).with(Shadow::new(3).set_top().set_left().gradient(true)),
+    "░░░░░░░░░░░░░░░░░░░ "
+    "▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒ "
+    "▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓ "
+    "░▒▓ i32 | i32 | i32 "
+    "░▒▓-----+-----+-----"
+    "░▒▓ 123 | 456 | 789 "
+    "    234 | 567 | 891 "
+);
+
+test_table!(
+    test_shadow_gradient_size_6,
+    Matrix::iter([(123, 456, 789)]).with(Style::psql()).with(Shadow::new(6).gradient(true)),
+    " i32 | i32 | i32       "
+    "-----+-----+-----▓▓▒▒░░"
+    " 123 | 456 | 789 ▓▓▒▒░░"
+    " ▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓"
+    " ▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓"
+    " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
+    " ▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒▒"
+    " ░░░░░░░░░░░░░░░░░░░░░░"
+    " ░░░░░░░░░░░░░░░░░░░░░░"
+);
+
+#[test]
+fn test_shadow_gradient_dimension() {
+    let mut table = Matrix::iter([(123, 456, 789), (234, 567, 891)]);
+    table.with(Style::psql()).with(Shadow::new(3));
+    let (width, height) = (table.total_width(), table.total_height());
+
+    table.with(Shadow::new(3).gradient(true));
+
+    assert_eq!(table.total_width(), width);
+    assert_eq!(table.total_height(), height);
+    assert_eq!(table.to_string().lines().count(), height);
+}
+
 #[cfg(feature = "color")]
 test_table!(
     test_shadow_set_color_0,
@@ -983,7 +983,7 @@ mod row {
             static_table!(
                 "+---+-------+"
                 "| 0 | 1     |"
-                "+---+       +"
+                "+---+       |"
                 "| 1 +---+---+"
                 "|   | 5 | 6 |"
                 "+---+---+---+"
@@ -1209,6 +1209,48 @@ test_table!(
     "+---+----------+----------+----------+"
 );
 
+test_table!(
+    column_span_join,
+    Matrix::new(3, 3).with(Modify::new((1, 1)).with(Span::column(3).join(|a, b| format!("{a} {b}")))),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |          0-0 0-1 0-2           |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    column_span_join_column,
+    Matrix::new(3, 3).with(Modify::new(Columns::single(2)).with(Span::column(2).join(|a, b| format!("{a},{b}")))),
+    "+---+----------+---------+---------+"
+    "| N | column 0 | column 1,column 2 |"
+    "+---+----------+---------+---------+"
+    "| 0 |   0-0    |      0-1,0-2      |"
+    "+---+----------+---------+---------+"
+    "| 1 |   1-0    |      1-1,1-2      |"
+    "+---+----------+---------+---------+"
+    "| 2 |   2-0    |      2-1,2-2      |"
+    "+---+----------+---------+---------+"
+);
+
+test_table!(
+    column_span_join_overflow,
+    Matrix::new(3, 3).with(Modify::new((2, 2)).with(Span::column(10).join(|a, b| format!("{a}+{b}")))),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |   0-1    |   0-2    |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |       1-1+1-2       |"
+    "+---+----------+----------+----------+"
+    "| 2 |   2-0    |   2-1    |   2-2    |"
+    "+---+----------+----------+----------+"
+);
+
 test_table!(
     fix_qc_0,
     {
@@ -1229,6 +1271,91 @@ test_table!(
     "+------+-----++++++++++++++++++++++++++++------------+------------+------------+------------+------------+-----------+-----------+------------+------------+-----------+"
 );
 
+test_table!(
+    span_column_is_clamped_test,
+    Builder::from_iter([["a", "b", "c"], ["d", "e", "f"]])
+        .build()
+        .modify((0, 1), Span::column(10)),
+    "+---+---+---+"
+    "| a | b     |"
+    "+---+---+---+"
+    "| d | e | f |"
+    "+---+---+---+"
+);
+
+test_table!(
+    span_column_max_is_clamped_test,
+    Matrix::table(2, 3).modify((1, 2), Span::column(usize::MAX)),
+    "+---+----------+----------+----------+"
+    "| N | column 0 | column 1 | column 2 |"
+    "+---+----------+----------+----------+"
+    "| 0 |   0-0    |         0-1         |"
+    "+---+----------+----------+----------+"
+    "| 1 |   1-0    |   1-1    |   1-2    |"
+    "+---+----------+----------+----------+"
+);
+
+test_table!(
+    span_row_max_is_clamped_test,
+    Matrix::table(3, 2).modify((1, 1), Span::row(usize::MAX)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+          +----------+"
+    "| 1 |          |   1-1    |"
+    "+---+          +----------+"
+    "| 2 |          |   2-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    span_first_row_frame_correction_test,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .modify((0, 0), Span::column(3))
+        .with(BorderSpanCorrection),
+    "┌───────────────┐"
+    "│       N       │"
+    "├───┬─────┬─────┤"
+    "│ 0 │ 0-0 │ 0-1 │"
+    "├───┼─────┼─────┤"
+    "│ 1 │ 1-0 │ 1-1 │"
+    "└───┴─────┴─────┘"
+);
+
+test_table!(
+    span_right_frame_correction_test,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .modify((1, 1), Span::column(2))
+        .modify((1, 1), Span::row(2))
+        .with(BorderSpanCorrection),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┴──────────┤"
+    "│ 0 │         0-0         │"
+    "├───┤                     │"
+    "│ 1 │                     │"
+    "└───┴─────────────────────┘"
+);
+
+test_table!(
+    span_left_frame_correction_test,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .modify((1, 0), Span::column(2))
+        .modify((1, 0), Span::row(2))
+        .with(BorderSpanCorrection),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┴──────────┼──────────┤"
+    "│      0       │   0-1    │"
+    "│              ├──────────┤"
+    "│              │   1-1    │"
+    "└──────────────┴──────────┘"
+);
+
 fn create_span_list(count_rows: usize, count_cols: usize) -> impl Iterator<Item = Position> {
     (0..count_rows).flat_map(move |r| (0..count_cols).map(move |c| (r, c)))
 }
@@ -0,0 +1,67 @@
+#![cfg(feature = "std")]
+
+use tabled::{settings::Sparkline, Table, Tabled};
+
+use testing_table::test_table;
+
+#[test]
+fn sparkline_empty_series() {
+    assert_eq!(Sparkline::new(3).render::<f64>(&[]), "   ");
+}
+
+#[test]
+fn sparkline_constant_series() {
+    assert_eq!(Sparkline::new(4).render(&[7, 7, 7, 7]), "▁▁▁▁");
+}
+
+#[test]
+fn sparkline_squeezes_long_series() {
+    assert_eq!(Sparkline::new(4).render(&[0, 0, 1, 1, 2, 2, 3, 3]), "▁▃▆█");
+}
+
+#[test]
+fn sparkline_pads_short_series() {
+    assert_eq!(Sparkline::new(4).render(&[0, 1]), "▁█  ");
+}
+
+#[test]
+fn sparkline_clamps_to_range() {
+    assert_eq!(
+        Sparkline::new(3).range(0.0, 7.0).render(&[-5, 3, 99]),
+        "▁▄█"
+    );
+}
+
+#[test]
+fn sparkline_nan_is_blank() {
+    assert_eq!(Sparkline::new(3).render(&[0.0, f64::NAN, 1.0]), "▁ █");
+}
+
+#[derive(Tabled)]
+struct Series {
+    id: usize,
+    #[tabled(display_with = "sparkline")]
+    values: Vec<i32>,
+}
+
+fn sparkline(values: &[i32]) -> String {
+    Sparkline::new(5).render(values)
+}
+
+test_table!(
+    sparkline_display_with,
+    Table::new([
+        Series { id: 0, values: vec![1, 2, 3, 4, 5] },
+        Series { id: 1, values: vec![5, 4, 3, 2, 1, 0, 0, 0, 0, 0] },
+        Series { id: 2, values: vec![] },
+    ]),
+    "+----+--------+"
+    "| id | values |"
+    "+----+--------+"
+    "| 0  | ▁▃▅▆█  |"
+    "+----+--------+"
+    "| 1  | █▅▂▁▁  |"
+    "+----+--------+"
+    "| 2  |        |"
+    "+----+--------+"
+);
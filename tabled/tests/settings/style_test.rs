@@ -4,14 +4,15 @@ use std::{collections::HashMap, iter::FromIterator};
 
 use tabled::{
     builder::Builder,
-    grid::config::Border as GridBorder,
+    grid::{config::Border as GridBorder, util::string::string_width},
     settings::{
         object::{Columns, Rows, Segment},
         style::{
-            Border, BorderColor, BorderSpanCorrection, HorizontalLine, Line, LineChar, LineText,
-            Offset, On, RawStyle, Style, VerticalLine,
+            Border, BorderColor, BorderSpanCorrection, EmptyBorder, Frame, HorizontalLine,
+            Intersection, Line, LineChar, LineText, Offset, On, RawStyle, Style, VerticalLine,
+            Weight,
         },
-        Color, Format, Highlight, Modify, Padding, Span,
+        Color, Format, Highlight, Modify, Padding, Span, Width,
     },
     Table,
 };
@@ -200,6 +201,296 @@ test_table!(
     "│ 2 │   2-0    │   2-1    │   2-2    │"
 );
 
+test_table!(
+    style_vertical_char_psql,
+    Matrix::new(3, 3).with(Style::psql().vertical_char('¦')),
+    " N ¦ column 0 ¦ column 1 ¦ column 2 "
+    "---+----------+----------+----------"
+    " 0 ¦   0-0    ¦   0-1    ¦   0-2    "
+    " 1 ¦   1-0    ¦   1-1    ¦   1-2    "
+    " 2 ¦   2-0    ¦   2-1    ¦   2-2    "
+);
+
+test_table!(
+    style_vertical_char_keeps_frame,
+    Matrix::new(2, 2).with(Style::modern().vertical_char('┆')),
+    "┌───┬──────────┬──────────┐"
+    "│ N ┆ column 0 ┆ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 ┆   0-0    ┆   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 ┆   1-0    ┆   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    style_space,
+    Matrix::new(3, 3).with(Style::space()),
+    "N column 0 column 1 column 2"
+    "0   0-0      0-1      0-2   "
+    "1   1-0      1-1      1-2   "
+    "2   2-0      2-1      2-2   "
+);
+
+test_table!(
+    style_space_header_gap,
+    Matrix::new(3, 3).with(Style::space().header_gap(true)),
+    "N column 0 column 1 column 2"
+    "                            "
+    "0   0-0      0-1      0-2   "
+    "1   1-0      1-1      1-2   "
+    "2   2-0      2-1      2-2   "
+);
+
+#[test]
+fn style_space_has_single_space_gaps() {
+    let table = Builder::from_iter([["a", "b", "c"], ["1", "2", "3"]])
+        .build()
+        .with(Style::space())
+        .to_string();
+
+    assert_eq!(table, "a b c\n1 2 3");
+}
+
+test_table!(
+    raw_style_vertical_str,
+    Matrix::new(2, 2).with({
+        let mut style = RawStyle::from(Style::modern());
+        style.set_vertical_str("║│");
+        style
+    }),
+    "┌───┬───────────┬───────────┐"
+    "│ N ║│ column 0 ║│ column 1 │"
+    "├───┼───────────┼───────────┤"
+    "│ 0 ║│   0-0    ║│   0-1    │"
+    "├───┼───────────┼───────────┤"
+    "│ 1 ║│   1-0    ║│   1-1    │"
+    "└───┴───────────┴───────────┘"
+);
+
+test_table!(
+    raw_style_vertical_str_with_span,
+    Matrix::new(2, 2)
+        .with({
+            let mut style = RawStyle::from(Style::ascii());
+            style.set_vertical_str("| ");
+            style
+        })
+        .with(Modify::new((1, 1)).with(Span::column(2))),
+    "+---+-----------+-----------+"
+    "| N |  column 0 |  column 1 |"
+    "+---+-----------+-----------+"
+    "| 0 |          0-0          |"
+    "+---+-----------+-----------+"
+    "| 1 |    1-0    |    1-1    |"
+    "+---+-----------+-----------+"
+);
+
+test_table!(
+    raw_style_merge_frame_over_separators,
+    Matrix::new(2, 2).with({
+        let mut separators = RawStyle::from(Style::empty());
+        separators
+            .set_horizontal(Some('─'))
+            .set_vertical(Some('│'))
+            .set_intersection(Some('┼'));
+
+        let mut frame = RawStyle::from(Style::modern());
+        frame
+            .set_horizontal(None)
+            .set_vertical(None)
+            .set_intersection(None);
+
+        separators.merge(&frame);
+        separators
+    }),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+#[test]
+fn raw_style_vertical_str_with_wide_span() {
+    let mut style = RawStyle::from(Style::ascii());
+    style.set_vertical_str("||");
+
+    let mut table = Table::new([("hello world and more", "world", "x"), ("a", "b", "c")]);
+    table
+        .with(style)
+        .with(Modify::new((1, 0)).with(Span::column(2)));
+
+    let output = table.to_string();
+    assert_eq!(
+        output,
+        static_table!(
+            "+----------+-----------+-------+"
+            "| &str     || &str     || &str |"
+            "+----------+-----------+-------+"
+            "| hello world and more || x    |"
+            "+----------+-----------+-------+"
+            "| a        || b        || c    |"
+            "+----------+-----------+-------+"
+        )
+    );
+    assert!(output
+        .lines()
+        .all(|line| string_width(line) == table.total_width()));
+    assert_eq!(
+        table.to_grid(),
+        [
+            [" &str     ", " &str     ", " &str "],
+            [" hello world and more ", "", " x    "],
+            [" a        ", " b        ", " c    "],
+        ]
+    );
+}
+
+test_table!(
+    raw_style_vertical_str_line_text,
+    Matrix::new(2, 2)
+        .with({
+            let mut style = RawStyle::from(Style::ascii());
+            style.set_vertical_str("||");
+            style
+        })
+        .with(LineText::new("-Numbers-").horizontal(0).offset(2)),
+    "+--Nu-mbers-----+-----------+"
+    "| N || column 0 || column 1 |"
+    "+---+-----------+-----------+"
+    "| 0 ||   0-0    ||   0-1    |"
+    "+---+-----------+-----------+"
+    "| 1 ||   1-0    ||   1-1    |"
+    "+---+-----------+-----------+"
+);
+
+#[test]
+fn raw_style_merge_keeps_base_when_overlay_is_empty() {
+    let mut style = RawStyle::from(Style::modern());
+    style.merge(&RawStyle::from(Style::empty()));
+
+    assert_eq!(style, RawStyle::from(Style::modern()));
+}
+
+#[test]
+fn raw_style_vertical_str_width() {
+    let mut style = RawStyle::from(Style::ascii());
+    style.set_vertical_str("<=>");
+
+    let table = Matrix::new(2, 2)
+        .with(style)
+        .with(Width::truncate(30))
+        .to_string();
+
+    assert!(table.lines().all(|line| string_width(line) == 30));
+    assert_eq!(
+        table,
+        static_table!(
+            "+--+------------+------------+"
+            "|  <=> column 0 <=> column 1 |"
+            "+--+------------+------------+"
+            "|  <=>   0-0    <=>   0-1    |"
+            "+--+------------+------------+"
+            "|  <=>   1-0    <=>   1-1    |"
+            "+--+------------+------------+"
+        )
+    );
+}
+
+test_table!(
+    style_remove_bottom_keeps_sides,
+    Matrix::new(2, 2).with(Style::modern().remove_bottom()),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+);
+
+#[test]
+fn style_remove_frame_lines_for_stacking() {
+    let top = Matrix::new(1, 2)
+        .with(Style::ascii().remove_bottom())
+        .to_string();
+    let bottom = Matrix::new(1, 2).with(Style::ascii()).to_string();
+
+    assert_eq!(
+        format!("{}\n{}", top, bottom),
+        static_table!(
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 |   0-0    |   0-1    |"
+            "+---+----------+----------+"
+            "| N | column 0 | column 1 |"
+            "+---+----------+----------+"
+            "| 0 |   0-0    |   0-1    |"
+            "+---+----------+----------+"
+        )
+    );
+}
+
+test_table!(
+    intersection_override_single_junction,
+    Matrix::new(3, 3)
+        .with(Style::modern())
+        .with(Modify::new((2, 2)).with(Intersection::new('╬'))),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │   0-2    │"
+    "├───┼──────────╬──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │   1-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 2 │   2-0    │   2-1    │   2-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    intersection_override_row,
+    Matrix::new(2, 2)
+        .with(Style::ascii())
+        .with(Modify::new(Rows::single(1)).with(Intersection::new('*'))),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "*---*----------*----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    style_frame_only,
+    Matrix::new(3, 3).with(Style::modern().frame_only()),
+    "┌─────────────────────────────────┐"
+    "│ N  column 0  column 1  column 2 │"
+    "│ 0    0-0       0-1       0-2    │"
+    "│ 1    1-0       1-1       1-2    │"
+    "│ 2    2-0       2-1       2-2    │"
+    "└─────────────────────────────────┘"
+);
+
+test_table!(
+    style_frame_only_custom_lines,
+    Matrix::new(3, 3).with(
+        Style::rounded()
+            .horizontals([(1, HorizontalLine::full('=', '+', '|', '|'))])
+            .verticals([(1, VerticalLine::new('!').top('+').bottom('-'))])
+            .frame_only()
+    ),
+    "╭─────────────────────────────────╮"
+    "│ N  column 0  column 1  column 2 │"
+    "│ 0    0-0       0-1       0-2    │"
+    "│ 1    1-0       1-1       1-2    │"
+    "│ 2    2-0       2-1       2-2    │"
+    "╰─────────────────────────────────╯"
+);
+
 test_table!(
     custom_style,
     Matrix::new(3, 3)
@@ -346,6 +637,43 @@ test_table!(
      +\u{1b}[31m---\u{1b}[39m+\u{1b}[31m----------\u{1b}[39m+\u{1b}[31m----------\u{1b}[39m+"
 );
 
+test_table!(
+    border_color_cell_top,
+    Matrix::table(2, 2).modify((1, 1), BorderColor::new().set_top(Color::FG_RED)),
+    "+---+----------+----------+\n\
+     | N | column 0 | column 1 |\n\
+     +---+\u{1b}[31m----------\u{1b}[39m+----------+\n\
+     | 0 |   0-0    |   0-1    |\n\
+     +---+----------+----------+\n\
+     | 1 |   1-0    |   1-1    |\n\
+     +---+----------+----------+"
+);
+
+test_table!(
+    border_color_cell_with_border,
+    Matrix::table(2, 2)
+        .modify((1, 1), Border::new().set_top('=').set_left('!'))
+        .modify((1, 1), BorderColor::new().set_top(Color::FG_RED)),
+    "+---+----------+----------+\n\
+     | N | column 0 | column 1 |\n\
+     +---+\u{1b}[31m==========\u{1b}[39m+----------+\n\
+     | 0 !   0-0    |   0-1    |\n\
+     +---+----------+----------+\n\
+     | 1 |   1-0    |   1-1    |\n\
+     +---+----------+----------+"
+);
+
+#[test]
+fn border_color_try_get() {
+    let border = BorderColor::new().set_top(Color::FG_RED);
+
+    assert_eq!(border.try_get_top(), Some(&Color::FG_RED));
+    assert_eq!(border.try_get_bottom(), None);
+    assert_eq!(border.try_get_left(), None);
+    assert_eq!(border.try_get_right(), None);
+    assert_eq!(border.try_get_corner_top_left(), None);
+}
+
 #[cfg(feature = "color")]
 test_table!(
     border_text_colored,
@@ -515,8 +843,8 @@ test_table!(
     Matrix::table(2, 2).with(Modify::new(Rows::single(1)).with(Border::filled('*').set_top('#'))),
     "+---+----------+----------+"
     "| N | column 0 | column 1 |"
-    "*###*##########*##########*"
-    "* 0 *   0-0    *   0-1    *"
+    "*#########################*"
+    "* 0 |   0-0    |   0-1    *"
     "***************************"
     "| 1 |   1-0    |   1-1    |"
     "+---+----------+----------+"
@@ -527,11 +855,37 @@ test_table!(
     Matrix::table(2, 2)
         .with(Style::empty())
         .with(Modify::new(Rows::single(1)).with(Border::filled('*').set_top('#'))),
-    "  N   column 0   column 1  "
-    "*###*##########*##########*"
-    "* 0 *   0-0    *   0-1    *"
-    "***************************"
-    "  1     1-0        1-1     "
+    "  N  column 0  column 1  "
+    "*#######################*"
+    "* 0    0-0       0-1    *"
+    "*************************"
+    "  1    1-0       1-1     "
+);
+
+test_table!(
+    border_columns_region_test,
+    Matrix::table(2, 3)
+        .with(Style::ascii())
+        .with(Modify::new(Columns::new(1..3)).with(Border::filled('*'))),
+    "+---***********************----------+"
+    "| N * column 0 | column 1 * column 2 |"
+    "+---*----------+----------*----------+"
+    "| 0 *   0-0    |   0-1    *   0-2    |"
+    "+---*----------+----------*----------+"
+    "| 1 *   1-0    |   1-1    *   1-2    |"
+    "+---***********************----------+"
+);
+
+test_table!(
+    style_corners_test,
+    Matrix::new(2, 2).with(Style::modern().corners('╭', '╮', '╰', '╯')),
+    "╭───┬──────────┬──────────╮"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "╰───┴──────────┴──────────╯"
 );
 
 test_table!(
@@ -746,6 +1100,19 @@ test_table!(
     "  1    1-0       1-1     "
 );
 
+test_table!(
+    border_empty_right_test,
+    Matrix::table(2, 2)
+        .with(Style::empty())
+        .with(Modify::new((1, 1)).with(Border::filled('*')))
+        .with(Modify::new((1, 1)).with(EmptyBorder::right())),
+    " N   column 0   column 1 "
+    "   ************          "
+    " 0 *   0-0        0-1    "
+    "   ************          "
+    " 1     1-0        1-1    "
+);
+
 #[test]
 fn custom_style_test() {
     macro_rules! test_style {
@@ -2566,6 +2933,20 @@ test_table!(
     "  +-------+  "
 );
 
+test_table!(
+    table_format_width_center_40_test,
+    format!("{:^40}", Table::new(vec!["hello", "world", "!"])),
+    "               +-------+                "
+    "               | &str  |                "
+    "               +-------+                "
+    "               | hello |                "
+    "               +-------+                "
+    "               | world |                "
+    "               +-------+                "
+    "               |   !   |                "
+    "               +-------+                "
+);
+
 test_table!(
     table_format_width_3_test,
     format!("{:x^13}", Table::new(vec!["hello", "world", "!"])),
@@ -2652,3 +3033,119 @@ test_table!(
     "| 2 |   2-0    |   2-1    |   2-2    |"
     "xxxx|xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx"
 );
+
+test_table!(
+    frame_blank_style,
+    Matrix::new(2, 2)
+        .with(Style::blank())
+        .with(Frame::new(Border::filled('*'))),
+    "***************************"
+    "* N   column 0   column 1 *"
+    "* 0     0-0        0-1    *"
+    "* 1     1-0        1-1    *"
+    "***************************"
+);
+
+test_table!(
+    frame_keeps_inner_lines,
+    Matrix::new(2, 2)
+        .with(Style::modern())
+        .with(Frame::new(Border::filled('#'))),
+    "###########################"
+    "# N │ column 0 │ column 1 #"
+    "#───┼──────────┼──────────#"
+    "# 0 │   0-0    │   0-1    #"
+    "#───┼──────────┼──────────#"
+    "# 1 │   1-0    │   1-1    #"
+    "###########################"
+);
+
+test_table!(
+    frame_partial_border,
+    Matrix::new(2, 2)
+        .with(Style::psql())
+        .with(Frame::new(Border::new().set_top('=').set_bottom('='))),
+    "========================="
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+    "========================="
+);
+
+test_table!(
+    frame_custom_lines,
+    Matrix::new(2, 2)
+        .with(Style::blank().horizontals([(1, HorizontalLine::new('-').intersection('+'))]))
+        .with(Frame::new(Border::filled('*'))),
+    "***************************"
+    "* N   column 0   column 1 *"
+    "*---+----------+----------*"
+    "* 0     0-0        0-1    *"
+    "* 1     1-0        1-1    *"
+    "***************************"
+);
+
+test_table!(
+    style_weight_heavy,
+    Matrix::new(2, 2).with(Style::modern().weight(Weight::Heavy)),
+    "┏━━━┳━━━━━━━━━━┳━━━━━━━━━━┓"
+    "┃ N ┃ column 0 ┃ column 1 ┃"
+    "┣━━━╋━━━━━━━━━━╋━━━━━━━━━━┫"
+    "┃ 0 ┃   0-0    ┃   0-1    ┃"
+    "┣━━━╋━━━━━━━━━━╋━━━━━━━━━━┫"
+    "┃ 1 ┃   1-0    ┃   1-1    ┃"
+    "┗━━━┻━━━━━━━━━━┻━━━━━━━━━━┛"
+);
+
+test_table!(
+    style_weight_double,
+    Matrix::new(2, 2).with(Style::rounded().weight(Weight::Double)),
+    "╔═══╦══════════╦══════════╗"
+    "║ N ║ column 0 ║ column 1 ║"
+    "╠═══╬══════════╬══════════╣"
+    "║ 0 ║   0-0    ║   0-1    ║"
+    "║ 1 ║   1-0    ║   1-1    ║"
+    "╚═══╩══════════╩══════════╝"
+);
+
+test_table!(
+    style_weight_light,
+    Matrix::new(2, 2).with(Style::extended().weight(Weight::Light)),
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    style_weight_keeps_ascii,
+    Matrix::new(1, 2).with(Style::ascii().weight(Weight::Heavy)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    style_group_separator,
+    Matrix::new(2, 3).with(Style::modern().group_separator(2, '║')),
+    "┌───┬──────────┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 ║ column 2 │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    ║   0-2    │"
+    "├───┼──────────┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    ║   1-2    │"
+    "└───┴──────────┴──────────┴──────────┘"
+);
+
+test_table!(
+    style_group_separator_without_verticals,
+    Matrix::new(1, 3).with(Style::blank().remove_vertical().group_separator(1, '┃')),
+    " N  column 0 ┃ column 1  column 2 "
+    " 0    0-0    ┃   0-1       0-2    "
+);
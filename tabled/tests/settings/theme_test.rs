@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+use tabled::settings::{object::Columns, themes::Theme, Alignment, Modify, Padding, Style, Width};
+
+use crate::matrix::Matrix;
+use testing_table::test_table;
+
+test_table!(
+    theme_empty,
+    Matrix::new(2, 2).with(Theme::new()),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
+
+test_table!(
+    theme_options_are_applied_in_order,
+    Matrix::new(2, 2).with(
+        Theme::new()
+            .with(Style::psql())
+            .with(Modify::new(Columns::new(1..)).with(Alignment::left()))
+            .with(Style::markdown())
+    ),
+    "| N | column 0 | column 1 |"
+    "|---|----------|----------|"
+    "| 0 | 0-0      | 0-1      |"
+    "| 1 | 1-0      | 1-1      |"
+);
+
+test_table!(
+    theme_clone,
+    {
+        let theme = Theme::new().with(Style::modern());
+        let _ = Matrix::new(1, 1).with(theme.clone());
+        Matrix::new(2, 2).with(theme)
+    },
+    "┌───┬──────────┬──────────┐"
+    "│ N │ column 0 │ column 1 │"
+    "├───┼──────────┼──────────┤"
+    "│ 0 │   0-0    │   0-1    │"
+    "├───┼──────────┼──────────┤"
+    "│ 1 │   1-0    │   1-1    │"
+    "└───┴──────────┴──────────┘"
+);
+
+test_table!(
+    theme_minimal,
+    Matrix::new(2, 2).with(Theme::minimal()),
+    "N   column 0   column 1  "
+    "--- ---------- ----------"
+    "0   0-0        0-1       "
+    "1   1-0        1-1       "
+);
+
+test_table!(
+    theme_dark,
+    Matrix::new(2, 2).with(Theme::dark()),
+    "╭───┬──────────┬──────────╮\n│ \u{1b}[1m\u{1b}[97mN\u{1b}[22m\u{1b}[39m │ \u{1b}[1m\u{1b}[97mcolumn 0\u{1b}[22m\u{1b}[39m │ \u{1b}[1m\u{1b}[97mcolumn 1\u{1b}[22m\u{1b}[39m │\n├───┼──────────┼──────────┤\n│\u{1b}[40m \u{1b}[49m\u{1b}[40m0\u{1b}[49m\u{1b}[40m \u{1b}[49m│\u{1b}[40m \u{1b}[49m\u{1b}[40m  \u{1b}[49m\u{1b}[40m0-0\u{1b}[49m\u{1b}[40m   \u{1b}[49m\u{1b}[40m \u{1b}[49m│\u{1b}[40m \u{1b}[49m\u{1b}[40m  \u{1b}[49m\u{1b}[40m0-1\u{1b}[49m\u{1b}[40m   \u{1b}[49m\u{1b}[40m \u{1b}[49m│\n│\u{1b}[100m \u{1b}[49m\u{1b}[100m1\u{1b}[49m\u{1b}[100m \u{1b}[49m│\u{1b}[100m \u{1b}[49m\u{1b}[100m  \u{1b}[49m\u{1b}[100m1-0\u{1b}[49m\u{1b}[100m   \u{1b}[49m\u{1b}[100m \u{1b}[49m│\u{1b}[100m \u{1b}[49m\u{1b}[100m  \u{1b}[49m\u{1b}[100m1-1\u{1b}[49m\u{1b}[100m   \u{1b}[49m\u{1b}[100m \u{1b}[49m│\n╰───┴──────────┴──────────╯"
+);
+
+test_table!(
+    theme_reestimates_dimension_between_options,
+    {
+        let table = Matrix::new(2, 2).with(
+            Theme::new()
+                .with(Width::wrap(20))
+                .with(Padding::new(3, 3, 0, 0)),
+        );
+
+        let expected = Matrix::new(2, 2)
+            .with(Width::wrap(20))
+            .with(Padding::new(3, 3, 0, 0))
+            .to_string();
+        assert_eq!(table.to_string(), expected);
+
+        table
+    },
+    "+------+-----------+-----------+"
+    "|      |   colum   |   colum   |"
+    "|      |   n 0     |   n 1     |"
+    "+------+-----------+-----------+"
+    "|      |    0-0    |    0-1    |"
+    "+------+-----------+-----------+"
+    "|      |    1-0    |    1-1    |"
+    "+------+-----------+-----------+"
+);
@@ -0,0 +1,78 @@
+#![cfg(feature = "std")]
+
+use tabled::{
+    settings::{Style, Tree},
+    Table,
+};
+
+use testing_table::test_table;
+
+test_table!(
+    tree,
+    Table::new([
+        ["tabled", "0.14.0"],
+        ["papergrid", "0.10.0"],
+        ["bytecount", "0.6.3"],
+        ["unicode-width", "0.1.10"],
+        ["tabled_derive", "0.6.0"],
+        ["heck", "0.4.1"],
+        ["proc-macro2", "1.0.56"],
+        ["unicode-ident", "1.0.8"],
+        ["quote", "1.0.26"],
+    ])
+    .with(Style::rounded())
+    .with(Tree::new([0, 1, 2, 2, 1, 2, 2, 3, 2]).offset(1)),
+    "╭───────────────────────────┬────────╮"
+    "│ 0                         │ 1      │"
+    "├───────────────────────────┼────────┤"
+    "│ tabled                    │ 0.14.0 │"
+    "│ ├── papergrid             │ 0.10.0 │"
+    "│ │   ├── bytecount         │ 0.6.3  │"
+    "│ │   └── unicode-width     │ 0.1.10 │"
+    "│ └── tabled_derive         │ 0.6.0  │"
+    "│     ├── heck              │ 0.4.1  │"
+    "│     ├── proc-macro2       │ 1.0.56 │"
+    "│     │   └── unicode-ident │ 1.0.8  │"
+    "│     └── quote             │ 1.0.26 │"
+    "╰───────────────────────────┴────────╯"
+);
+
+test_table!(
+    tree_multiline,
+    Table::new([["root"], ["a\nb"], ["c"], ["d\ne"], ["f"]])
+        .with(Style::psql())
+        .with(Tree::new([0, 1, 2, 1, 2]).offset(1)),
+    " 0         "
+    "-----------"
+    " root      "
+    " ├── a     "
+    " │   b     "
+    " │   └── c "
+    " └── d     "
+    "     e     "
+    "     └── f "
+);
+
+test_table!(
+    tree_column,
+    Table::new([["1", "root"], ["2", "leaf"]]).with(Tree::new([0, 1]).column(1).offset(1)),
+    "+---+----------+"
+    "| 0 | 1        |"
+    "+---+----------+"
+    "| 1 | root     |"
+    "+---+----------+"
+    "| 2 | └── leaf |"
+    "+---+----------+"
+);
+
+test_table!(
+    tree_out_of_bounds,
+    Table::new([["root"], ["leaf"]]).with(Tree::new([0, 1, 1, 1]).column(5).offset(1)),
+    "+------+"
+    "| 0    |"
+    "+------+"
+    "| root |"
+    "+------+"
+    "| leaf |"
+    "+------+"
+);
@@ -6,9 +6,10 @@ use tabled::{
         formatting::{TabSize, TrimStrategy},
         object::{Columns, Object, Rows, Segment},
         peaker::{PriorityMax, PriorityMin},
-        width::{Justify, MinWidth, SuffixLimit, Width},
+        width::{AmbiguousWidth, Justify, MinWidth, SuffixLimit, Width},
         Alignment, Margin, Modify, Padding, Panel, Settings, Span, Style,
     },
+    Table,
 };
 
 use crate::matrix::Matrix;
@@ -366,6 +367,62 @@ test_table!(
     "| tence             |"
 );
 
+test_table!(
+    max_width_wrapped_break_on_path,
+    Matrix::iter(["/home/user/projects/tabled/src/settings/width/wrap.rs"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(15).break_on(&['/']))),
+    "| &str            |"
+    "|-----------------|"
+    "| /home/user/     |"
+    "| projects/       |"
+    "| tabled/src/     |"
+    "| settings/width/ |"
+    "| wrap.rs         |"
+);
+
+test_table!(
+    max_width_wrapped_break_on_falls_back_to_chars,
+    Matrix::iter(["a-very_long_identifier_without_breaks-b"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(15).break_on(&['/', '-']))),
+    "| &str            |"
+    "|-----------------|"
+    "| a-              |"
+    "| very_long_ident |"
+    "| ifier_without_b |"
+    "| reaks-b         |"
+);
+
+test_table!(
+    max_width_wrapped_break_on_keep_words,
+    Matrix::iter(["hello world foo/bar baz"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(9).keep_words().break_on(&['/']))),
+    "| &str    |"
+    "|---------|"
+    "| hello   |"
+    "| world   |"
+    "| foo/bar |"
+    "| baz     |"
+);
+
+test_table!(
+    max_width_wrapped_break_on_total,
+    Matrix::iter(["/usr/local/share/applications"])
+        .with(Style::markdown())
+        .with(Modify::new(Segment::all()).with(Alignment::left()))
+        .with(Width::wrap(19).break_on(&['/'])),
+    "| &str            |"
+    "|-----------------|"
+    "| /usr/local/     |"
+    "| share/          |"
+    "| applications    |"
+);
+
 #[cfg(feature = "color")]
 #[test]
 fn max_width_wrapped_keep_words_long_word_color() {
@@ -2832,3 +2889,363 @@ mod derived {
         );
     }
 }
+
+#[test]
+fn width_ambiguous_test() {
+    let data = [["±1", "2"]];
+
+    let mut table = Table::new(data);
+    table.with(Width::ambiguous(AmbiguousWidth::AsWide));
+
+    let wide = table.to_string();
+    let wide_width = table.total_width();
+
+    table.with(Width::ambiguous(AmbiguousWidth::AsNarrow));
+
+    let narrow = table.to_string();
+    let narrow_width = table.total_width();
+
+    assert_eq!(
+        wide,
+        static_table!(
+            "+-----+---+"
+            "| 0   | 1 |"
+            "+-----+---+"
+            "| ±1 | 2 |"
+            "+-----+---+"
+        )
+    );
+    assert_eq!(wide_width, 11);
+
+    assert_eq!(
+        narrow,
+        static_table!(
+            "+----+---+"
+            "| 0  | 1 |"
+            "+----+---+"
+            "| ±1 | 2 |"
+            "+----+---+"
+        )
+    );
+    assert_eq!(narrow_width, 10);
+}
+
+#[test]
+fn width_ambiguous_is_kept_per_table_test() {
+    let data = [["±1", "2"]];
+
+    let mut wide = Table::new(data);
+    wide.with(Width::ambiguous(AmbiguousWidth::AsWide));
+
+    let narrow = Table::new(data);
+
+    assert_eq!(narrow.total_width(), 10);
+    assert_eq!(wide.total_width(), 11);
+    assert_eq!(
+        narrow.to_string(),
+        static_table!(
+            "+----+---+"
+            "| 0  | 1 |"
+            "+----+---+"
+            "| ±1 | 2 |"
+            "+----+---+"
+        )
+    );
+}
+
+#[test]
+fn width_ambiguous_wrap_test() {
+    let mut table = Table::new([["±±±±"]]);
+    table
+        .with(Width::ambiguous(AmbiguousWidth::AsWide))
+        .with(Width::wrap(8));
+
+    assert_eq!(table.total_width(), 8);
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+------+"
+            "| 0    |"
+            "+------+"
+            "| ±± |"
+            "| ±± |"
+            "+------+"
+        )
+    );
+}
+
+#[test]
+fn width_percent_test() {
+    let data = [["Hello", "World", "!"]];
+
+    let mut table = Table::new(data);
+    table.with(Width::percent([50]).total(80));
+
+    assert_eq!(table.total_width(), 80);
+    assert_eq!(
+        table.to_string().lines().next().unwrap().find("-+-"),
+        Some(38)
+    );
+    assert_eq!(
+        table.to_string(),
+        static_table!(
+            "+--------------------------------------+-------------------+-------------------+"
+            "| 0                                    | 1                 | 2                 |"
+            "+--------------------------------------+-------------------+-------------------+"
+            "| Hello                                | World             | !                 |"
+            "+--------------------------------------+-------------------+-------------------+"
+        )
+    );
+}
+
+#[test]
+fn width_percent_wrap_test() {
+    let table = Matrix::new(2, 3)
+        .with(Width::percent([50, 20]).total(30))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+------------+-----+----+----+"
+            "|     N      | col | co | co |"
+            "|            | umn | lu | lu |"
+            "|            |  0  | mn | mn |"
+            "|            |     |  1 |  2 |"
+            "+------------+-----+----+----+"
+            "|     0      | 0-0 | 0- | 0- |"
+            "|            |     | 1  | 2  |"
+            "+------------+-----+----+----+"
+            "|     1      | 1-0 | 1- | 1- |"
+            "|            |     | 1  | 2  |"
+            "+------------+-----+----+----+"
+        )
+    );
+}
+
+#[test]
+fn width_percent_cap_test() {
+    let data = [
+        ["1", "Tabled is a library for pretty printing tables"],
+        ["2", "Short"],
+    ];
+
+    let table = Table::new(data)
+        .with(Modify::new(Columns::single(1)).with(Width::percent(50)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+-------------------------+"
+            "| 0 | 1                       |"
+            "+---+-------------------------+"
+            "| 1 | Tabled is a library for |"
+            "|   |  pretty printing tables |"
+            "+---+-------------------------+"
+            "| 2 | Short                   |"
+            "+---+-------------------------+"
+        )
+    );
+}
+
+#[test]
+fn width_percent_cap_multiple_columns_test() {
+    let data = [["1", "Tabled is a library", "for pretty printing tables"]];
+
+    let table = Table::new(data)
+        .with(Modify::new(Columns::single(1)).with(Width::percent(25).total(40)))
+        .with(Modify::new(Columns::single(2)).with(Width::percent(50).total(40)))
+        .to_string();
+
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+---------+------------------+"
+            "| 0 | 1       | 2                |"
+            "+---+---------+------------------+"
+            "| 1 | Tabled  | for pretty print |"
+            "|   | is a li | ing tables       |"
+            "|   | brary   |                  |"
+            "+---+---------+------------------+"
+        )
+    );
+}
+
+#[test]
+fn width_percent_cap_fits_test() {
+    let table = Matrix::new(2, 2)
+        .with(Modify::new(Columns::new(..)).with(Width::percent(100)))
+        .to_string();
+
+    assert_eq!(table, Matrix::new(2, 2).to_string());
+}
+
+test_table!(
+    width_list_test,
+    Table::new([("Hello World", "Some long text here", "abcdef")])
+        .with(Padding::zero())
+        .with(Width::list([5, 10, 3])),
+    "+-----+----------+---+"
+    "|&str |&str      |&st|"
+    "|     |          |r  |"
+    "+-----+----------+---+"
+    "|Hello|Some long |abc|"
+    "| Worl|text here |def|"
+    "|d    |          |   |"
+    "+-----+----------+---+"
+);
+
+test_table!(
+    width_list_includes_padding_test,
+    Table::new([("Hello World", "Some long text here", "abcdef")])
+        .with(Padding::new(2, 1, 0, 0))
+        .with(Width::list([8, 13, 6])),
+    "+--------+-------------+------+"
+    "|  &str  |  &str       |  &st |"
+    "|        |             |  r   |"
+    "+--------+-------------+------+"
+    "|  Hello |  Some long  |  abc |"
+    "|   Worl |  text here  |  def |"
+    "|  d     |             |      |"
+    "+--------+-------------+------+"
+);
+
+test_table!(
+    width_list_partial_test,
+    Matrix::new(2, 2).with(Width::list([3, 6])),
+    "+---+------+----------+"
+    "| N | colu | column 1 |"
+    "|   | mn 0 |          |"
+    "+---+------+----------+"
+    "| 0 | 0-0  |   0-1    |"
+    "+---+------+----------+"
+    "| 1 | 1-0  |   1-1    |"
+    "+---+------+----------+"
+);
+
+test_table!(
+    width_list_padding_test,
+    Matrix::new(2, 2).with(Style::psql()).with(Width::list([3, 12, 5])),
+    " N |  column 0  | col "
+    "   |            | umn "
+    "   |            |  1  "
+    "---+------------+-----"
+    " 0 |    0-0     | 0-1 "
+    " 1 |    1-0     | 1-1 "
+);
+
+test_table!(
+    width_list_span_test,
+    Matrix::new(2, 2)
+        .insert((1, 1), "a long text in a spanned cell")
+        .with(Modify::new((1, 1)).with(Span::column(2)))
+        .with(Width::list([3, 6, 8])),
+    "+---+------+--------+"
+    "| N | colu | column |"
+    "|   | mn 0 |  1     |"
+    "+---+------+--------+"
+    "| 0 | a long text i |"
+    "|   | n a spanned c |"
+    "|   | ell           |"
+    "+---+------+--------+"
+    "| 1 | 1-0  |  1-1   |"
+    "+---+------+--------+"
+);
+
+#[test]
+fn width_fit_test() {
+    let table = Matrix::new(3, 3).with(Width::fit(30)).to_string();
+
+    assert_eq!(string_width_multiline(&table), 30);
+    assert!(table.lines().all(|line| string_width_multiline(line) == 30));
+    assert_eq!(
+        table,
+        static_table!(
+            "+---+--------+-------+-------+"
+            "| N | col... | co... | co... |"
+            "+---+--------+-------+-------+"
+            "| 0 |  0-0   |  0-1  |  0-2  |"
+            "+---+--------+-------+-------+"
+            "| 1 |  1-0   |  1-1  |  1-2  |"
+            "+---+--------+-------+-------+"
+            "| 2 |  2-0   |  2-1  |  2-2  |"
+            "+---+--------+-------+-------+"
+        )
+    );
+}
+
+test_table!(
+    width_fit_min_width_test,
+    Matrix::new(2, 3).with(Width::fit(20).min_width(2).suffix("~")),
+    "+---+----+----+----+"
+    "| N | c~ | c~ | c~ |"
+    "+---+----+----+----+"
+    "| 0 | 0~ | 0~ | 0~ |"
+    "+---+----+----+----+"
+    "| 1 | 1~ | 1~ | 1~ |"
+    "+---+----+----+----+"
+);
+
+test_table!(
+    width_fit_pin_last_test,
+    Matrix::new(2, 4).insert((1, 4), "status: waiting").with(Width::fit(46).pin_last()),
+    "+---+-------+-------+------+-----------------+"
+    "| N | co... | co... | c... |    column 3     |"
+    "+---+-------+-------+------+-----------------+"
+    "| 0 |  0-0  |  0-1  | 0-2  | status: waiting |"
+    "+---+-------+-------+------+-----------------+"
+    "| 1 |  1-0  |  1-1  | 1-2  |       1-3       |"
+    "+---+-------+-------+------+-----------------+"
+);
+
+test_table!(
+    width_fit_pin_first_test,
+    Matrix::new(2, 3).insert((1, 0), "long index").with(Width::fit(35).pin_first()),
+    "+------------+------+------+------+"
+    "|     N      | c... | c... | c... |"
+    "+------------+------+------+------+"
+    "| long index | 0-0  | 0-1  | 0-2  |"
+    "+------------+------+------+------+"
+    "|     1      | 1-0  | 1-1  | 1-2  |"
+    "+------------+------+------+------+"
+);
+
+test_table!(
+    width_fit_span_test,
+    Matrix::new(2, 3)
+        .insert((1, 1), "a long text in a spanned cell")
+        .with(Modify::new((1, 1)).with(Span::column(2)))
+        .with(Width::fit(30)),
+    "+---+--------+-------+-------+"
+    "| N | col... | co... | co... |"
+    "+---+--------+-------+-------+"
+    "| 0 | a long text... |  0-2  |"
+    "+---+--------+-------+-------+"
+    "| 1 |  1-0   |  1-1  |  1-2  |"
+    "+---+--------+-------+-------+"
+);
+
+test_table!(
+    width_fit_pin_first_and_last_test,
+    Matrix::new(2, 3).with(Width::fit(30).pin_first().pin_last()),
+    "+---+------+------+----------+"
+    "| N | c... | c... | column 2 |"
+    "+---+------+------+----------+"
+    "| 0 | 0-0  | 0-1  |   0-2    |"
+    "+---+------+------+----------+"
+    "| 1 | 1-0  | 1-1  |   1-2    |"
+    "+---+------+------+----------+"
+);
+
+test_table!(
+    width_fit_is_noop_when_fits_test,
+    Matrix::new(2, 2).with(Width::fit(100)),
+    "+---+----------+----------+"
+    "| N | column 0 | column 1 |"
+    "+---+----------+----------+"
+    "| 0 |   0-0    |   0-1    |"
+    "+---+----------+----------+"
+    "| 1 |   1-0    |   1-1    |"
+    "+---+----------+----------+"
+);
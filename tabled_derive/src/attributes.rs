@@ -12,6 +12,7 @@ pub struct Attributes {
     pub display_with: Option<String>,
     pub display_with_args: Option<Vec<FuncArg>>,
     pub order: Option<usize>,
+    pub map: bool,
 }
 
 impl Attributes {
@@ -64,6 +65,11 @@ impl Attributes {
                 }
             }
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            parse::TabledAttrKind::Map(b) => {
+                if b.value {
+                    self.map = true;
+                }
+            }
         }
 
         Ok(())
@@ -448,6 +448,21 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
         return quote!(vec![::std::borrow::Cow::from(#call)]);
     }
 
+    if attr.map {
+        // entries are sorted by key, because an iteration order of a map may differ between runs.
+        return quote! {{
+            let mut entries = ::std::iter::IntoIterator::into_iter(&#field).collect::<Vec<_>>();
+            entries.sort_by(|a, b| ::std::cmp::Ord::cmp(&a.0, &b.0));
+
+            let entries = entries
+                .into_iter()
+                .map(|(key, value)| format!("{}: {}", key, value))
+                .collect::<Vec<_>>();
+
+            vec![::std::borrow::Cow::Owned(entries.join("\n"))]
+        }};
+    }
+
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
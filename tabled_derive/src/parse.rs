@@ -33,6 +33,7 @@ pub enum TabledAttrKind {
     RenameAll(LitStr),
     DisplayWith(LitStr, Option<Token!(,)>, Punctuated<syn::Expr, Token!(,)>),
     Order(LitInt),
+    Map(LitBool),
 }
 
 impl Parse for TabledAttr {
@@ -64,6 +65,7 @@ impl Parse for TabledAttr {
                 match name_str.as_str() {
                     "skip" => return Ok(Self::new(name, Skip(lit))),
                     "inline" => return Ok(Self::new(name, Inline(lit, None))),
+                    "map" => return Ok(Self::new(name, Map(lit))),
                     _ => {}
                 }
             }
@@ -132,6 +134,7 @@ impl Parse for TabledAttr {
                     Inline(LitBool::new(true, Span::call_site()), None),
                 ))
             }
+            "map" => return Ok(Self::new(name, Map(LitBool::new(true, Span::call_site())))),
             _ => {}
         }
 